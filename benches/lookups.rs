@@ -0,0 +1,108 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 3166-1?
+//
+// | ISO 3166-1 is part of the ISO 3166 standard published by the International
+// | Organization for Standardization (ISO), and defines codes for the names of
+// | countries, dependent territories, and special areas of geographical
+// | interest.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_3166-1)
+//
+// Originally by zeyla on GitHub.
+//
+// Compares the indexed lookups against a linear scan of the table, which is
+// how every lookup used to be done.
+#[macro_use]
+extern crate criterion;
+extern crate iso3166_1;
+
+use criterion::{black_box, Criterion};
+use iso3166_1::Entry;
+
+// A spread of codes from the start, middle, and end of the table, and one that
+// is not assigned at all.
+const ALPHA2: &[&str] = &["AF", "DE", "ZM", "AA"];
+const ALPHA3: &[&str] = &["AFG", "DEU", "ZMB", "AAA"];
+const NUM: &[&str] = &["004", "276", "894", "000"];
+
+fn alpha2(c: &mut Criterion) {
+    c.bench_function("alpha2 indexed", |b| {
+        b.iter(|| {
+            for code in ALPHA2 {
+                black_box(Entry::by_alpha2(black_box(code)));
+            }
+        })
+    });
+    c.bench_function("alpha2 linear", |b| {
+        b.iter(|| {
+            for code in ALPHA2 {
+                let code = black_box(code);
+                black_box(Entry::all().iter().find(|e| e.alpha2 == *code));
+            }
+        })
+    });
+}
+
+fn alpha3(c: &mut Criterion) {
+    c.bench_function("alpha3 indexed", |b| {
+        b.iter(|| {
+            for code in ALPHA3 {
+                black_box(Entry::by_alpha3(black_box(code)));
+            }
+        })
+    });
+    c.bench_function("alpha3 linear", |b| {
+        b.iter(|| {
+            for code in ALPHA3 {
+                let code = black_box(code);
+                black_box(Entry::all().iter().find(|e| e.alpha3 == *code));
+            }
+        })
+    });
+}
+
+fn num(c: &mut Criterion) {
+    c.bench_function("num indexed", |b| {
+        b.iter(|| {
+            for code in NUM {
+                black_box(Entry::by_num(black_box(code)));
+            }
+        })
+    });
+    c.bench_function("num linear", |b| {
+        b.iter(|| {
+            for code in NUM {
+                let code = black_box(code);
+                black_box(Entry::all().iter().find(|e| e.num == *code));
+            }
+        })
+    });
+}
+
+fn owned(c: &mut Criterion) {
+    c.bench_function("alpha2 owned", |b| {
+        b.iter(|| {
+            for code in ALPHA2 {
+                black_box(iso3166_1::alpha2(black_box(code)));
+            }
+        })
+    });
+}
+
+criterion_group!(benches, alpha2, alpha3, num, owned);
+criterion_main!(benches);
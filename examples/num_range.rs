@@ -1,4 +1,4 @@
-#![allow(unused_variables)]
+#![allow(unused_variables, clippy::len_zero)]
 
 extern crate iso3166_1;
 
@@ -0,0 +1,21 @@
+#![allow(unused_variables)]
+
+extern crate iso3166_1;
+
+fn main() {
+    // Retrieve a `Vec` of `CountryCode`s whose numeric values are between `100`
+    // and `300`, inclusive.
+    let countries1 = iso3166_1::numeric_range(100..=300);
+
+    // Retrieve a `Vec` of `CountryCode`s whose numeric values are greater than
+    // or equal to `400`.
+    let countries2 = iso3166_1::numeric_range(400..);
+
+    // Retrieve a `Vec` of `CountryCode`s whose numeric values are less than
+    // `500`.
+    let countries3 = iso3166_1::numeric_range(..500);
+
+    // An unbounded range gives you every country, sorted by numeric value.
+    let countries4 = iso3166_1::numeric_range(..);
+    assert!(countries4.len() == iso3166_1::all().len()); // true
+}
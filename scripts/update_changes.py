@@ -0,0 +1,179 @@
+#!/usr/bin/python3
+# ISC License (ISC)
+#
+# Copyright (c) 2016, Austin Hellyer <hello@austinhellyer.me>
+#
+# Permission to use, copy, modify, and/or distribute this software for any
+# purpose with or without fee is hereby granted, provided that the above
+# copyright notice and this permission notice appear in all copies.
+#
+# THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+# WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+# MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+# SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+# RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+# CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+# CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+#
+# What is ISO 3166-1?
+#
+# | ISO 3166-1 is part of the ISO 3166 standard published by the International
+# | Organization for Standardization (ISO), and defines codes for the names of
+# | countries, dependent territories, and special areas of geographical
+# | interest.
+# |
+# | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_3166-1)
+#
+# Originally by zeyla on GitHub.
+
+# USAGE:
+# Requires python3. After editing the history in 'src/as_of.rs' or
+# 'src/iso3166_3.rs', run 'make update-changes' to rebuild the list of changes
+# and `DATA_VERSION` in 'src/changes.rs' from it.
+
+import os
+import re
+
+src = os.path.join(os.path.dirname(__file__), '../src')
+
+
+def read(name):
+    with open(os.path.join(src, name), 'r') as f:
+        return f.read()
+
+
+def date(text):
+    return tuple(int(part) for part in re.findall(r'\d+', text))
+
+
+# Parses a `Validity` expression into its first and last days, or `None`.
+def validity(text):
+    until = re.fullmatch(r'until\((\d+, \d+, \d+)\)', text)
+
+    if until:
+        return None, date(until.group(1))
+
+    between = re.fullmatch(r'between\(Date::new\((.*?)\), Date::new\((.*?)\)\)', text)
+
+    return date(between.group(1)), date(between.group(2))
+
+
+as_of = read('as_of.rs')
+codes = read('codes.rs')
+former = read('iso3166_3.rs')
+
+entries = {}
+
+for alpha2, alpha3, name, num in re.findall(
+        r'Entry \{\n\s+country: Country::\w+,\n\s+alpha2: "(\w+)",\n'
+        r'\s+alpha3: "(\w+)",\n\s+name: "(.*?)",\n\s+num: "(\d+)",', codes):
+    entries[alpha2] = {'alpha3': alpha3, 'name': name, 'num': num}
+
+assigned = {
+    alpha2: date(when)
+    for alpha2, when in re.findall(r'\((\w\w), Date::new\((.*?)\)\),', as_of)
+}
+
+past = {}
+
+for alpha2, alpha3, name, num, valid in re.findall(
+        r'HistoricalEntry \{\n\s+country: Some\(\w+\),\n\s+alpha2: "(\w+)",\n'
+        r'\s+alpha3: "(\w+)",\n\s+name: "(.*?)",\n\s+num: Some\("(\d+)"\),\n'
+        r'\s+validity: (.*?),\n', as_of):
+    start, end = validity(valid)
+    past.setdefault(alpha2, []).append(
+        {'alpha3': alpha3, 'name': name, 'num': num, 'from': start, 'until': end})
+
+renamed = {}
+
+for alpha2, name, when in re.findall(r'\((\w\w), "(.*?)", Date::new\((.*?)\)\),', as_of):
+    renamed.setdefault(alpha2, []).append((date(when), name))
+
+# The order of changes made on the same day.
+ORDER = ['Removed', 'Added', 'Alpha3Changed', 'NumericChanged', 'NameChanged']
+
+changes = []
+# The latest change reflected in the table, rather than only in `RENAMED`.
+version = (1974, 1, 1)
+
+
+def change(when, alpha2, kind, fields, reference):
+    changes.append((when, ORDER.index(kind), alpha2, kind, fields, reference))
+
+
+def obp(alpha2):
+    return 'https://www.iso.org/obp/ui/#iso:code:3166:{}'.format(alpha2)
+
+
+for code, name, alpha2, valid in re.findall(
+        r'code: "(\w+)",\n\s+name: "(.*?)",\n\s+alpha2: "(\w+)",\n.*?'
+        r'validity: (.*?),\n', former, re.S):
+    start, end = validity(valid)
+    reference = 'ISO 3166-3 {}'.format(code)
+
+    if start:
+        change(start, alpha2, 'Added', [('name', name)], reference)
+
+    change(end, alpha2, 'Removed', [('name', name)], reference)
+    version = max(version, end)
+
+for alpha2, entry in entries.items():
+    states = sorted(past.get(alpha2, []), key=lambda state: state['until'])
+    start = max([assigned.get(alpha2, (0,))] + [state['until'] for state in states])
+    states.append(dict(entry, **{'from': start if start != (0,) else None}))
+    first = states[0]['from']
+
+    if first:
+        change(first, alpha2, 'Added', [('name', states[0]['name'])], obp(alpha2))
+        version = max(version, first)
+
+    for before, after in zip(states, states[1:]):
+        for field, kind in [('alpha3', 'Alpha3Changed'), ('num', 'NumericChanged'),
+                            ('name', 'NameChanged')]:
+            if before[field] != after[field]:
+                change(after['from'], alpha2, kind,
+                       [('from', before[field]), ('to', after[field])], obp(alpha2))
+                version = max(version, after['from'])
+
+    name = entry['name']
+
+    for when, later in sorted(renamed.get(alpha2, [])):
+        change(when, alpha2, 'NameChanged', [('from', name), ('to', later)], obp(alpha2))
+        name = later
+
+contents = ''
+
+for when, _, alpha2, kind, fields, reference in sorted(changes):
+    values = ', '.join('{}: "{}"'.format(field, value) for field, value in fields)
+    line = '        kind: ChangeKind::{} {{ {} }},\n'.format(kind, values)
+
+    if len(line) > 101:
+        line = '        kind: ChangeKind::{} {{\n'.format(kind)
+
+        for field, value in fields:
+            line += '            {}: "{}",\n'.format(field, value)
+
+        line += '        },\n'
+
+    contents += '    Change {\n'
+    contents += '        date: Date::new({}, {}, {}),\n'.format(*when)
+    contents += '        alpha2: "{}",\n'.format(alpha2)
+    contents += line
+    contents += '        reference: "{}",\n'.format(reference)
+    contents += '    },\n'
+
+path = os.path.join(src, 'changes.rs')
+
+with open(path, 'r') as f:
+    text = f.read()
+
+head, rest = text.split('    // Begin changes\n', 1)
+_, tail = rest.split('    // End changes\n', 1)
+text = head + '    // Begin changes\n' + contents + '    // End changes\n' + tail
+text = re.sub(r'DATA_VERSION: Date = Date::new\(.*?\);',
+              'DATA_VERSION: Date = Date::new({}, {}, {});'.format(*version), text)
+
+with open(path, 'w') as f:
+    f.write(text)
+
+print('Updated.')
@@ -42,36 +42,59 @@ clip = subprocess.run(['xsel', '--clipboard'], stdout=subprocess.PIPE)
 # Split the clip into newlines, one item per index. This splits by 2+ spaces.
 rows = clip.stdout.decode('utf-8').split("\n")
 
-# Cycle through each row and append to the text its entry.
-text = ""
+# Cycle through each row and build both the `Country` variants and the entries
+# of the table backing them.
+variants = ""
+entries = ""
 
 for row in rows:
+    if not row.strip():
+        continue
+
     # 0: Name
     # 1: Alpha2
     # 2: Alpha3
     # 3: Num
-    cells = re.split(r'\s{2,}', row)
+    cells = re.split(r'\s{2,}', row.strip())
+
+    # Wikipedia footnote markers, such as "[a]", are not part of the name.
+    name = re.sub(r'\[\w+\]', '', cells[0]).strip()
+
+    variants += '    /// {}\n'.format(name)
+    variants += '    {},\n'.format(cells[1])
+
+    entries += '    Entry {\n'
+    entries += '        country: Country::{},\n'.format(cells[1])
+    entries += '        alpha2: "{}",\n'.format(cells[1])
+    entries += '        alpha3: "{}",\n'.format(cells[2])
+    entries += '        name: "{}",\n'.format(name.replace('"', '\\"'))
+    entries += '        num: "{}",\n'.format(cells[3])
+    entries += '    },\n'
 
-    text += '    codes.push(CountryCode {\n'
-    text += '        alpha2: "{}",\n'.format(cells[1])
-    text += '        alpha3: "{}",\n'.format(cells[2])
-    text += '        name: "{}",\n'.format(cells[0])
-    text += '        num: "{}",\n'.format(cells[3])
-    text += '    });\n'
+count = len(entries.split('    Entry {')) - 1
 
-# Read the codes.rs file and split it to find the code after 'Begin' and before
-# 'End'
+# Read the codes.rs file and replace the code between each 'Begin' and 'End'
+# pair.
 codes_path = os.path.join(os.path.dirname(__file__), '../src/codes.rs')
 
 with open(codes_path, 'r') as f:
     codes_file = f.read()
 
-# Split by where to insert the text.
-codes = codes_file.rsplit('// Begin', 1)
-# And where to end putting the text.
-codes_end = codes_file.rsplit('// End\n', 1)
+
+def replace(text, section, contents):
+    begin = '// Begin {}\n'.format(section)
+    end = '    // End {}\n'.format(section)
+    head, rest = text.split(begin, 1)
+    _, tail = rest.split(end, 1)
+
+    return head + begin + contents + end + tail
+
+
+codes_file = replace(codes_file, 'variants', variants)
+codes_file = replace(codes_file, 'entries', entries)
+codes_file = re.sub(r'\[Entry; \d+\]', '[Entry; {}]'.format(count), codes_file)
 
 with open(codes_path, 'w') as f:
-    f.write(codes[0] + '// Begin\n' + text + '    // End\n' + codes_end[1])
+    f.write(codes_file)
 
 print('Updated.')
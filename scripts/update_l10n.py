@@ -0,0 +1,181 @@
+#!/usr/bin/python3
+# ISC License (ISC)
+#
+# Copyright (c) 2016, Austin Hellyer <hello@austinhellyer.me>
+#
+# Permission to use, copy, modify, and/or distribute this software for any
+# purpose with or without fee is hereby granted, provided that the above
+# copyright notice and this permission notice appear in all copies.
+#
+# THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+# WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+# MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+# SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+# RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+# CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+# CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+#
+# What is ISO 3166-1?
+#
+# | ISO 3166-1 is part of the ISO 3166 standard published by the International
+# | Organization for Standardization (ISO), and defines codes for the names of
+# | countries, dependent territories, and special areas of geographical
+# | interest.
+# |
+# | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_3166-1)
+#
+# Originally by zeyla on GitHub.
+
+# USAGE:
+# Requires python3. Region display names are read from the CLDR data compiled
+# into the icu_experimental_data crate. Extract the crate, then run:
+#
+#   python3 scripts/update_l10n.py <crate>/data/macros/displaynames_regions_v1.rs.data
+#
+# Each locale in `FULL` gets a table of every name. Each locale in `PARTIAL`
+# only gets the names differing from those of its parent.
+
+import os
+import re
+import sys
+
+FULL = [
+    'af', 'ar', 'bg', 'bn', 'ca', 'cs', 'da', 'de', 'el', 'en', 'es', 'et',
+    'fa', 'fi', 'fil', 'fr', 'he', 'hi', 'hr', 'hu', 'id', 'it', 'ja', 'ko',
+    'lt', 'lv', 'ms', 'nl', 'no', 'pl', 'pt', 'ro', 'ru', 'sk', 'sl', 'sr',
+    'sr-Latn', 'sv', 'sw', 'th', 'tr', 'uk', 'ur', 'vi', 'zh', 'zh-Hant',
+]
+
+# Each partial locale, with the locale it falls back to.
+PARTIAL = {
+    'de-AT': 'de',
+    'de-CH': 'de',
+    'en-001': 'en',
+    'en-AU': 'en-001',
+    'en-CA': 'en-001',
+    'es-419': 'es',
+    'es-MX': 'es-419',
+    'fr-BE': 'fr',
+    'fr-CA': 'fr',
+    'pt-PT': 'pt',
+    'zh-HK': 'zh-Hant',
+}
+
+
+# Reads the bytes of a Rust byte string literal.
+def unescape(literal):
+    escapes = {'0': b'\0', 'n': b'\n', 't': b'\t', 'r': b'\r', '"': b'"',
+               '\\': b'\\', "'": b"'"}
+    out = bytearray()
+    i = 0
+
+    while i < len(literal):
+        if literal[i] != '\\':
+            out += literal[i].encode('utf-8')
+            i += 1
+        elif literal[i + 1] == 'x':
+            out.append(int(literal[i + 2:i + 4], 16))
+            i += 4
+        else:
+            out += escapes[literal[i + 1]]
+            i += 2
+
+    return bytes(out)
+
+
+# Reads the strings of a serialized `VarZeroVec<str>`.
+def var_zero_vec(data):
+    count = int.from_bytes(data[0:4], 'little')
+    indices = [int.from_bytes(data[4 + 2 * i:6 + 2 * i], 'little')
+               for i in range(count)]
+    strings = data[4 + 2 * count:]
+    ends = indices[1:] + [len(strings)]
+
+    return [strings[start:end].decode('utf-8')
+            for start, end in zip(indices, ends)]
+
+
+with open(sys.argv[1], 'r') as f:
+    source = f.read()
+
+# Each locale's data is a static holding a map of region codes to names.
+statics = {}
+
+for match in re.finditer(r'static ([A-Z0-9_]+): <[^=]*= icu::experimental::'
+                         r'displaynames::provider::RegionDisplayNamesV1 '
+                         r'\{(.*?)\};\n', source, re.S):
+    literals = re.findall(r'from_bytes_unchecked\(b"((?:[^"\\]|\\.)*)"\)',
+                          match.group(2))
+    keys = unescape(literals[0])
+    regions = [keys[i:i + 3].rstrip(b'\0').decode()
+               for i in range(0, len(keys), 3)]
+    statics[match.group(1)] = dict(zip(regions,
+                                       var_zero_vec(unescape(literals[1]))))
+
+keys = re.findall(r'"([^"]+)"',
+                  re.search(r'static KEYS: [^=]*= \[(.*?)\];', source).group(1))
+values = re.findall(r'&([A-Z0-9_]+)',
+                    re.search(r'static VALUES: [^=]*= \[(.*?)\];', source,
+                              re.S).group(1))
+names = {key: statics[value] for key, value in zip(keys, values)}
+
+# The codes, in the order of the `Country` variants.
+codes_path = os.path.join(os.path.dirname(__file__), '../src/codes.rs')
+
+with open(codes_path, 'r') as f:
+    codes = re.findall(r'alpha2: "(\w+)",', f.read())
+
+
+def ident(locale):
+    return locale.upper().replace('-', '_')
+
+
+def literal(name):
+    return '"{}"'.format(name.replace('\\', '\\\\').replace('"', '\\"'))
+
+
+tables = ''
+locales = ''
+
+for locale in sorted(FULL + list(PARTIAL)):
+    if locale in PARTIAL:
+        parent = names[PARTIAL[locale]]
+        changed = [code for code in codes
+                   if names[locale][code] != parent[code]]
+
+        tables += 'static {}: &[(Country, &str)] = &[\n'.format(ident(locale))
+
+        for code in changed:
+            tables += '    (Country::{}, {}),\n'.format(code,
+                                              literal(names[locale][code]))
+
+        tables += '];\n\n'
+        locales += '    Locale {{\n        tag: "{}",\n        names: ' \
+            'Names::Partial({}),\n    }},\n'.format(locale, ident(locale))
+    else:
+        tables += 'static {}: [&str; {}] = [\n'.format(ident(locale),
+                                                       len(codes))
+
+        for code in codes:
+            tables += '    {},\n'.format(literal(names[locale][code]))
+
+        tables += '];\n\n'
+        locales += '    Locale {{\n        tag: "{}",\n        names: ' \
+            'Names::Full(&{}),\n    }},\n'.format(locale, ident(locale))
+
+contents = 'static LOCALES: [Locale; {}] = [\n{}];\n\n{}'.format(
+    len(FULL) + len(PARTIAL), locales, tables.rstrip('\n') + '\n')
+
+# Replace the code between the 'Begin' and 'End' markers of l10n.rs.
+l10n_path = os.path.join(os.path.dirname(__file__), '../src/l10n.rs')
+
+with open(l10n_path, 'r') as f:
+    l10n_file = f.read()
+
+head, rest = l10n_file.split('// Begin locales\n', 1)
+_, tail = rest.split('// End locales\n', 1)
+
+with open(l10n_path, 'w') as f:
+    f.write(head + '// Begin locales\n' + contents + '// End locales\n' + tail)
+
+print('Updated.')
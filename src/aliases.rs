@@ -0,0 +1,108 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 3166-1?
+//
+// | ISO 3166-1 is part of the ISO 3166 standard published by the International
+// | Organization for Standardization (ISO), and defines codes for the names of
+// | countries, dependent territories, and special areas of geographical
+// | interest.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_3166-1)
+//
+// Originally by zeyla on GitHub.
+
+// Curated colloquial, former and abbreviated names of countries, as commonly
+// found in data not following ISO 3166-1.
+
+use Country;
+use Country::*;
+
+/// How a name given to [`name_or_alias`] matched a country.
+///
+/// [`name_or_alias`]: fn.name_or_alias.html
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum NameMatch {
+    /// The name is the English short name of the country, such as
+    /// `"United States of America"`
+    Canonical,
+    /// The name is one of the aliases of the country, such as `"USA"`
+    Alias,
+}
+
+// Returns the aliases of a country, none of which is the short name of any
+// country.
+pub(crate) const fn aliases(country: Country) -> &'static [&'static str] {
+    match country {
+        AE => &["UAE", "Emirates"],
+        AG => &["Antigua"],
+        BA => &["Bosnia", "Bosnia-Herzegovina"],
+        BL => &["St Barthélemy", "St Barts"],
+        BN => &["Brunei"],
+        BO => &["Bolivia"],
+        BQ => &["Caribbean Netherlands"],
+        BS => &["The Bahamas"],
+        CD => &[
+            "Democratic Republic of the Congo",
+            "DR Congo",
+            "DRC",
+            "Congo-Kinshasa",
+            "Zaire",
+        ],
+        CF => &["CAR"],
+        CG => &["Republic of the Congo", "Congo-Brazzaville"],
+        CI => &["Ivory Coast"],
+        CV => &["Cape Verde"],
+        CZ => &["Czechia"],
+        FK => &["Falkland Islands (Malvinas)", "Falklands", "Malvinas"],
+        FM => &["Micronesia", "Federated States of Micronesia"],
+        GB => &["United Kingdom", "UK", "Great Britain", "Britain"],
+        GM => &["The Gambia"],
+        HK => &["Hong Kong SAR"],
+        IE => &["Republic of Ireland", "Eire"],
+        IR => &["Iran"],
+        KN => &["St Kitts and Nevis", "Saint Kitts"],
+        KP => &["North Korea", "DPRK"],
+        KR => &["South Korea", "Republic of Korea"],
+        LA => &["Laos"],
+        LC => &["St Lucia"],
+        MD => &["Moldova"],
+        MK => &["North Macedonia", "Macedonia", "FYROM"],
+        MM => &["Burma"],
+        MO => &["Macau", "Macao SAR"],
+        NL => &["Holland", "The Netherlands"],
+        PM => &["St Pierre and Miquelon"],
+        PS => &["Palestine", "Palestinian Territories"],
+        RU => &["Russia"],
+        SH => &["Saint Helena", "St Helena"],
+        SJ => &["Svalbard"],
+        SX => &["Sint Maarten"],
+        SY => &["Syria"],
+        SZ => &["Eswatini"],
+        TL => &["East Timor"],
+        TR => &["Türkiye"],
+        TW => &["Taiwan"],
+        TZ => &["Tanzania"],
+        US => &["United States", "USA", "US"],
+        VA => &["Vatican City", "Vatican"],
+        VC => &["Saint Vincent", "St Vincent and the Grenadines"],
+        VE => &["Venezuela"],
+        VG => &["British Virgin Islands"],
+        VI => &["US Virgin Islands", "U.S. Virgin Islands"],
+        VN => &["Vietnam"],
+        _ => &[],
+    }
+}
@@ -0,0 +1,491 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 3166-1?
+//
+// | ISO 3166-1 is part of the ISO 3166 standard published by the International
+// | Organization for Standardization (ISO), and defines codes for the names of
+// | countries, dependent territories, and special areas of geographical
+// | interest.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_3166-1)
+//
+// Originally by zeyla on GitHub.
+
+// When the codes and names of the table became valid, the states before
+// them, and the names adopted since.
+//
+// Dates are those of the change in the country, such as the reunification of
+// Germany, or of the ISO 3166 newsletter where that is all that is known.
+// Where only the year of a change is known, it is taken to be the 1st of
+// January of that year. Codes with no start date have been valid since the
+// first edition of ISO 3166, in 1974.
+//
+// The changes in `changes` are built from these tables and those of
+// `iso3166_3`, so run 'make update-changes' after editing any of them.
+//
+// Sources:
+// https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2
+// https://en.wikipedia.org/wiki/ISO_3166-3
+
+use codes::ENTRIES;
+use iso3166_3;
+use Country::*;
+use {Country, Date, Entry};
+
+/// A period during which codes or a name were valid.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Validity {
+    /// The first day of the period, or `None` if it began with the first
+    /// edition of ISO 3166, in 1974
+    pub from: Option<Date>,
+    /// The day the period ended, or `None` if it has not
+    pub until: Option<Date>,
+}
+
+impl Validity {
+    /// Returns whether the period includes the given date.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iso3166_1::{Date, Validity};
+    ///
+    /// let validity = Validity {
+    ///     from: None,
+    ///     until: Some(Date::new(2010, 12, 15)),
+    /// };
+    ///
+    /// assert!(validity.contains(Date::new(2010, 12, 14)));
+    /// assert!(!validity.contains(Date::new(2010, 12, 15)));
+    /// ```
+    pub fn contains(&self, date: Date) -> bool {
+        self.from.is_none_or(|from| from <= date) && self.until.is_none_or(|until| date < until)
+    }
+}
+
+/// The codes and name of a country during a period.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct HistoricalEntry {
+    /// The country now using the codes, if they are still assigned
+    pub country: Option<Country>,
+    /// Two-character Alpha2 code
+    pub alpha2: &'static str,
+    /// Three-character Alpha3 code
+    pub alpha3: &'static str,
+    /// English short name of the country
+    pub name: &'static str,
+    /// Numeric code of the country, if it had one
+    pub num: Option<&'static str>,
+    /// When the codes and name were valid
+    pub validity: Validity,
+}
+
+/// A view of the dataset as it stood on a date, including countries that
+/// have since been withdrawn. Created by [`as_of`].
+///
+/// [`as_of`]: fn.as_of.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct AsOf {
+    date: Date,
+}
+
+impl AsOf {
+    /// Returns the date of the view.
+    pub fn date(&self) -> Date {
+        self.date
+    }
+
+    /// Returns the country with the given Alpha2 code on the date, if one
+    /// existed.
+    pub fn alpha2(&self, alpha2: &str) -> Option<HistoricalEntry> {
+        self.iter().find(|entry| entry.alpha2 == alpha2)
+    }
+
+    /// Returns the country with the given Alpha3 code on the date, if one
+    /// existed.
+    pub fn alpha3(&self, alpha3: &str) -> Option<HistoricalEntry> {
+        self.iter().find(|entry| entry.alpha3 == alpha3)
+    }
+
+    /// Returns the country with the given name on the date, if one existed.
+    pub fn name(&self, name: &str) -> Option<HistoricalEntry> {
+        self.iter().find(|entry| entry.name == name)
+    }
+
+    /// Returns all countries on the date, sorted by their Alpha2 code.
+    #[cfg(feature = "std")]
+    pub fn all(&self) -> Vec<HistoricalEntry> {
+        let mut all: Vec<HistoricalEntry> = self.iter().collect();
+        all.sort_by_key(|entry| entry.alpha2);
+
+        all
+    }
+
+    /// Returns an iterator over all countries on the date, in no particular
+    /// order.
+    pub fn iter(&self) -> impl Iterator<Item = HistoricalEntry> {
+        let date = self.date;
+
+        history().filter(move |entry| entry.validity.contains(date))
+    }
+}
+
+/// Returns a view of the dataset as it stood on the given date.
+///
+/// Dates before 1974 are answered as of the first edition of ISO 3166.
+///
+/// # Examples
+///
+/// ```rust
+/// use iso3166_1::{as_of, Date};
+///
+/// let view = as_of(Date::new(2005, 1, 1));
+///
+/// assert_eq!(view.alpha2("SZ").unwrap().name, "Swaziland");
+/// assert_eq!(view.alpha2("AN").unwrap().name, "Netherlands Antilles");
+/// assert!(view.alpha2("CW").is_none());
+///
+/// let view = as_of(Date::new(2020, 1, 1));
+///
+/// assert_eq!(view.alpha2("CW").unwrap().name, "Curaçao");
+/// assert!(view.alpha2("AN").is_none());
+/// ```
+pub fn as_of(date: Date) -> AsOf {
+    AsOf { date }
+}
+
+// Returns when the codes of an entry became valid.
+pub(crate) fn validity(entry: &Entry) -> Validity {
+    let assigned = ASSIGNED
+        .iter()
+        .find(|&&(country, _)| country == entry.country)
+        .map(|&(_, date)| date);
+    let changed = PAST
+        .iter()
+        .filter(|past| past.country == Some(entry.country))
+        .filter(|past| past.alpha3 != entry.alpha3 || past.num != Some(entry.num))
+        .filter_map(|past| past.validity.until)
+        .max();
+
+    Validity {
+        from: assigned.max(changed),
+        until: None,
+    }
+}
+
+// Returns when the name of an entry was valid, until it was replaced by one
+// of `RENAMED`.
+pub(crate) fn name_validity(entry: &Entry) -> Validity {
+    let renamed = PAST
+        .iter()
+        .filter(|past| past.country == Some(entry.country) && past.name != entry.name)
+        .filter_map(|past| past.validity.until)
+        .max();
+
+    Validity {
+        from: validity(entry).from.max(renamed),
+        until: renamed_after(entry.country, None),
+    }
+}
+
+// Returns when the country was next renamed after the given date, if it was.
+fn renamed_after(country: Country, date: Option<Date>) -> Option<Date> {
+    RENAMED
+        .iter()
+        .filter(|&&(renamed, _, from)| renamed == country && date.is_none_or(|date| date < from))
+        .map(|&(_, _, from)| from)
+        .min()
+}
+
+// Returns every state of every country, current or former.
+fn history() -> impl Iterator<Item = HistoricalEntry> {
+    let current = ENTRIES.iter().map(|entry| HistoricalEntry {
+        country: Some(entry.country),
+        alpha2: entry.alpha2,
+        alpha3: entry.alpha3,
+        name: entry.name,
+        num: Some(entry.num),
+        validity: name_validity(entry),
+    });
+    let former = iso3166_3::all().iter().map(|former| HistoricalEntry {
+        country: None,
+        alpha2: former.alpha2,
+        alpha3: former.alpha3,
+        name: former.name,
+        num: former.num,
+        validity: former.validity,
+    });
+
+    let renamed = RENAMED.iter().map(|&(country, name, from)| {
+        let entry = country.entry();
+
+        HistoricalEntry {
+            country: Some(country),
+            alpha2: entry.alpha2,
+            alpha3: entry.alpha3,
+            name,
+            num: Some(entry.num),
+            validity: Validity {
+                from: Some(from),
+                until: renamed_after(country, Some(from)),
+            },
+        }
+    });
+
+    current
+        .chain(PAST.iter().cloned())
+        .chain(renamed)
+        .chain(former)
+}
+
+pub(crate) const fn until(year: u16, month: u8, day: u8) -> Validity {
+    Validity {
+        from: None,
+        until: Some(Date::new(year, month, day)),
+    }
+}
+
+pub(crate) const fn between(from: Date, until: Date) -> Validity {
+    Validity {
+        from: Some(from),
+        until: Some(until),
+    }
+}
+
+// Countries whose codes were assigned after the first edition.
+static ASSIGNED: &[(Country, Date)] = &[
+    (AI, Date::new(1985, 1, 1)),
+    (AM, Date::new(1991, 12, 26)),
+    (AW, Date::new(1986, 1, 1)),
+    (AX, Date::new(2004, 2, 13)),
+    (AZ, Date::new(1991, 12, 26)),
+    (BA, Date::new(1992, 1, 1)),
+    (BF, Date::new(1984, 8, 4)),
+    (BJ, Date::new(1975, 11, 30)),
+    (BL, Date::new(2007, 9, 21)),
+    (BQ, Date::new(2010, 12, 15)),
+    (BY, Date::new(1991, 12, 26)),
+    (CD, Date::new(1997, 5, 17)),
+    (CW, Date::new(2010, 12, 15)),
+    (CZ, Date::new(1993, 1, 1)),
+    (DJ, Date::new(1977, 6, 27)),
+    (EE, Date::new(1991, 12, 26)),
+    (ER, Date::new(1993, 5, 24)),
+    (FM, Date::new(1986, 1, 1)),
+    (GE, Date::new(1991, 12, 26)),
+    (GG, Date::new(2006, 3, 29)),
+    (GS, Date::new(1993, 1, 1)),
+    (HR, Date::new(1992, 1, 1)),
+    (IM, Date::new(2006, 3, 29)),
+    (JE, Date::new(2006, 3, 29)),
+    (KG, Date::new(1991, 12, 26)),
+    (KI, Date::new(1979, 1, 1)),
+    (KZ, Date::new(1991, 12, 26)),
+    (LT, Date::new(1991, 12, 26)),
+    (LV, Date::new(1991, 12, 26)),
+    (MD, Date::new(1991, 12, 26)),
+    (ME, Date::new(2006, 9, 26)),
+    (MF, Date::new(2007, 9, 21)),
+    (MH, Date::new(1986, 1, 1)),
+    (MK, Date::new(1993, 1, 1)),
+    (MM, Date::new(1989, 6, 18)),
+    (MP, Date::new(1986, 1, 1)),
+    (PS, Date::new(1999, 1, 1)),
+    (PW, Date::new(1986, 1, 1)),
+    (RS, Date::new(2006, 9, 26)),
+    (RU, Date::new(1991, 12, 26)),
+    (SI, Date::new(1992, 1, 1)),
+    (SK, Date::new(1993, 1, 1)),
+    (SS, Date::new(2011, 8, 9)),
+    (SX, Date::new(2010, 12, 15)),
+    (TF, Date::new(1979, 1, 1)),
+    (TJ, Date::new(1991, 12, 26)),
+    (TL, Date::new(2002, 5, 20)),
+    (TM, Date::new(1991, 12, 26)),
+    (TV, Date::new(1977, 1, 1)),
+    (UM, Date::new(1986, 1, 1)),
+    (UZ, Date::new(1991, 12, 26)),
+    (VU, Date::new(1980, 7, 30)),
+    (YT, Date::new(1993, 1, 1)),
+    (ZW, Date::new(1980, 4, 18)),
+];
+
+// Earlier codes and names of countries that are still assigned.
+static PAST: &[HistoricalEntry] = &[
+    HistoricalEntry {
+        country: Some(BO),
+        alpha2: "BO",
+        alpha3: "BOL",
+        name: "Bolivia",
+        num: Some("068"),
+        validity: until(2009, 3, 18),
+    },
+    HistoricalEntry {
+        country: Some(CI),
+        alpha2: "CI",
+        alpha3: "CIV",
+        name: "Ivory Coast",
+        num: Some("384"),
+        validity: until(1985, 10, 14),
+    },
+    HistoricalEntry {
+        country: Some(CV),
+        alpha2: "CV",
+        alpha3: "CPV",
+        name: "Cape Verde",
+        num: Some("132"),
+        validity: until(2013, 10, 24),
+    },
+    HistoricalEntry {
+        country: Some(DE),
+        alpha2: "DE",
+        alpha3: "DEU",
+        name: "Germany, Federal Republic of",
+        num: Some("280"),
+        validity: until(1990, 10, 3),
+    },
+    HistoricalEntry {
+        country: Some(ET),
+        alpha2: "ET",
+        alpha3: "ETH",
+        name: "Ethiopia",
+        num: Some("230"),
+        validity: until(1993, 5, 24),
+    },
+    HistoricalEntry {
+        country: Some(KH),
+        alpha2: "KH",
+        alpha3: "KHM",
+        name: "Khmer Republic",
+        num: Some("116"),
+        validity: until(1976, 1, 5),
+    },
+    HistoricalEntry {
+        country: Some(KH),
+        alpha2: "KH",
+        alpha3: "KHM",
+        name: "Kampuchea, Democratic",
+        num: Some("116"),
+        validity: between(Date::new(1976, 1, 5), Date::new(1989, 5, 1)),
+    },
+    HistoricalEntry {
+        country: Some(LA),
+        alpha2: "LA",
+        alpha3: "LAO",
+        name: "Laos",
+        num: Some("418"),
+        validity: until(1975, 12, 2),
+    },
+    HistoricalEntry {
+        country: Some(LY),
+        alpha2: "LY",
+        alpha3: "LBY",
+        name: "Libyan Arab Republic",
+        num: Some("434"),
+        validity: until(1977, 3, 2),
+    },
+    HistoricalEntry {
+        country: Some(LY),
+        alpha2: "LY",
+        alpha3: "LBY",
+        name: "Libyan Arab Jamahiriya",
+        num: Some("434"),
+        validity: between(Date::new(1977, 3, 2), Date::new(2011, 9, 16)),
+    },
+    HistoricalEntry {
+        country: Some(PS),
+        alpha2: "PS",
+        alpha3: "PSE",
+        name: "Palestinian Territory, Occupied",
+        num: Some("275"),
+        validity: between(Date::new(1999, 1, 1), Date::new(2013, 2, 6)),
+    },
+    HistoricalEntry {
+        country: Some(RO),
+        alpha2: "RO",
+        alpha3: "ROM",
+        name: "Romania",
+        num: Some("642"),
+        validity: until(2002, 1, 1),
+    },
+    HistoricalEntry {
+        country: Some(SD),
+        alpha2: "SD",
+        alpha3: "SDN",
+        name: "Sudan",
+        num: Some("736"),
+        validity: until(2011, 8, 9),
+    },
+    HistoricalEntry {
+        country: Some(SH),
+        alpha2: "SH",
+        alpha3: "SHN",
+        name: "Saint Helena",
+        num: Some("654"),
+        validity: until(2009, 9, 1),
+    },
+    HistoricalEntry {
+        country: Some(UA),
+        alpha2: "UA",
+        alpha3: "UKR",
+        name: "Ukrainian SSR",
+        num: Some("804"),
+        validity: until(1991, 12, 26),
+    },
+    HistoricalEntry {
+        country: Some(VE),
+        alpha2: "VE",
+        alpha3: "VEN",
+        name: "Venezuela",
+        num: Some("862"),
+        validity: until(2009, 1, 1),
+    },
+    HistoricalEntry {
+        country: Some(VN),
+        alpha2: "VN",
+        alpha3: "VNM",
+        name: "Viet-Nam, Republic of",
+        num: Some("704"),
+        validity: until(1976, 7, 2),
+    },
+    HistoricalEntry {
+        country: Some(WS),
+        alpha2: "WS",
+        alpha3: "WSM",
+        name: "Western Samoa",
+        num: Some("882"),
+        validity: until(1997, 7, 4),
+    },
+    HistoricalEntry {
+        country: Some(YE),
+        alpha2: "YE",
+        alpha3: "YEM",
+        name: "Yemen",
+        num: Some("886"),
+        validity: until(1990, 5, 22),
+    },
+];
+
+// Names adopted since the table of entries was last updated, with the day
+// each was adopted. The table keeps the former names, so that `CountryCode`s
+// stored with them still convert into a `Country`.
+static RENAMED: &[(Country, &str, Date)] = &[
+    (CZ, "Czechia", Date::new(2016, 7, 5)),
+    (SZ, "Eswatini", Date::new(2018, 4, 19)),
+    (MK, "North Macedonia", Date::new(2019, 2, 12)),
+    (TR, "Türkiye", Date::new(2022, 6, 1)),
+];
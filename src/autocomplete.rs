@@ -0,0 +1,233 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 3166-1?
+//
+// | ISO 3166-1 is part of the ISO 3166 standard published by the International
+// | Organization for Standardization (ISO), and defines codes for the names of
+// | countries, dependent territories, and special areas of geographical
+// | interest.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_3166-1)
+//
+// Originally by zeyla on GitHub.
+
+// Typeahead over the names, aliases and codes of countries.
+//
+// The index holds every folded name and alias once for each word in it, so a
+// prefix finds names by any of their words, and every code. It is sorted, so
+// the keys starting with a prefix are found by binary search. It is built on
+// first use, and shared from then on.
+
+use codes::ENTRIES;
+use std::sync::OnceLock;
+use {fold, CountryCode};
+
+/// A country suggested by [`autocomplete`], with the text that matched.
+///
+/// [`autocomplete`]: fn.autocomplete.html
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Completion {
+    /// The country suggested
+    pub code: CountryCode,
+    /// The name, alias or code of the country that starts with the prefix
+    pub matched: &'static str,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+enum Source {
+    Name,
+    Alias,
+    Code,
+}
+
+#[derive(Debug)]
+struct Key {
+    // The folded text, from the start of one of its words.
+    text: String,
+    // The position of the entry in `ENTRIES`.
+    entry: usize,
+    source: Source,
+    matched: &'static str,
+    // The number of the word the text starts at.
+    word: usize,
+}
+
+// Words that start no key unless they start the name, being too common in names
+// to tell countries apart.
+const STOP_WORDS: &[&str] = &[
+    "and",
+    "da",
+    "democratic",
+    "federated",
+    "federation",
+    "island",
+    "islands",
+    "kingdom",
+    "of",
+    "part",
+    "people",
+    "province",
+    "republic",
+    "state",
+    "states",
+    "territories",
+    "territory",
+    "the",
+    "united",
+];
+
+static INDEX: OnceLock<Vec<Key>> = OnceLock::new();
+
+/// Returns up to `limit` countries with a name, alias or code starting with
+/// the prefix, or with a word in their name or alias starting with it, best
+/// first.
+///
+/// The prefix is matched as [`name_normalized`] matches names, ignoring
+/// accents, letter case and punctuation. Exact matches come first, then names
+/// and aliases starting with the prefix, then those with a later word starting
+/// with it, and then codes starting with it. Each country is suggested once.
+///
+/// [`name_normalized`]: fn.name_normalized.html
+///
+/// # Examples
+///
+/// ```rust
+/// let completions = iso3166_1::autocomplete("kor", 5);
+///
+/// assert_eq!(completions[0].code.alpha2, "KR");
+/// assert_eq!(completions[1].matched, "Korea (Democratic People's Republic of)");
+///
+/// let completions = iso3166_1::autocomplete("Bissau", 5);
+///
+/// assert_eq!(completions[0].code.alpha2, "GW");
+/// ```
+pub fn autocomplete(prefix: &str, limit: usize) -> Vec<Completion> {
+    let prefix: String = fold::fold(prefix).collect();
+
+    if prefix.is_empty() {
+        return Vec::new();
+    }
+
+    let index = INDEX.get_or_init(build);
+    let start = index.partition_point(|key| key.text < prefix);
+    let mut found: Vec<(Rank, &Key)> = index[start..]
+        .iter()
+        .take_while(|key| key.text.starts_with(&prefix))
+        .map(|key| (rank(key, &prefix), key))
+        .collect();
+
+    found.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let mut completions: Vec<Completion> = Vec::new();
+
+    for (_, key) in found {
+        if completions.len() == limit {
+            break;
+        }
+
+        let entry = &ENTRIES[key.entry];
+
+        if completions.iter().all(|c| c.code.alpha2 != entry.alpha2) {
+            completions.push(Completion {
+                code: CountryCode::from(entry),
+                matched: key.matched,
+            });
+        }
+    }
+
+    completions
+}
+
+// How well a key matches, best first: the tier of the match, the word it
+// matched at, and the length and text of what it matched.
+type Rank = (u8, usize, usize, &'static str);
+
+fn rank(key: &Key, prefix: &str) -> Rank {
+    let exact = key.word == 0 && key.text.len() == prefix.len();
+    let tier = match (key.source, key.word) {
+        _ if exact => 0,
+        (Source::Name, 0) => 1,
+        (Source::Alias, 0) => 2,
+        (Source::Name, _) => 3,
+        (Source::Alias, _) => 4,
+        (Source::Code, _) => 5,
+    };
+
+    (tier, key.word, key.matched.len(), key.matched)
+}
+
+fn build() -> Vec<Key> {
+    let mut index = Vec::new();
+
+    for (position, entry) in ENTRIES.iter().enumerate() {
+        let names = Some(&entry.name)
+            .into_iter()
+            .map(|name| (Source::Name, name));
+        let aliases = entry.aliases().iter().map(|alias| (Source::Alias, alias));
+
+        for (source, &matched) in names.chain(aliases) {
+            let text: String = fold::fold(matched).collect();
+
+            for (word, start) in word_starts(&text).enumerate() {
+                index.push(Key {
+                    text: text[start..].to_owned(),
+                    entry: position,
+                    source,
+                    matched,
+                    word,
+                });
+            }
+        }
+
+        for &matched in &[entry.alpha2, entry.alpha3] {
+            index.push(Key {
+                text: matched.to_ascii_lowercase(),
+                entry: position,
+                source: Source::Code,
+                matched,
+                word: 0,
+            });
+        }
+    }
+
+    index.sort_by(|a, b| a.text.cmp(&b.text));
+
+    index
+}
+
+// Returns the byte offsets of the words in a folded name, skipping stop words
+// and single letters, as the "d" of "Côte d'Ivoire", after the first one.
+fn word_starts(text: &str) -> impl Iterator<Item = usize> + '_ {
+    let mut previous = ' ';
+
+    text.char_indices()
+        .filter(move |&(_, c)| {
+            let starts = c.is_alphanumeric() && !previous.is_alphanumeric();
+
+            previous = c;
+            starts
+        })
+        .map(|(start, _)| start)
+        .filter(move |&start| {
+            let word = text[start..]
+                .split(|c: char| !c.is_alphanumeric())
+                .next()
+                .unwrap_or("");
+
+            start == 0 || (word.chars().nth(1).is_some() && !STOP_WORDS.contains(&word))
+        })
+}
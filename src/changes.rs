@@ -0,0 +1,815 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 3166-1?
+//
+// | ISO 3166-1 is part of the ISO 3166 standard published by the International
+// | Organization for Standardization (ISO), and defines codes for the names of
+// | countries, dependent territories, and special areas of geographical
+// | interest.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_3166-1)
+//
+// Originally by zeyla on GitHub.
+
+// The changes made to ISO 3166-1 since its first edition in 1974, in the order
+// they were made.
+//
+// These are built from the history in `as_of` and `iso3166_3`, along with
+// `DATA_VERSION`, by running 'make update-changes'. The 'Begin' and 'End'
+// comments tell the update script where to insert the changes.
+
+use Date;
+
+/// The date of the latest change to ISO 3166-1 reflected in the table of
+/// entries.
+///
+/// Later changes are renames kept in the history alone, such as that of Turkey
+/// to Türkiye, as the table keeps the former names.
+///
+/// Comparing it with the version a dataset was stored at gives the changes
+/// to apply to it, with [`changes_between`].
+///
+/// [`changes_between`]: fn.changes_between.html
+pub const DATA_VERSION: Date = Date::new(2013, 10, 24);
+
+/// A change made to ISO 3166-1.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Change {
+    /// The date the change took effect
+    pub date: Date,
+    /// The Alpha2 code the change applies to
+    pub alpha2: &'static str,
+    /// What changed
+    pub kind: ChangeKind,
+    /// Where the change is recorded, such as the ISO 3166-3 code of a
+    /// withdrawn country
+    pub reference: &'static str,
+}
+
+/// What a `Change` changed.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ChangeKind {
+    /// Codes were assigned to a country.
+    Added {
+        /// The name the country was added with
+        name: &'static str,
+    },
+    /// The codes of a country were withdrawn.
+    Removed {
+        /// The name the country had when it was removed
+        name: &'static str,
+    },
+    /// The Alpha3 code of a country changed.
+    Alpha3Changed {
+        /// The previous Alpha3 code
+        from: &'static str,
+        /// The new Alpha3 code
+        to: &'static str,
+    },
+    /// The numeric code of a country changed.
+    NumericChanged {
+        /// The previous numeric code
+        from: &'static str,
+        /// The new numeric code
+        to: &'static str,
+    },
+    /// The short name of a country changed.
+    NameChanged {
+        /// The previous name
+        from: &'static str,
+        /// The new name
+        to: &'static str,
+    },
+}
+
+/// Returns every change made to ISO 3166-1 since its first edition, ordered
+/// by date.
+pub fn changes() -> &'static [Change] {
+    CHANGES
+}
+
+/// Returns the changes made after `from`, up to and including `to`, ordered
+/// by date.
+///
+/// Applying them to the dataset as it stood on `from` gives the dataset as it
+/// stood on `to`.
+///
+/// # Examples
+///
+/// ```rust
+/// use iso3166_1::{changes_between, ChangeKind, Date};
+///
+/// let changes = changes_between(Date::new(2010, 1, 1), Date::new(2012, 1, 1));
+/// let renumbered = ChangeKind::NumericChanged {
+///     from: "736",
+///     to: "729",
+/// };
+///
+/// assert!(changes.iter().any(|change| change.kind == renumbered));
+/// ```
+pub fn changes_between(from: Date, to: Date) -> &'static [Change] {
+    let start = CHANGES.partition_point(|change| change.date <= from);
+    let end = CHANGES.partition_point(|change| change.date <= to);
+
+    CHANGES.get(start..end).unwrap_or(&[])
+}
+
+static CHANGES: &[Change] = &[
+    // Begin changes
+    Change {
+        date: Date::new(1975, 5, 16),
+        alpha2: "SK",
+        kind: ChangeKind::Removed { name: "Sikkim" },
+        reference: "ISO 3166-3 SKIN",
+    },
+    Change {
+        date: Date::new(1975, 11, 30),
+        alpha2: "DY",
+        kind: ChangeKind::Removed { name: "Dahomey" },
+        reference: "ISO 3166-3 DYBJ",
+    },
+    Change {
+        date: Date::new(1975, 11, 30),
+        alpha2: "BJ",
+        kind: ChangeKind::Added { name: "Benin" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:BJ",
+    },
+    Change {
+        date: Date::new(1975, 12, 2),
+        alpha2: "LA",
+        kind: ChangeKind::NameChanged { from: "Laos", to: "Lao People's Democratic Republic" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:LA",
+    },
+    Change {
+        date: Date::new(1976, 1, 5),
+        alpha2: "KH",
+        kind: ChangeKind::NameChanged { from: "Khmer Republic", to: "Kampuchea, Democratic" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:KH",
+    },
+    Change {
+        date: Date::new(1976, 7, 2),
+        alpha2: "VD",
+        kind: ChangeKind::Removed { name: "Viet-Nam, Democratic Republic of" },
+        reference: "ISO 3166-3 VDVN",
+    },
+    Change {
+        date: Date::new(1976, 7, 2),
+        alpha2: "VN",
+        kind: ChangeKind::NameChanged { from: "Viet-Nam, Republic of", to: "Viet Nam" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:VN",
+    },
+    Change {
+        date: Date::new(1977, 1, 1),
+        alpha2: "TV",
+        kind: ChangeKind::Added { name: "Tuvalu" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:TV",
+    },
+    Change {
+        date: Date::new(1977, 3, 2),
+        alpha2: "LY",
+        kind: ChangeKind::NameChanged {
+            from: "Libyan Arab Republic",
+            to: "Libyan Arab Jamahiriya",
+        },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:LY",
+    },
+    Change {
+        date: Date::new(1977, 6, 27),
+        alpha2: "AI",
+        kind: ChangeKind::Removed { name: "French Afars and Issas" },
+        reference: "ISO 3166-3 AIDJ",
+    },
+    Change {
+        date: Date::new(1977, 6, 27),
+        alpha2: "DJ",
+        kind: ChangeKind::Added { name: "Djibouti" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:DJ",
+    },
+    Change {
+        date: Date::new(1979, 1, 1),
+        alpha2: "BQ",
+        kind: ChangeKind::Removed { name: "British Antarctic Territory" },
+        reference: "ISO 3166-3 BQAQ",
+    },
+    Change {
+        date: Date::new(1979, 1, 1),
+        alpha2: "FQ",
+        kind: ChangeKind::Removed { name: "French Southern and Antarctic Territories" },
+        reference: "ISO 3166-3 FQHH",
+    },
+    Change {
+        date: Date::new(1979, 1, 1),
+        alpha2: "GE",
+        kind: ChangeKind::Removed { name: "Gilbert and Ellice Islands" },
+        reference: "ISO 3166-3 GEHH",
+    },
+    Change {
+        date: Date::new(1979, 1, 1),
+        alpha2: "KI",
+        kind: ChangeKind::Added { name: "Kiribati" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:KI",
+    },
+    Change {
+        date: Date::new(1979, 1, 1),
+        alpha2: "TF",
+        kind: ChangeKind::Added { name: "French Southern Territories" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:TF",
+    },
+    Change {
+        date: Date::new(1979, 10, 1),
+        alpha2: "PZ",
+        kind: ChangeKind::Removed { name: "Panama Canal Zone" },
+        reference: "ISO 3166-3 PZPA",
+    },
+    Change {
+        date: Date::new(1980, 4, 18),
+        alpha2: "RH",
+        kind: ChangeKind::Removed { name: "Southern Rhodesia" },
+        reference: "ISO 3166-3 RHZW",
+    },
+    Change {
+        date: Date::new(1980, 4, 18),
+        alpha2: "ZW",
+        kind: ChangeKind::Added { name: "Zimbabwe" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:ZW",
+    },
+    Change {
+        date: Date::new(1980, 7, 30),
+        alpha2: "NH",
+        kind: ChangeKind::Removed { name: "New Hebrides" },
+        reference: "ISO 3166-3 NHVU",
+    },
+    Change {
+        date: Date::new(1980, 7, 30),
+        alpha2: "VU",
+        kind: ChangeKind::Added { name: "Vanuatu" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:VU",
+    },
+    Change {
+        date: Date::new(1983, 1, 1),
+        alpha2: "NQ",
+        kind: ChangeKind::Removed { name: "Dronning Maud Land" },
+        reference: "ISO 3166-3 NQAQ",
+    },
+    Change {
+        date: Date::new(1984, 1, 1),
+        alpha2: "CT",
+        kind: ChangeKind::Removed { name: "Canton and Enderbury Islands" },
+        reference: "ISO 3166-3 CTKI",
+    },
+    Change {
+        date: Date::new(1984, 8, 4),
+        alpha2: "HV",
+        kind: ChangeKind::Removed { name: "Upper Volta" },
+        reference: "ISO 3166-3 HVBF",
+    },
+    Change {
+        date: Date::new(1984, 8, 4),
+        alpha2: "BF",
+        kind: ChangeKind::Added { name: "Burkina Faso" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:BF",
+    },
+    Change {
+        date: Date::new(1985, 1, 1),
+        alpha2: "AI",
+        kind: ChangeKind::Added { name: "Anguilla" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:AI",
+    },
+    Change {
+        date: Date::new(1985, 10, 14),
+        alpha2: "CI",
+        kind: ChangeKind::NameChanged { from: "Ivory Coast", to: "Côte d'Ivoire" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:CI",
+    },
+    Change {
+        date: Date::new(1986, 1, 1),
+        alpha2: "JT",
+        kind: ChangeKind::Removed { name: "Johnston Island" },
+        reference: "ISO 3166-3 JTUM",
+    },
+    Change {
+        date: Date::new(1986, 1, 1),
+        alpha2: "MI",
+        kind: ChangeKind::Removed { name: "Midway Islands" },
+        reference: "ISO 3166-3 MIUM",
+    },
+    Change {
+        date: Date::new(1986, 1, 1),
+        alpha2: "PC",
+        kind: ChangeKind::Removed { name: "Pacific Islands (Trust Territory)" },
+        reference: "ISO 3166-3 PCHH",
+    },
+    Change {
+        date: Date::new(1986, 1, 1),
+        alpha2: "PU",
+        kind: ChangeKind::Removed { name: "United States Miscellaneous Pacific Islands" },
+        reference: "ISO 3166-3 PUUM",
+    },
+    Change {
+        date: Date::new(1986, 1, 1),
+        alpha2: "WK",
+        kind: ChangeKind::Removed { name: "Wake Island" },
+        reference: "ISO 3166-3 WKUM",
+    },
+    Change {
+        date: Date::new(1986, 1, 1),
+        alpha2: "AW",
+        kind: ChangeKind::Added { name: "Aruba" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:AW",
+    },
+    Change {
+        date: Date::new(1986, 1, 1),
+        alpha2: "FM",
+        kind: ChangeKind::Added { name: "Micronesia (Federated States of)" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:FM",
+    },
+    Change {
+        date: Date::new(1986, 1, 1),
+        alpha2: "MH",
+        kind: ChangeKind::Added { name: "Marshall Islands" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:MH",
+    },
+    Change {
+        date: Date::new(1986, 1, 1),
+        alpha2: "MP",
+        kind: ChangeKind::Added { name: "Northern Mariana Islands" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:MP",
+    },
+    Change {
+        date: Date::new(1986, 1, 1),
+        alpha2: "PW",
+        kind: ChangeKind::Added { name: "Palau" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:PW",
+    },
+    Change {
+        date: Date::new(1986, 1, 1),
+        alpha2: "UM",
+        kind: ChangeKind::Added { name: "United States Minor Outlying Islands" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:UM",
+    },
+    Change {
+        date: Date::new(1989, 5, 1),
+        alpha2: "KH",
+        kind: ChangeKind::NameChanged { from: "Kampuchea, Democratic", to: "Cambodia" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:KH",
+    },
+    Change {
+        date: Date::new(1989, 6, 18),
+        alpha2: "BU",
+        kind: ChangeKind::Removed { name: "Burma" },
+        reference: "ISO 3166-3 BUMM",
+    },
+    Change {
+        date: Date::new(1989, 6, 18),
+        alpha2: "MM",
+        kind: ChangeKind::Added { name: "Myanmar" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:MM",
+    },
+    Change {
+        date: Date::new(1990, 5, 22),
+        alpha2: "YD",
+        kind: ChangeKind::Removed { name: "Yemen, Democratic" },
+        reference: "ISO 3166-3 YDYE",
+    },
+    Change {
+        date: Date::new(1990, 5, 22),
+        alpha2: "YE",
+        kind: ChangeKind::NumericChanged { from: "886", to: "887" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:YE",
+    },
+    Change {
+        date: Date::new(1990, 10, 3),
+        alpha2: "DD",
+        kind: ChangeKind::Removed { name: "German Democratic Republic" },
+        reference: "ISO 3166-3 DDDE",
+    },
+    Change {
+        date: Date::new(1990, 10, 3),
+        alpha2: "DE",
+        kind: ChangeKind::NumericChanged { from: "280", to: "276" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:DE",
+    },
+    Change {
+        date: Date::new(1990, 10, 3),
+        alpha2: "DE",
+        kind: ChangeKind::NameChanged { from: "Germany, Federal Republic of", to: "Germany" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:DE",
+    },
+    Change {
+        date: Date::new(1991, 12, 26),
+        alpha2: "BY",
+        kind: ChangeKind::Removed { name: "Byelorussian SSR" },
+        reference: "ISO 3166-3 BYAA",
+    },
+    Change {
+        date: Date::new(1991, 12, 26),
+        alpha2: "SU",
+        kind: ChangeKind::Removed { name: "USSR" },
+        reference: "ISO 3166-3 SUHH",
+    },
+    Change {
+        date: Date::new(1991, 12, 26),
+        alpha2: "AM",
+        kind: ChangeKind::Added { name: "Armenia" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:AM",
+    },
+    Change {
+        date: Date::new(1991, 12, 26),
+        alpha2: "AZ",
+        kind: ChangeKind::Added { name: "Azerbaijan" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:AZ",
+    },
+    Change {
+        date: Date::new(1991, 12, 26),
+        alpha2: "BY",
+        kind: ChangeKind::Added { name: "Belarus" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:BY",
+    },
+    Change {
+        date: Date::new(1991, 12, 26),
+        alpha2: "EE",
+        kind: ChangeKind::Added { name: "Estonia" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:EE",
+    },
+    Change {
+        date: Date::new(1991, 12, 26),
+        alpha2: "GE",
+        kind: ChangeKind::Added { name: "Georgia" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:GE",
+    },
+    Change {
+        date: Date::new(1991, 12, 26),
+        alpha2: "KG",
+        kind: ChangeKind::Added { name: "Kyrgyzstan" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:KG",
+    },
+    Change {
+        date: Date::new(1991, 12, 26),
+        alpha2: "KZ",
+        kind: ChangeKind::Added { name: "Kazakhstan" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:KZ",
+    },
+    Change {
+        date: Date::new(1991, 12, 26),
+        alpha2: "LT",
+        kind: ChangeKind::Added { name: "Lithuania" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:LT",
+    },
+    Change {
+        date: Date::new(1991, 12, 26),
+        alpha2: "LV",
+        kind: ChangeKind::Added { name: "Latvia" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:LV",
+    },
+    Change {
+        date: Date::new(1991, 12, 26),
+        alpha2: "MD",
+        kind: ChangeKind::Added { name: "Moldova (Republic of)" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:MD",
+    },
+    Change {
+        date: Date::new(1991, 12, 26),
+        alpha2: "RU",
+        kind: ChangeKind::Added { name: "Russian Federation" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:RU",
+    },
+    Change {
+        date: Date::new(1991, 12, 26),
+        alpha2: "TJ",
+        kind: ChangeKind::Added { name: "Tajikistan" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:TJ",
+    },
+    Change {
+        date: Date::new(1991, 12, 26),
+        alpha2: "TM",
+        kind: ChangeKind::Added { name: "Turkmenistan" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:TM",
+    },
+    Change {
+        date: Date::new(1991, 12, 26),
+        alpha2: "UZ",
+        kind: ChangeKind::Added { name: "Uzbekistan" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:UZ",
+    },
+    Change {
+        date: Date::new(1991, 12, 26),
+        alpha2: "UA",
+        kind: ChangeKind::NameChanged { from: "Ukrainian SSR", to: "Ukraine" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:UA",
+    },
+    Change {
+        date: Date::new(1992, 1, 1),
+        alpha2: "BA",
+        kind: ChangeKind::Added { name: "Bosnia and Herzegovina" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:BA",
+    },
+    Change {
+        date: Date::new(1992, 1, 1),
+        alpha2: "HR",
+        kind: ChangeKind::Added { name: "Croatia" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:HR",
+    },
+    Change {
+        date: Date::new(1992, 1, 1),
+        alpha2: "SI",
+        kind: ChangeKind::Added { name: "Slovenia" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:SI",
+    },
+    Change {
+        date: Date::new(1993, 1, 1),
+        alpha2: "CS",
+        kind: ChangeKind::Removed { name: "Czechoslovakia" },
+        reference: "ISO 3166-3 CSHH",
+    },
+    Change {
+        date: Date::new(1993, 1, 1),
+        alpha2: "NT",
+        kind: ChangeKind::Removed { name: "Neutral Zone" },
+        reference: "ISO 3166-3 NTHH",
+    },
+    Change {
+        date: Date::new(1993, 1, 1),
+        alpha2: "CZ",
+        kind: ChangeKind::Added { name: "Czech Republic" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:CZ",
+    },
+    Change {
+        date: Date::new(1993, 1, 1),
+        alpha2: "FX",
+        kind: ChangeKind::Added { name: "France, Metropolitan" },
+        reference: "ISO 3166-3 FXFR",
+    },
+    Change {
+        date: Date::new(1993, 1, 1),
+        alpha2: "GS",
+        kind: ChangeKind::Added { name: "South Georgia and the South Sandwich Islands" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:GS",
+    },
+    Change {
+        date: Date::new(1993, 1, 1),
+        alpha2: "MK",
+        kind: ChangeKind::Added { name: "Macedonia (the former Yugoslav Republic of)" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:MK",
+    },
+    Change {
+        date: Date::new(1993, 1, 1),
+        alpha2: "SK",
+        kind: ChangeKind::Added { name: "Slovakia" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:SK",
+    },
+    Change {
+        date: Date::new(1993, 1, 1),
+        alpha2: "YT",
+        kind: ChangeKind::Added { name: "Mayotte" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:YT",
+    },
+    Change {
+        date: Date::new(1993, 5, 24),
+        alpha2: "ER",
+        kind: ChangeKind::Added { name: "Eritrea" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:ER",
+    },
+    Change {
+        date: Date::new(1993, 5, 24),
+        alpha2: "ET",
+        kind: ChangeKind::NumericChanged { from: "230", to: "231" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:ET",
+    },
+    Change {
+        date: Date::new(1997, 1, 1),
+        alpha2: "FX",
+        kind: ChangeKind::Removed { name: "France, Metropolitan" },
+        reference: "ISO 3166-3 FXFR",
+    },
+    Change {
+        date: Date::new(1997, 5, 17),
+        alpha2: "ZR",
+        kind: ChangeKind::Removed { name: "Zaire" },
+        reference: "ISO 3166-3 ZRCD",
+    },
+    Change {
+        date: Date::new(1997, 5, 17),
+        alpha2: "CD",
+        kind: ChangeKind::Added { name: "Congo (Democratic Republic of the)" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:CD",
+    },
+    Change {
+        date: Date::new(1997, 7, 4),
+        alpha2: "WS",
+        kind: ChangeKind::NameChanged { from: "Western Samoa", to: "Samoa" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:WS",
+    },
+    Change {
+        date: Date::new(1999, 1, 1),
+        alpha2: "PS",
+        kind: ChangeKind::Added { name: "Palestinian Territory, Occupied" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:PS",
+    },
+    Change {
+        date: Date::new(2002, 1, 1),
+        alpha2: "RO",
+        kind: ChangeKind::Alpha3Changed { from: "ROM", to: "ROU" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:RO",
+    },
+    Change {
+        date: Date::new(2002, 5, 20),
+        alpha2: "TP",
+        kind: ChangeKind::Removed { name: "East Timor" },
+        reference: "ISO 3166-3 TPTL",
+    },
+    Change {
+        date: Date::new(2002, 5, 20),
+        alpha2: "TL",
+        kind: ChangeKind::Added { name: "Timor-Leste" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:TL",
+    },
+    Change {
+        date: Date::new(2003, 7, 23),
+        alpha2: "YU",
+        kind: ChangeKind::Removed { name: "Yugoslavia" },
+        reference: "ISO 3166-3 YUCS",
+    },
+    Change {
+        date: Date::new(2003, 7, 23),
+        alpha2: "CS",
+        kind: ChangeKind::Added { name: "Serbia and Montenegro" },
+        reference: "ISO 3166-3 CSXX",
+    },
+    Change {
+        date: Date::new(2004, 2, 13),
+        alpha2: "AX",
+        kind: ChangeKind::Added { name: "Åland Islands" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:AX",
+    },
+    Change {
+        date: Date::new(2006, 3, 29),
+        alpha2: "GG",
+        kind: ChangeKind::Added { name: "Guernsey" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:GG",
+    },
+    Change {
+        date: Date::new(2006, 3, 29),
+        alpha2: "IM",
+        kind: ChangeKind::Added { name: "Isle of Man" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:IM",
+    },
+    Change {
+        date: Date::new(2006, 3, 29),
+        alpha2: "JE",
+        kind: ChangeKind::Added { name: "Jersey" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:JE",
+    },
+    Change {
+        date: Date::new(2006, 9, 26),
+        alpha2: "CS",
+        kind: ChangeKind::Removed { name: "Serbia and Montenegro" },
+        reference: "ISO 3166-3 CSXX",
+    },
+    Change {
+        date: Date::new(2006, 9, 26),
+        alpha2: "ME",
+        kind: ChangeKind::Added { name: "Montenegro" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:ME",
+    },
+    Change {
+        date: Date::new(2006, 9, 26),
+        alpha2: "RS",
+        kind: ChangeKind::Added { name: "Serbia" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:RS",
+    },
+    Change {
+        date: Date::new(2007, 9, 21),
+        alpha2: "BL",
+        kind: ChangeKind::Added { name: "Saint Barthélemy" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:BL",
+    },
+    Change {
+        date: Date::new(2007, 9, 21),
+        alpha2: "MF",
+        kind: ChangeKind::Added { name: "Saint Martin (French part)" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:MF",
+    },
+    Change {
+        date: Date::new(2009, 1, 1),
+        alpha2: "VE",
+        kind: ChangeKind::NameChanged {
+            from: "Venezuela",
+            to: "Venezuela (Bolivarian Republic of)",
+        },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:VE",
+    },
+    Change {
+        date: Date::new(2009, 3, 18),
+        alpha2: "BO",
+        kind: ChangeKind::NameChanged { from: "Bolivia", to: "Bolivia (Plurinational State of)" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:BO",
+    },
+    Change {
+        date: Date::new(2009, 9, 1),
+        alpha2: "SH",
+        kind: ChangeKind::NameChanged {
+            from: "Saint Helena",
+            to: "Saint Helena, Ascension and Tristan da Cunha",
+        },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:SH",
+    },
+    Change {
+        date: Date::new(2010, 12, 15),
+        alpha2: "AN",
+        kind: ChangeKind::Removed { name: "Netherlands Antilles" },
+        reference: "ISO 3166-3 ANHH",
+    },
+    Change {
+        date: Date::new(2010, 12, 15),
+        alpha2: "BQ",
+        kind: ChangeKind::Added { name: "Bonaire, Sint Eustatius and Saba" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:BQ",
+    },
+    Change {
+        date: Date::new(2010, 12, 15),
+        alpha2: "CW",
+        kind: ChangeKind::Added { name: "Curaçao" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:CW",
+    },
+    Change {
+        date: Date::new(2010, 12, 15),
+        alpha2: "SX",
+        kind: ChangeKind::Added { name: "Sint Maarten (Dutch part)" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:SX",
+    },
+    Change {
+        date: Date::new(2011, 8, 9),
+        alpha2: "SS",
+        kind: ChangeKind::Added { name: "South Sudan" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:SS",
+    },
+    Change {
+        date: Date::new(2011, 8, 9),
+        alpha2: "SD",
+        kind: ChangeKind::NumericChanged { from: "736", to: "729" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:SD",
+    },
+    Change {
+        date: Date::new(2011, 9, 16),
+        alpha2: "LY",
+        kind: ChangeKind::NameChanged { from: "Libyan Arab Jamahiriya", to: "Libya" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:LY",
+    },
+    Change {
+        date: Date::new(2013, 2, 6),
+        alpha2: "PS",
+        kind: ChangeKind::NameChanged {
+            from: "Palestinian Territory, Occupied",
+            to: "Palestine, State of",
+        },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:PS",
+    },
+    Change {
+        date: Date::new(2013, 10, 24),
+        alpha2: "CV",
+        kind: ChangeKind::NameChanged { from: "Cape Verde", to: "Cabo Verde" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:CV",
+    },
+    Change {
+        date: Date::new(2016, 7, 5),
+        alpha2: "CZ",
+        kind: ChangeKind::NameChanged { from: "Czech Republic", to: "Czechia" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:CZ",
+    },
+    Change {
+        date: Date::new(2018, 4, 19),
+        alpha2: "SZ",
+        kind: ChangeKind::NameChanged { from: "Swaziland", to: "Eswatini" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:SZ",
+    },
+    Change {
+        date: Date::new(2019, 2, 12),
+        alpha2: "MK",
+        kind: ChangeKind::NameChanged {
+            from: "Macedonia (the former Yugoslav Republic of)",
+            to: "North Macedonia",
+        },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:MK",
+    },
+    Change {
+        date: Date::new(2022, 6, 1),
+        alpha2: "TR",
+        kind: ChangeKind::NameChanged { from: "Turkey", to: "Türkiye" },
+        reference: "https://www.iso.org/obp/ui/#iso:code:3166:TR",
+    },
+    // End changes
+];
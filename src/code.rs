@@ -0,0 +1,333 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 3166-1?
+//
+// | ISO 3166-1 is part of the ISO 3166 standard published by the International
+// | Organization for Standardization (ISO), and defines codes for the names of
+// | countries, dependent territories, and special areas of geographical
+// | interest.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_3166-1)
+//
+// Originally by zeyla on GitHub.
+
+use core::convert::TryFrom;
+use core::fmt::{Display, Formatter, Result as FmtResult};
+use core::str::{self, FromStr};
+use index;
+use {is_user_assigned, Country};
+
+/// An error returned when a string or number does not have the shape of an
+/// ISO 3166-1 code.
+///
+/// This only describes the shape of the code: a well-formed code is not
+/// necessarily assigned to a country.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum CodeError {
+    /// The code did not have the expected number of characters or digits.
+    Length {
+        /// The number of characters or digits the code must have
+        expected: usize,
+        /// The number of characters or digits the code had
+        found: usize,
+    },
+    /// The code contained a character it may not, such as a lowercase letter
+    /// in an Alpha2 code or a letter in a numeric code.
+    Character(char),
+}
+
+impl Display for CodeError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match *self {
+            CodeError::Length { expected, found } => write!(
+                f,
+                "expected a code of {} characters, found {}",
+                expected, found
+            ),
+            CodeError::Character(c) => write!(f, "invalid character {:?} in code", c),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl ::std::error::Error for CodeError {}
+
+/// A two-character Alpha2 code, such as `"DE"`.
+///
+/// An `Alpha2` is always made of two uppercase ASCII letters, but it is not
+/// necessarily assigned to a country. Use [`country`] to check that.
+///
+/// [`country`]: #method.country
+///
+/// # Examples
+///
+/// ```rust
+/// use iso3166_1::{Alpha2, Country};
+///
+/// let code: Alpha2 = "DE".parse().unwrap();
+///
+/// assert_eq!(code.country(), Some(Country::DE));
+/// assert_eq!(code.to_alpha3().unwrap().as_str(), "DEU");
+/// assert!("de".parse::<Alpha2>().is_err());
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Alpha2([u8; 2]);
+
+/// A three-character Alpha3 code, such as `"DEU"`.
+///
+/// An `Alpha3` is always made of three uppercase ASCII letters, but it is not
+/// necessarily assigned to a country. Use [`country`] to check that.
+///
+/// [`country`]: #method.country
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Alpha3([u8; 3]);
+
+/// A three-digit numeric code, such as `"276"`.
+///
+/// A `Numeric` is always made of three ASCII digits, but it is not
+/// necessarily assigned to a country. Use [`country`] to check that.
+///
+/// [`country`]: #method.country
+///
+/// # Examples
+///
+/// ```rust
+/// use iso3166_1::Numeric;
+/// use std::convert::TryFrom;
+///
+/// let code = Numeric::try_from(4).unwrap();
+///
+/// assert_eq!(code.as_str(), "004");
+/// assert_eq!(code.to_alpha2().unwrap().as_str(), "AF");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Numeric([u8; 3]);
+
+impl Alpha2 {
+    /// Returns the code as a string slice.
+    pub fn as_str(&self) -> &str {
+        str::from_utf8(&self.0).expect("Alpha2 codes are ASCII")
+    }
+
+    /// Returns whether the code is in a range left for users to assign.
+    pub fn is_user_assigned(&self) -> bool {
+        is_user_assigned(self.as_str())
+    }
+
+    /// Returns the country assigned the code, if any.
+    pub fn country(&self) -> Option<Country> {
+        index::alpha2(self.as_str()).map(|entry| entry.country)
+    }
+
+    /// Returns the Alpha3 code of the country assigned the code, if any.
+    pub fn to_alpha3(&self) -> Option<Alpha3> {
+        self.country().map(Country::alpha3_code)
+    }
+
+    /// Returns the numeric code of the country assigned the code, if any.
+    pub fn to_numeric(&self) -> Option<Numeric> {
+        self.country().map(Country::numeric_code)
+    }
+}
+
+impl Alpha3 {
+    /// Returns the code as a string slice.
+    pub fn as_str(&self) -> &str {
+        str::from_utf8(&self.0).expect("Alpha3 codes are ASCII")
+    }
+
+    /// Returns whether the code is in a range left for users to assign.
+    pub fn is_user_assigned(&self) -> bool {
+        is_user_assigned(self.as_str())
+    }
+
+    /// Returns the country assigned the code, if any.
+    pub fn country(&self) -> Option<Country> {
+        index::alpha3(self.as_str()).map(|entry| entry.country)
+    }
+
+    /// Returns the Alpha2 code of the country assigned the code, if any.
+    pub fn to_alpha2(&self) -> Option<Alpha2> {
+        self.country().map(Country::alpha2_code)
+    }
+
+    /// Returns the numeric code of the country assigned the code, if any.
+    pub fn to_numeric(&self) -> Option<Numeric> {
+        self.country().map(Country::numeric_code)
+    }
+}
+
+impl Numeric {
+    /// Returns the code as a zero-padded string slice.
+    pub fn as_str(&self) -> &str {
+        str::from_utf8(&self.0).expect("numeric codes are ASCII")
+    }
+
+    /// Returns whether the code is in a range left for users to assign.
+    pub fn is_user_assigned(&self) -> bool {
+        is_user_assigned(self.as_str())
+    }
+
+    /// Returns the code as an integer.
+    pub fn value(&self) -> u16 {
+        self.0
+            .iter()
+            .fold(0, |acc, b| acc * 10 + u16::from(b - b'0'))
+    }
+
+    /// Returns the country assigned the code, if any.
+    pub fn country(&self) -> Option<Country> {
+        index::num(self.as_str()).map(|entry| entry.country)
+    }
+
+    /// Returns the Alpha2 code of the country assigned the code, if any.
+    pub fn to_alpha2(&self) -> Option<Alpha2> {
+        self.country().map(Country::alpha2_code)
+    }
+
+    /// Returns the Alpha3 code of the country assigned the code, if any.
+    pub fn to_alpha3(&self) -> Option<Alpha3> {
+        self.country().map(Country::alpha3_code)
+    }
+}
+
+// Checks that `code` is made of `$len` characters all passing `valid`, returning
+// them as bytes.
+macro_rules! parse_code {
+    ($code:expr, $len:expr, $valid:ident) => {{
+        let code: &str = $code;
+        let found = code.chars().count();
+
+        if found != $len {
+            return Err(CodeError::Length {
+                expected: $len,
+                found,
+            });
+        }
+
+        if let Some(c) = code.chars().find(|c| !c.$valid()) {
+            return Err(CodeError::Character(c));
+        }
+
+        let mut bytes = [0; $len];
+        bytes.copy_from_slice(code.as_bytes());
+        bytes
+    }};
+}
+
+impl FromStr for Alpha2 {
+    type Err = CodeError;
+
+    fn from_str(code: &str) -> Result<Alpha2, CodeError> {
+        Ok(Alpha2(parse_code!(code, 2, is_ascii_uppercase)))
+    }
+}
+
+impl FromStr for Alpha3 {
+    type Err = CodeError;
+
+    fn from_str(code: &str) -> Result<Alpha3, CodeError> {
+        Ok(Alpha3(parse_code!(code, 3, is_ascii_uppercase)))
+    }
+}
+
+impl FromStr for Numeric {
+    type Err = CodeError;
+
+    fn from_str(code: &str) -> Result<Numeric, CodeError> {
+        Ok(Numeric(parse_code!(code, 3, is_ascii_digit)))
+    }
+}
+
+impl<'a> TryFrom<&'a str> for Alpha2 {
+    type Error = CodeError;
+
+    fn try_from(code: &'a str) -> Result<Alpha2, CodeError> {
+        code.parse()
+    }
+}
+
+impl<'a> TryFrom<&'a str> for Alpha3 {
+    type Error = CodeError;
+
+    fn try_from(code: &'a str) -> Result<Alpha3, CodeError> {
+        code.parse()
+    }
+}
+
+impl<'a> TryFrom<&'a str> for Numeric {
+    type Error = CodeError;
+
+    fn try_from(code: &'a str) -> Result<Numeric, CodeError> {
+        code.parse()
+    }
+}
+
+impl TryFrom<u16> for Numeric {
+    type Error = CodeError;
+
+    fn try_from(code: u16) -> Result<Numeric, CodeError> {
+        if code > 999 {
+            return Err(CodeError::Length {
+                expected: 3,
+                found: if code > 9999 { 5 } else { 4 },
+            });
+        }
+
+        Ok(Numeric([
+            b'0' + (code / 100) as u8,
+            b'0' + (code / 10 % 10) as u8,
+            b'0' + (code % 10) as u8,
+        ]))
+    }
+}
+
+impl AsRef<str> for Alpha2 {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for Alpha3 {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for Numeric {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Display for Alpha2 {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Display for Alpha3 {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Display for Numeric {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.write_str(self.as_str())
+    }
+}
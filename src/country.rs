@@ -0,0 +1,291 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 3166-1?
+//
+// | ISO 3166-1 is part of the ISO 3166 standard published by the International
+// | Organization for Standardization (ISO), and defines codes for the names of
+// | countries, dependent territories, and special areas of geographical
+// | interest.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_3166-1)
+//
+// Originally by zeyla on GitHub.
+
+use codes::ENTRIES;
+use {index, Alpha2, Alpha3, Country, Entry, Numeric};
+
+#[cfg(feature = "l10n")]
+use l10n;
+
+#[cfg(feature = "std")]
+use core::convert::TryFrom;
+#[cfg(feature = "std")]
+use CountryCode;
+
+impl Country {
+    /// Returns the country with the given Alpha2 code if one exists.
+    ///
+    /// This can be used in constant expressions. To fail at compile time if
+    /// the code is not assigned, use the [`country!`] macro instead.
+    ///
+    /// [`country!`]: macro.country.html
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iso3166_1::Country;
+    ///
+    /// const DEFAULT: Option<Country> = Country::from_alpha2("DE");
+    ///
+    /// assert_eq!(DEFAULT, Some(Country::DE));
+    /// ```
+    pub const fn from_alpha2(alpha2: &str) -> Option<Country> {
+        match index::alpha2(alpha2) {
+            Some(entry) => Some(entry.country),
+            None => None,
+        }
+    }
+
+    /// Returns the country with the given Alpha3 code if one exists.
+    ///
+    /// This can be used in constant expressions.
+    pub const fn from_alpha3(alpha3: &str) -> Option<Country> {
+        match index::alpha3(alpha3) {
+            Some(entry) => Some(entry.country),
+            None => None,
+        }
+    }
+
+    /// Returns the country with the given numeric code if one exists.
+    ///
+    /// This can be used in constant expressions.
+    pub const fn from_numeric(numeric: u16) -> Option<Country> {
+        match index::numeric(numeric) {
+            Some(entry) => Some(entry.country),
+            None => None,
+        }
+    }
+
+    /// Returns the country with the given Alpha2, Alpha3 or zero-padded
+    /// numeric code if one exists.
+    ///
+    /// This can be used in constant expressions, and is what the
+    /// [`country!`] macro is built on.
+    ///
+    /// [`country!`]: macro.country.html
+    pub const fn from_code(code: &str) -> Option<Country> {
+        let entry = match code.len() {
+            2 => index::alpha2(code),
+            3 if code.as_bytes()[0].is_ascii_digit() => index::num(code),
+            3 => index::alpha3(code),
+            _ => None,
+        };
+
+        match entry {
+            Some(entry) => Some(entry.country),
+            None => None,
+        }
+    }
+
+    /// Returns the two-character Alpha2 code of the country.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iso3166_1::Country;
+    ///
+    /// assert_eq!(Country::AF.alpha2(), "AF");
+    /// ```
+    pub const fn alpha2(self) -> &'static str {
+        self.entry().alpha2
+    }
+
+    /// Returns the three-character Alpha3 code of the country.
+    pub const fn alpha3(self) -> &'static str {
+        self.entry().alpha3
+    }
+
+    /// Returns the English short name of the country.
+    pub const fn name(self) -> &'static str {
+        self.entry().name
+    }
+
+    /// Returns the colloquial, former and abbreviated names the country is
+    /// also known by.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iso3166_1::Country;
+    ///
+    /// assert!(Country::MM.aliases().contains(&"Burma"));
+    /// ```
+    pub const fn aliases(self) -> &'static [&'static str] {
+        self.entry().aliases()
+    }
+
+    /// Returns the demonyms of the country, the first being the one to use as
+    /// a nationality.
+    pub const fn demonyms(self) -> &'static [&'static str] {
+        self.entry().demonyms()
+    }
+
+    /// Returns the demonym to use as the nationality of the country, if it has
+    /// one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iso3166_1::Country;
+    ///
+    /// assert_eq!(Country::NL.demonym(), Some("Dutch"));
+    /// assert_eq!(Country::AQ.demonym(), None);
+    /// ```
+    pub const fn demonym(self) -> Option<&'static str> {
+        self.entry().demonym()
+    }
+
+    /// Returns the adjective of the country, such as `"Philippine"`, if it has
+    /// one.
+    pub const fn adjective(self) -> Option<&'static str> {
+        self.entry().adjective()
+    }
+
+    /// Returns the full name of the country, if it has one other than its
+    /// short name.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iso3166_1::Country;
+    ///
+    /// assert_eq!(Country::DE.formal_name(), Some("the Federal Republic of Germany"));
+    /// assert_eq!(Country::CA.formal_name(), None);
+    /// ```
+    pub const fn formal_name(self) -> Option<&'static str> {
+        self.entry().formal_name()
+    }
+
+    /// Returns the English short name of the country as ISO publishes it,
+    /// with articles in parentheses.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iso3166_1::Country;
+    ///
+    /// assert_eq!(Country::BS.iso_name(), "Bahamas (the)");
+    /// assert_eq!(Country::DE.iso_name(), "Germany");
+    /// ```
+    pub const fn iso_name(self) -> &'static str {
+        self.entry().iso_name()
+    }
+
+    /// Returns the ISO short name of the country in upper case, as on the ISO
+    /// Online Browsing Platform.
+    #[cfg(feature = "std")]
+    pub fn upper_name(self) -> String {
+        self.entry().upper_name()
+    }
+
+    /// Returns the French short name of the country.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iso3166_1::Country;
+    ///
+    /// assert_eq!(Country::DE.name_fr(), "Allemagne (l')");
+    /// ```
+    pub const fn name_fr(self) -> &'static str {
+        self.entry().name_fr()
+    }
+
+    /// Returns the name of the country in the given locale, such as `"de-AT"`,
+    /// falling back to its parent locales.
+    ///
+    /// See the [`l10n`] module for how locales fall back.
+    ///
+    /// [`l10n`]: l10n/index.html
+    #[cfg(feature = "l10n")]
+    pub fn localized_name(self, locale: &str) -> Option<&'static str> {
+        l10n::name(self, locale)
+    }
+
+    /// Returns the numeric code of the country.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iso3166_1::Country;
+    ///
+    /// assert_eq!(Country::AF.numeric(), 4);
+    /// ```
+    pub const fn numeric(self) -> u16 {
+        self.entry().numeric()
+    }
+
+    /// Returns the Alpha2 code of the country as an `Alpha2`.
+    pub fn alpha2_code(self) -> Alpha2 {
+        self.alpha2().parse().expect("the table only holds valid codes")
+    }
+
+    /// Returns the Alpha3 code of the country as an `Alpha3`.
+    pub fn alpha3_code(self) -> Alpha3 {
+        self.alpha3().parse().expect("the table only holds valid codes")
+    }
+
+    /// Returns the numeric code of the country as a `Numeric`.
+    pub fn numeric_code(self) -> Numeric {
+        self.entry().num.parse().expect("the table only holds valid codes")
+    }
+
+    /// Returns the entry of the country in the static table.
+    pub const fn entry(self) -> &'static Entry {
+        &ENTRIES[self as usize]
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<Country> for CountryCode {
+    fn from(country: Country) -> CountryCode {
+        CountryCode::from(country.entry())
+    }
+}
+
+/// Converts a `CountryCode` back into the `Country` it was created from.
+///
+/// All four fields must match those of the same country. If they do not, the
+/// `CountryCode` is given back as the error.
+#[cfg(feature = "std")]
+impl TryFrom<CountryCode> for Country {
+    type Error = CountryCode;
+
+    fn try_from(code: CountryCode) -> Result<Country, CountryCode> {
+        let found = ENTRIES.iter().find(|entry| {
+            entry.alpha2 == code.alpha2
+                && entry.alpha3 == code.alpha3
+                && entry.name == code.name
+                && entry.num == code.num
+        });
+
+        match found {
+            Some(entry) => Ok(entry.country),
+            None => Err(code),
+        }
+    }
+}
@@ -0,0 +1,101 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 3166-1?
+//
+// | ISO 3166-1 is part of the ISO 3166 standard published by the International
+// | Organization for Standardization (ISO), and defines codes for the names of
+// | countries, dependent territories, and special areas of geographical
+// | interest.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_3166-1)
+//
+// Originally by zeyla on GitHub.
+
+use core::fmt::{Display, Formatter, Result as FmtResult};
+
+/// A calendar date, used to say when codes and names were valid.
+///
+/// # Examples
+///
+/// ```rust
+/// use iso3166_1::Date;
+///
+/// let date = Date::new(2010, 12, 15);
+///
+/// assert!(date < Date::new(2011, 1, 1));
+/// assert_eq!(date.to_string(), "2010-12-15");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Date {
+    year: u16,
+    month: u8,
+    day: u8,
+}
+
+impl Date {
+    /// Creates a date from a year, a month from 1 to 12, and a day from 1 to
+    /// the number of days in that month, counting the 29th of February in leap
+    /// years.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the month or day is out of range, such as for the 31st of
+    /// April or the 29th of February 2001.
+    pub const fn new(year: u16, month: u8, day: u8) -> Date {
+        assert!(month >= 1 && month <= 12, "month out of range");
+        assert!(
+            day >= 1 && day <= days_in_month(year, month),
+            "day out of range"
+        );
+
+        Date { year, month, day }
+    }
+
+    /// Returns the year of the date.
+    pub const fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// Returns the month of the date, from 1 to 12.
+    pub const fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// Returns the day of the month of the date, from 1 to 31.
+    pub const fn day(&self) -> u8 {
+        self.day
+    }
+}
+
+const fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+const fn is_leap_year(year: u16) -> bool {
+    year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400))
+}
+
+impl Display for Date {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
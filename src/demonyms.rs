@@ -0,0 +1,317 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 3166-1?
+//
+// | ISO 3166-1 is part of the ISO 3166 standard published by the International
+// | Organization for Standardization (ISO), and defines codes for the names of
+// | countries, dependent territories, and special areas of geographical
+// | interest.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_3166-1)
+//
+// Originally by zeyla on GitHub.
+
+// Demonyms and adjectives of countries, as entered in the nationality fields of
+// forms.
+
+use Country;
+use Country::*;
+
+// Returns the demonyms of a country, the first being the one to render it as a
+// nationality, such as "German", and the others being alternatives, such as
+// "Filipina" after "Filipino".
+pub(crate) const fn demonyms(country: Country) -> &'static [&'static str] {
+    match country {
+        AD => &["Andorran"],
+        AE => &["Emirati"],
+        AF => &["Afghan"],
+        AG => &["Antiguan", "Barbudan"],
+        AI => &["Anguillan"],
+        AL => &["Albanian"],
+        AM => &["Armenian"],
+        AO => &["Angolan"],
+        AR => &["Argentine", "Argentinian"],
+        AS => &["American Samoan"],
+        AT => &["Austrian"],
+        AU => &["Australian"],
+        AW => &["Aruban"],
+        AX => &["Ålander"],
+        AZ => &["Azerbaijani", "Azeri"],
+        BA => &["Bosnian", "Herzegovinian"],
+        BB => &["Barbadian", "Bajan"],
+        BD => &["Bangladeshi"],
+        BE => &["Belgian"],
+        BF => &["Burkinabé"],
+        BG => &["Bulgarian"],
+        BH => &["Bahraini"],
+        BI => &["Burundian"],
+        BJ => &["Beninese"],
+        BL => &["Barthélemois"],
+        BM => &["Bermudian"],
+        BN => &["Bruneian"],
+        BO => &["Bolivian"],
+        BQ => &["Caribbean Dutch"],
+        BR => &["Brazilian"],
+        BS => &["Bahamian"],
+        BT => &["Bhutanese"],
+        BW => &["Motswana", "Batswana"],
+        BY => &["Belarusian"],
+        BZ => &["Belizean"],
+        CA => &["Canadian"],
+        CC => &["Cocos Islander"],
+        CD => &["Congolese"],
+        CF => &["Central African"],
+        CG => &["Congolese"],
+        CH => &["Swiss"],
+        CI => &["Ivorian"],
+        CK => &["Cook Islander"],
+        CL => &["Chilean"],
+        CM => &["Cameroonian"],
+        CN => &["Chinese"],
+        CO => &["Colombian"],
+        CR => &["Costa Rican"],
+        CU => &["Cuban"],
+        CV => &["Cabo Verdean", "Cape Verdean"],
+        CW => &["Curaçaoan"],
+        CX => &["Christmas Islander"],
+        CY => &["Cypriot"],
+        CZ => &["Czech"],
+        DE => &["German"],
+        DJ => &["Djiboutian"],
+        DK => &["Danish", "Dane"],
+        DM => &["Dominican"],
+        DO => &["Dominican"],
+        DZ => &["Algerian"],
+        EC => &["Ecuadorian"],
+        EE => &["Estonian"],
+        EG => &["Egyptian"],
+        EH => &["Sahrawi", "Western Saharan"],
+        ER => &["Eritrean"],
+        ES => &["Spanish", "Spaniard"],
+        ET => &["Ethiopian"],
+        FI => &["Finnish", "Finn"],
+        FJ => &["Fijian"],
+        FK => &["Falkland Islander"],
+        FM => &["Micronesian"],
+        FO => &["Faroese"],
+        FR => &["French", "Frenchman", "Frenchwoman"],
+        GA => &["Gabonese"],
+        GB => &["British", "Briton"],
+        GD => &["Grenadian"],
+        GE => &["Georgian"],
+        GF => &["French Guianese"],
+        GG => &["Guernseyman", "Guernseywoman"],
+        GH => &["Ghanaian"],
+        GI => &["Gibraltarian"],
+        GL => &["Greenlandic", "Greenlander"],
+        GM => &["Gambian"],
+        GN => &["Guinean"],
+        GP => &["Guadeloupean"],
+        GQ => &["Equatorial Guinean", "Equatoguinean"],
+        GR => &["Greek"],
+        GT => &["Guatemalan"],
+        GU => &["Guamanian"],
+        GW => &["Bissau-Guinean"],
+        GY => &["Guyanese"],
+        HK => &["Hongkonger", "Hong Konger"],
+        HN => &["Honduran"],
+        HR => &["Croatian", "Croat"],
+        HT => &["Haitian"],
+        HU => &["Hungarian"],
+        ID => &["Indonesian"],
+        IE => &["Irish", "Irishman", "Irishwoman"],
+        IL => &["Israeli"],
+        IM => &["Manx"],
+        IN => &["Indian"],
+        IQ => &["Iraqi"],
+        IR => &["Iranian"],
+        IS => &["Icelandic", "Icelander"],
+        IT => &["Italian"],
+        JE => &["Jerseyman", "Jerseywoman"],
+        JM => &["Jamaican"],
+        JO => &["Jordanian"],
+        JP => &["Japanese"],
+        KE => &["Kenyan"],
+        KG => &["Kyrgyz", "Kyrgyzstani"],
+        KH => &["Cambodian", "Khmer"],
+        KI => &["I-Kiribati"],
+        KM => &["Comorian"],
+        KN => &["Kittitian", "Nevisian"],
+        KP => &["North Korean"],
+        KR => &["South Korean"],
+        KW => &["Kuwaiti"],
+        KY => &["Caymanian"],
+        KZ => &["Kazakh", "Kazakhstani"],
+        LA => &["Lao", "Laotian"],
+        LB => &["Lebanese"],
+        LC => &["Saint Lucian"],
+        LI => &["Liechtensteiner"],
+        LK => &["Sri Lankan"],
+        LR => &["Liberian"],
+        LS => &["Mosotho", "Basotho"],
+        LT => &["Lithuanian"],
+        LU => &["Luxembourgish", "Luxembourger"],
+        LV => &["Latvian"],
+        LY => &["Libyan"],
+        MA => &["Moroccan"],
+        MC => &["Monégasque", "Monacan"],
+        MD => &["Moldovan"],
+        ME => &["Montenegrin"],
+        MF => &["Saint-Martinois"],
+        MG => &["Malagasy"],
+        MH => &["Marshallese"],
+        MK => &["Macedonian", "North Macedonian"],
+        ML => &["Malian"],
+        MM => &["Burmese", "Myanma"],
+        MN => &["Mongolian"],
+        MO => &["Macanese"],
+        MP => &["Northern Mariana Islander"],
+        MQ => &["Martiniquais", "Martinican"],
+        MR => &["Mauritanian"],
+        MS => &["Montserratian"],
+        MT => &["Maltese"],
+        MU => &["Mauritian"],
+        MV => &["Maldivian"],
+        MW => &["Malawian"],
+        MX => &["Mexican"],
+        MY => &["Malaysian"],
+        MZ => &["Mozambican"],
+        NA => &["Namibian"],
+        NC => &["New Caledonian"],
+        NE => &["Nigerien"],
+        NF => &["Norfolk Islander"],
+        NG => &["Nigerian"],
+        NI => &["Nicaraguan"],
+        NL => &["Dutch", "Dutchman", "Dutchwoman", "Netherlander"],
+        NO => &["Norwegian"],
+        NP => &["Nepali", "Nepalese"],
+        NR => &["Nauruan"],
+        NU => &["Niuean"],
+        NZ => &["New Zealander"],
+        OM => &["Omani"],
+        PA => &["Panamanian"],
+        PE => &["Peruvian"],
+        PF => &["French Polynesian"],
+        PG => &["Papua New Guinean"],
+        PH => &["Filipino", "Filipina"],
+        PK => &["Pakistani"],
+        PL => &["Polish", "Pole"],
+        PM => &["Saint-Pierrais", "Miquelonnais"],
+        PN => &["Pitcairn Islander"],
+        PR => &["Puerto Rican"],
+        PS => &["Palestinian"],
+        PT => &["Portuguese"],
+        PW => &["Palauan"],
+        PY => &["Paraguayan"],
+        QA => &["Qatari"],
+        RE => &["Réunionese", "Réunionnais"],
+        RO => &["Romanian"],
+        RS => &["Serbian", "Serb"],
+        RU => &["Russian"],
+        RW => &["Rwandan"],
+        SA => &["Saudi", "Saudi Arabian"],
+        SB => &["Solomon Islander"],
+        SC => &["Seychellois"],
+        SD => &["Sudanese"],
+        SE => &["Swedish", "Swede"],
+        SG => &["Singaporean"],
+        SH => &["Saint Helenian"],
+        SI => &["Slovenian", "Slovene"],
+        SK => &["Slovak"],
+        SL => &["Sierra Leonean"],
+        SM => &["Sammarinese"],
+        SN => &["Senegalese"],
+        SO => &["Somali"],
+        SR => &["Surinamese"],
+        SS => &["South Sudanese"],
+        ST => &["São Toméan"],
+        SV => &["Salvadoran"],
+        SX => &["Sint Maartener"],
+        SY => &["Syrian"],
+        SZ => &["Swazi"],
+        TC => &["Turks and Caicos Islander"],
+        TD => &["Chadian"],
+        TG => &["Togolese"],
+        TH => &["Thai"],
+        TJ => &["Tajik", "Tajikistani"],
+        TK => &["Tokelauan"],
+        TL => &["Timorese", "East Timorese"],
+        TM => &["Turkmen"],
+        TN => &["Tunisian"],
+        TO => &["Tongan"],
+        TR => &["Turkish", "Turk"],
+        TT => &["Trinidadian", "Tobagonian"],
+        TV => &["Tuvaluan"],
+        TW => &["Taiwanese"],
+        TZ => &["Tanzanian"],
+        UA => &["Ukrainian"],
+        UG => &["Ugandan"],
+        US => &["American"],
+        UY => &["Uruguayan"],
+        UZ => &["Uzbek", "Uzbekistani"],
+        VC => &["Vincentian"],
+        VE => &["Venezuelan"],
+        VG => &["British Virgin Islander"],
+        VI => &["US Virgin Islander"],
+        VN => &["Vietnamese"],
+        VU => &["Ni-Vanuatu"],
+        WF => &["Wallisian", "Futunan"],
+        WS => &["Samoan"],
+        YE => &["Yemeni"],
+        YT => &["Mahoran"],
+        ZA => &["South African"],
+        ZM => &["Zambian"],
+        ZW => &["Zimbabwean"],
+        // Uninhabited, or without a native population.
+        AQ | BV | GS | HM | IO | SJ | TF | UM | VA => &[],
+    }
+}
+
+// Returns the adjective of a country, such as "Philippine", which is its first
+// demonym unless listed here.
+pub(crate) const fn adjective(country: Country) -> Option<&'static str> {
+    match country {
+        AQ => Some("Antarctic"),
+        AX => Some("Ålandish"),
+        BW => Some("Botswanan"),
+        CC => Some("Cocos Island"),
+        CK => Some("Cook Island"),
+        CX => Some("Christmas Island"),
+        FK => Some("Falkland Island"),
+        GG => Some("Guernsey"),
+        HK => Some("Hong Kong"),
+        JE => Some("Jersey"),
+        LI => Some("Liechtenstein"),
+        LS => Some("Basotho"),
+        MK => Some("North Macedonian"),
+        MP => Some("Northern Marianan"),
+        NF => Some("Norfolk Island"),
+        NZ => Some("New Zealand"),
+        PH => Some("Philippine"),
+        PN => Some("Pitcairn Island"),
+        SB => Some("Solomon Island"),
+        SX => Some("Sint Maarten"),
+        TC => Some("Turks and Caicos Island"),
+        VA => Some("Vatican"),
+        VG => Some("British Virgin Island"),
+        VI => Some("US Virgin Island"),
+        _ => match demonyms(country) {
+            [first, ..] => Some(*first),
+            [] => None,
+        },
+    }
+}
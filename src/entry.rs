@@ -0,0 +1,437 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 3166-1?
+//
+// | ISO 3166-1 is part of the ISO 3166 standard published by the International
+// | Organization for Standardization (ISO), and defines codes for the names of
+// | countries, dependent territories, and special areas of geographical
+// | interest.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_3166-1)
+//
+// Originally by zeyla on GitHub.
+
+use codes::ENTRIES;
+use core::ops::RangeBounds;
+use {aliases, as_of, demonyms, fold, index, names};
+use {Alpha2, Alpha3, Country, LookupError, NameMatch, Numeric, Validity};
+
+#[cfg(feature = "l10n")]
+use l10n;
+#[cfg(feature = "std")]
+use CountryCode;
+
+/// The data of a `Country`, as stored in the static table backing the crate.
+///
+/// Unlike `CountryCode`, an `Entry` borrows all of its data for `'static`, so
+/// lookups returning one never allocate.
+///
+/// Entries can only be read from the table, not built, so their codes are
+/// always well-formed:
+///
+/// ```rust,compile_fail
+/// use iso3166_1::{Country, Entry};
+///
+/// let entry = Entry {
+///     country: Country::AF,
+///     alpha2: "AF",
+///     alpha3: "AFG",
+///     name: "Afghanistan",
+///     num: "4",
+/// };
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub struct Entry {
+    /// The country the entry is for
+    pub country: Country,
+    /// Two-character Alpha2 code
+    pub alpha2: &'static str,
+    /// Three-character Alpha3 code
+    pub alpha3: &'static str,
+    /// English short name of the country
+    pub name: &'static str,
+    /// Numeric code of the country, zero-padded to three digits
+    pub num: &'static str,
+}
+
+impl Entry {
+    /// Returns the table of all entries, ordered by their numeric code.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iso3166_1::Entry;
+    ///
+    /// assert!(Entry::all().len() > 0);
+    /// ```
+    pub const fn all() -> &'static [Entry] {
+        &ENTRIES
+    }
+
+    /// Returns the entry with the given Alpha2 code if one exists.
+    ///
+    /// This is a constant-time lookup.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iso3166_1::Entry;
+    ///
+    /// assert_eq!(Entry::by_alpha2("AF").unwrap().name, "Afghanistan");
+    /// ```
+    pub const fn by_alpha2(alpha2: &str) -> Option<&'static Entry> {
+        index::alpha2(alpha2)
+    }
+
+    /// Returns the entry with the given Alpha3 code if one exists.
+    ///
+    /// This is a constant-time lookup.
+    pub const fn by_alpha3(alpha3: &str) -> Option<&'static Entry> {
+        index::alpha3(alpha3)
+    }
+
+    /// Returns the entry with the given name if one exists.
+    pub fn by_name(name: &str) -> Option<&'static Entry> {
+        ENTRIES.iter().find(|e| e.name == name)
+    }
+
+    /// Returns the entry with the given name if one exists, ignoring accents,
+    /// letter case, the kinds of apostrophes and hyphens used, punctuation
+    /// and spacing. Aliases are matched the same way when no name is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iso3166_1::Entry;
+    ///
+    /// let entry = Entry::by_normalized_name("cote d’ivoire").unwrap();
+    ///
+    /// assert_eq!(entry.name, "Côte d'Ivoire");
+    ///
+    /// let entry = Entry::by_normalized_name("turkiye").unwrap();
+    ///
+    /// assert_eq!(entry.name, "Turkey");
+    /// ```
+    pub fn by_normalized_name(name: &str) -> Option<&'static Entry> {
+        ENTRIES.iter().find(|e| fold::eq(e.name, name)).or_else(|| {
+            ENTRIES
+                .iter()
+                .find(|e| e.aliases().iter().any(|alias| fold::eq(alias, name)))
+        })
+    }
+
+    /// Returns the entry with the given alias, such as `"Burma"`, if one
+    /// exists.
+    pub fn by_alias(alias: &str) -> Option<&'static Entry> {
+        ENTRIES.iter().find(|e| e.aliases().contains(&alias))
+    }
+
+    /// Returns the entry with the given name or alias if one exists, along
+    /// with which of the two matched.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iso3166_1::{Entry, NameMatch};
+    ///
+    /// let (entry, matched) = Entry::by_name_or_alias("Holland").unwrap();
+    ///
+    /// assert_eq!(entry.alpha2, "NL");
+    /// assert_eq!(matched, NameMatch::Alias);
+    /// ```
+    pub fn by_name_or_alias(name: &str) -> Option<(&'static Entry, NameMatch)> {
+        Entry::by_name(name)
+            .map(|entry| (entry, NameMatch::Canonical))
+            .or_else(|| Entry::by_alias(name).map(|entry| (entry, NameMatch::Alias)))
+    }
+
+    /// Returns the entry with the given demonym or adjective, such as
+    /// `"Filipina"` or `"Philippine"`, if exactly one country has it.
+    ///
+    /// Like `by_normalized_name`, this ignores accents, letter case, and
+    /// punctuation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iso3166_1::Entry;
+    ///
+    /// assert_eq!(Entry::by_demonym("german").unwrap().alpha2, "DE");
+    /// assert_eq!(Entry::by_demonym("Congolese"), None);
+    /// ```
+    pub fn by_demonym(demonym: &str) -> Option<&'static Entry> {
+        Entry::try_by_demonym(demonym).ok()
+    }
+
+    /// Returns the entry with the given demonym or adjective, or why there is
+    /// none: either no country has it, or several do, as "Dominican".
+    pub fn try_by_demonym(demonym: &str) -> Result<&'static Entry, LookupError> {
+        let mut found = ENTRIES.iter().filter(|e| {
+            e.demonyms()
+                .iter()
+                .chain(e.adjective().as_ref())
+                .any(|name| fold::eq(name, demonym))
+        });
+
+        match (found.next(), found.next()) {
+            (Some(entry), None) => Ok(entry),
+            (Some(_), Some(_)) => Err(LookupError::Ambiguous),
+            (None, _) => Err(LookupError::UnknownName),
+        }
+    }
+
+    /// Returns the entry with the given full name if one exists, with or
+    /// without its leading "the".
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iso3166_1::Entry;
+    ///
+    /// let entry = Entry::by_formal_name("The Hellenic Republic").unwrap();
+    ///
+    /// assert_eq!(entry.alpha2, "GR");
+    /// ```
+    pub fn by_formal_name(name: &str) -> Option<&'static Entry> {
+        let name = without_article(name);
+
+        ENTRIES
+            .iter()
+            .find(|e| e.formal_name().map(without_article) == Some(name))
+    }
+
+    /// Returns the entry with the given ISO short name, such as
+    /// `"Bahamas (the)"`, if one exists.
+    pub fn by_iso_name(name: &str) -> Option<&'static Entry> {
+        ENTRIES.iter().find(|e| e.iso_name() == name)
+    }
+
+    /// Returns the entry with the given upper-case ISO short name, such as
+    /// `"BAHAMAS (THE)"`, if one exists.
+    pub fn by_upper_name(name: &str) -> Option<&'static Entry> {
+        ENTRIES.iter().find(|e| {
+            e.iso_name()
+                .chars()
+                .flat_map(char::to_uppercase)
+                .eq(name.chars())
+        })
+    }
+
+    /// Returns the entry with the given French short name if one exists.
+    pub fn by_name_fr(name: &str) -> Option<&'static Entry> {
+        ENTRIES.iter().find(|e| e.name_fr() == name)
+    }
+
+    /// Returns the entry with the given number if one exists.
+    ///
+    /// This is a constant-time lookup.
+    pub const fn by_num(num: &str) -> Option<&'static Entry> {
+        index::num(num)
+    }
+
+    /// Returns the entry with the given numeric code if one exists.
+    ///
+    /// This is a constant-time lookup.
+    pub const fn by_numeric(numeric: u16) -> Option<&'static Entry> {
+        index::numeric(numeric)
+    }
+
+    /// Returns the entries with a numeric code within the given range, in
+    /// order of their numeric code.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iso3166_1::Entry;
+    ///
+    /// let first = Entry::by_numeric_range(..).next().unwrap();
+    ///
+    /// assert_eq!(first.alpha2, "AF");
+    /// assert_eq!(Entry::by_numeric_range(1..4).count(), 0);
+    /// ```
+    pub fn by_numeric_range<R: RangeBounds<u16>>(
+        range: R,
+    ) -> impl Iterator<Item = &'static Entry> {
+        index::numeric_range(range)
+    }
+
+    /// Returns the entry with the given Alpha2 code, or why there is none.
+    pub fn try_by_alpha2(alpha2: &str) -> Result<&'static Entry, LookupError> {
+        let code: Alpha2 = alpha2.parse()?;
+
+        index::alpha2(code.as_str()).ok_or_else(|| LookupError::alpha2(code.as_str()))
+    }
+
+    /// Returns the entry with the given Alpha3 code, or why there is none.
+    pub fn try_by_alpha3(alpha3: &str) -> Result<&'static Entry, LookupError> {
+        let code: Alpha3 = alpha3.parse()?;
+
+        index::alpha3(code.as_str()).ok_or_else(|| LookupError::alpha3(code.as_str()))
+    }
+
+    /// Returns the entry with the given name, or why there is none.
+    pub fn try_by_name(name: &str) -> Result<&'static Entry, LookupError> {
+        Entry::by_name(name).ok_or(LookupError::UnknownName)
+    }
+
+    /// Returns the entry with the given number, or why there is none.
+    pub fn try_by_num(num: &str) -> Result<&'static Entry, LookupError> {
+        let code: Numeric = num.parse()?;
+
+        index::num(code.as_str()).ok_or_else(|| LookupError::num(code.as_str()))
+    }
+
+    /// Returns the colloquial, former and abbreviated names the country is
+    /// also known by.
+    pub const fn aliases(&self) -> &'static [&'static str] {
+        aliases::aliases(self.country)
+    }
+
+    /// Returns the demonyms of the country, such as `["Filipino", "Filipina"]`.
+    ///
+    /// The first is the one to use as a nationality, the others being
+    /// alternatives. Uninhabited territories have none.
+    pub const fn demonyms(&self) -> &'static [&'static str] {
+        demonyms::demonyms(self.country)
+    }
+
+    /// Returns the demonym to use as the nationality of the country, such as
+    /// `"German"`, if it has one.
+    pub const fn demonym(&self) -> Option<&'static str> {
+        match self.demonyms() {
+            [first, ..] => Some(*first),
+            [] => None,
+        }
+    }
+
+    /// Returns the adjective of the country, such as `"Philippine"`, if it has
+    /// one.
+    pub const fn adjective(&self) -> Option<&'static str> {
+        demonyms::adjective(self.country)
+    }
+
+    /// Returns the full name of the country, if it has one other than its
+    /// short name.
+    pub const fn formal_name(&self) -> Option<&'static str> {
+        names::formal(self.country)
+    }
+
+    /// Returns the English short name of the country as ISO publishes it,
+    /// with articles in parentheses.
+    pub const fn iso_name(&self) -> &'static str {
+        match names::iso(self.country) {
+            Some(name) => name,
+            None => self.name,
+        }
+    }
+
+    /// Returns the ISO short name of the country in upper case, as on the ISO
+    /// Online Browsing Platform.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iso3166_1::Entry;
+    ///
+    /// let entry = Entry::by_alpha2("CI").unwrap();
+    ///
+    /// assert_eq!(entry.upper_name(), "CÔTE D'IVOIRE");
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn upper_name(&self) -> String {
+        self.iso_name().to_uppercase()
+    }
+
+    /// Returns the French short name of the country, with articles in
+    /// parentheses, such as `"Allemagne (l')"`.
+    pub const fn name_fr(&self) -> &'static str {
+        names::fr(self.country)
+    }
+
+    /// Returns the name of the country in the given locale, such as `"de-AT"`,
+    /// falling back to its parent locales.
+    #[cfg(feature = "l10n")]
+    pub fn localized_name(&self, locale: &str) -> Option<&'static str> {
+        l10n::name(self.country, locale)
+    }
+
+    /// Returns since when the codes of the entry have been valid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iso3166_1::{Date, Entry};
+    ///
+    /// let validity = Entry::by_alpha2("SS").unwrap().validity();
+    ///
+    /// assert_eq!(validity.from, Some(Date::new(2011, 8, 9)));
+    /// ```
+    pub fn validity(&self) -> Validity {
+        as_of::validity(self)
+    }
+
+    /// Returns when the name of the entry was valid.
+    ///
+    /// The table keeps a few names that have since been replaced, such as
+    /// "Turkey", for which `until` is the day of the change.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iso3166_1::{Date, Entry};
+    ///
+    /// let entry = Entry::by_alpha2("CV").unwrap();
+    ///
+    /// assert_eq!(entry.name_validity().from, Some(Date::new(2013, 10, 24)));
+    /// assert_eq!(entry.name_validity().until, None);
+    ///
+    /// let entry = Entry::by_alpha2("TR").unwrap();
+    ///
+    /// assert_eq!(entry.name, "Turkey");
+    /// assert_eq!(entry.name_validity().until, Some(Date::new(2022, 6, 1)));
+    /// ```
+    pub fn name_validity(&self) -> Validity {
+        as_of::name_validity(self)
+    }
+
+    /// Returns the numeric code of the entry as an integer.
+    pub const fn numeric(&self) -> u16 {
+        let num = self.num.as_bytes();
+
+        (num[0] - b'0') as u16 * 100 + (num[1] - b'0') as u16 * 10 + (num[2] - b'0') as u16
+    }
+}
+
+// Strips the article full names start with, as in "the French Republic".
+fn without_article(name: &str) -> &str {
+    name.strip_prefix("the ")
+        .or_else(|| name.strip_prefix("The "))
+        .unwrap_or(name)
+}
+
+#[cfg(feature = "std")]
+impl<'a> From<&'a Entry> for CountryCode {
+    fn from(entry: &'a Entry) -> CountryCode {
+        CountryCode {
+            alpha2: entry.alpha2.to_owned(),
+            alpha3: entry.alpha3.to_owned(),
+            name: entry.name.to_owned(),
+            num: entry.num.to_owned(),
+        }
+    }
+}
@@ -0,0 +1,184 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 3166-1?
+//
+// | ISO 3166-1 is part of the ISO 3166 standard published by the International
+// | Organization for Standardization (ISO), and defines codes for the names of
+// | countries, dependent territories, and special areas of geographical
+// | interest.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_3166-1)
+//
+// Originally by zeyla on GitHub.
+
+use core::fmt::{Display, Formatter, Result as FmtResult};
+use {is_user_assigned, iso3166_3, reserved, CodeError, Country, Reservation};
+
+/// An error returned when a lookup does not find a country, describing why.
+///
+/// # Examples
+///
+/// ```rust
+/// use iso3166_1::{Country, Entry, LookupError};
+///
+/// assert_eq!(
+///     Entry::try_by_alpha2("UK"),
+///     Err(LookupError::Reserved(iso3166_1::reservation("UK").unwrap()))
+/// );
+/// assert_eq!(
+///     Entry::try_by_alpha2("AN"),
+///     Err(LookupError::Withdrawn {
+///         successors: &[Country::BQ, Country::CW, Country::SX],
+///     })
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum LookupError {
+    /// The code did not have the expected number of characters or digits.
+    InvalidLength {
+        /// The number of characters or digits the code must have
+        expected: usize,
+        /// The number of characters or digits the code had
+        found: usize,
+    },
+    /// The code contained a character it may not, such as a lowercase letter
+    /// in an Alpha2 code or a letter in a numeric code.
+    InvalidCharacter(char),
+    /// A number was too large to be read as a bound of a numeric range, which
+    /// may be at most 65535.
+    OutOfRange,
+    /// The code is well-formed, but is not assigned to any country.
+    Unassigned,
+    /// The code is in a range left for users to assign, such as `"XK"`, so
+    /// ISO 3166-1 will never assign it.
+    ///
+    /// See [`UserAssigned`] for looking such codes up.
+    ///
+    /// [`UserAssigned`]: struct.UserAssigned.html
+    UserAssigned,
+    /// The code is reserved by ISO 3166/MA rather than assigned, such as
+    /// `"UK"` or `"EU"`.
+    Reserved(&'static Reservation),
+    /// The code was withdrawn from ISO 3166-1. The countries whose codes now
+    /// cover the same area are given.
+    ///
+    /// See the [`iso3166_3`] module for more about withdrawn codes.
+    ///
+    /// [`iso3166_3`]: iso3166_3/index.html
+    Withdrawn {
+        /// The countries replacing the withdrawn code
+        successors: &'static [Country],
+    },
+    /// No country has the given name.
+    UnknownName,
+    /// The input identified different countries depending on what kind of
+    /// identifier it was read as.
+    Ambiguous,
+}
+
+impl LookupError {
+    // Explains why a well-formed Alpha2 code is not assigned.
+    pub(crate) fn alpha2(alpha2: &str) -> LookupError {
+        if let Some(withdrawn) = iso3166_3::alpha2(alpha2) {
+            LookupError::Withdrawn {
+                successors: withdrawn.successors,
+            }
+        } else if let Some(reservation) = reserved::reservation(alpha2) {
+            LookupError::Reserved(reservation)
+        } else if is_user_assigned(alpha2) {
+            LookupError::UserAssigned
+        } else {
+            LookupError::Unassigned
+        }
+    }
+
+    // Explains why a well-formed Alpha3 code is not assigned.
+    pub(crate) fn alpha3(alpha3: &str) -> LookupError {
+        if let Some(withdrawn) = iso3166_3::alpha3(alpha3) {
+            LookupError::Withdrawn {
+                successors: withdrawn.successors,
+            }
+        } else if let Some(reservation) = reserved::reservation(alpha3) {
+            LookupError::Reserved(reservation)
+        } else if is_user_assigned(alpha3) {
+            LookupError::UserAssigned
+        } else {
+            LookupError::Unassigned
+        }
+    }
+
+    // Explains why a well-formed numeric code is not assigned.
+    pub(crate) fn num(num: &str) -> LookupError {
+        match iso3166_3::num(num) {
+            Some(withdrawn) => LookupError::Withdrawn {
+                successors: withdrawn.successors,
+            },
+            None if is_user_assigned(num) => LookupError::UserAssigned,
+            None => LookupError::Unassigned,
+        }
+    }
+}
+
+impl From<CodeError> for LookupError {
+    fn from(error: CodeError) -> LookupError {
+        match error {
+            CodeError::Length { expected, found } => {
+                LookupError::InvalidLength { expected, found }
+            }
+            CodeError::Character(c) => LookupError::InvalidCharacter(c),
+        }
+    }
+}
+
+impl Display for LookupError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match *self {
+            LookupError::InvalidLength { expected, found } => write!(
+                f,
+                "expected a code of {} characters, found {}",
+                expected, found
+            ),
+            LookupError::InvalidCharacter(c) => write!(f, "invalid character {:?} in code", c),
+            LookupError::Unassigned => f.write_str("code is not assigned"),
+            LookupError::UserAssigned => f.write_str("code is user-assigned"),
+            LookupError::Reserved(reservation) => write!(
+                f,
+                "code is {} for {}",
+                reservation.category, reservation.refers_to
+            ),
+            LookupError::Withdrawn { successors } => {
+                f.write_str("code was withdrawn, and replaced by ")?;
+
+                for (i, successor) in successors.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+
+                    f.write_str(successor.alpha2())?;
+                }
+
+                Ok(())
+            }
+            LookupError::OutOfRange => f.write_str("number is larger than 65535"),
+            LookupError::UnknownName => f.write_str("no country has the name"),
+            LookupError::Ambiguous => f.write_str("input identifies more than one country"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl ::std::error::Error for LookupError {}
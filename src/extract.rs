@@ -0,0 +1,308 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 3166-1?
+//
+// | ISO 3166-1 is part of the ISO 3166 standard published by the International
+// | Organization for Standardization (ISO), and defines codes for the names of
+// | countries, dependent territories, and special areas of geographical
+// | interest.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_3166-1)
+//
+// Originally by zeyla on GitHub.
+
+// Finding the countries mentioned in free text.
+//
+// Names and aliases are held in a trie of their folded characters, walked from
+// the start of every capitalized word of the text. Abbreviations and codes,
+// such as "UK", "USA" and "DE", are matched as whole words, in upper case
+// only, and are left out by default.
+
+use codes::ENTRIES;
+use core::ops::Range;
+use std::sync::OnceLock;
+use {fold, CountryCode};
+
+/// A mention of a country found by [`extract`].
+///
+/// [`extract`]: fn.extract.html
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Mention<'a> {
+    /// The byte range of the mention in the text
+    pub span: Range<usize>,
+    /// The text of the mention, such as `"Ivory Coast"`
+    pub text: &'a str,
+    /// The country mentioned
+    pub code: CountryCode,
+}
+
+/// A matcher finding the countries mentioned in text by their name, alias,
+/// or code.
+///
+/// Bare two-letter codes and abbreviations are left out unless enabled with
+/// [`ignore_two_letter_codes`], and longer abbreviations unless enabled with
+/// [`ignore_abbreviations`].
+///
+/// [`ignore_two_letter_codes`]: #method.ignore_two_letter_codes
+/// [`ignore_abbreviations`]: #method.ignore_abbreviations
+///
+/// # Examples
+///
+/// ```rust
+/// use iso3166_1::Extractor;
+///
+/// let text = "IT staff moved from Guinea-Bissau to the UK.";
+/// let mentions = Extractor::new().extract(text);
+///
+/// assert_eq!(mentions.len(), 1);
+/// assert_eq!(mentions[0].span, 20..33);
+/// assert_eq!(mentions[0].code.alpha2, "GW");
+///
+/// let mentions = Extractor::new().ignore_two_letter_codes(false).extract(text);
+///
+/// assert_eq!(mentions.len(), 3);
+/// assert_eq!(mentions[2].code.alpha2, "GB");
+///
+/// let text = "THE CAR IS RED";
+///
+/// assert!(Extractor::new().extract(text).is_empty());
+/// assert_eq!(Extractor::new().ignore_abbreviations(false).extract(text).len(), 1);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Extractor {
+    nodes: Vec<Node>,
+    // Upper-case abbreviations and codes, sorted, with the position of their
+    // entry in `ENTRIES`.
+    codes: Vec<(&'static str, usize)>,
+    ignore_two_letter_codes: bool,
+    ignore_abbreviations: bool,
+}
+
+#[derive(Clone, Debug, Default)]
+struct Node {
+    // The folded character leading to each child, sorted.
+    children: Vec<(char, usize)>,
+    // The position of the entry whose name or alias ends here.
+    entry: Option<usize>,
+}
+
+static DEFAULT: OnceLock<Extractor> = OnceLock::new();
+
+/// Returns the countries mentioned in the text, in the order they appear.
+///
+/// This is [`Extractor::extract`] on a default, shared, `Extractor`.
+///
+/// [`Extractor::extract`]: struct.Extractor.html#method.extract
+///
+/// # Examples
+///
+/// ```rust
+/// let mentions = iso3166_1::extract("Talks between Côte d'Ivoire and Burma stalled.");
+///
+/// assert_eq!(mentions[0].text, "Côte d'Ivoire");
+/// assert_eq!(mentions[1].code.alpha2, "MM");
+/// ```
+pub fn extract(text: &str) -> Vec<Mention<'_>> {
+    DEFAULT.get_or_init(Extractor::new).extract(text)
+}
+
+impl Extractor {
+    /// Builds a matcher of the names, aliases and codes of every country.
+    pub fn new() -> Extractor {
+        let mut extractor = Extractor {
+            nodes: vec![Node::default()],
+            codes: Vec::new(),
+            ignore_two_letter_codes: true,
+            ignore_abbreviations: true,
+        };
+
+        for (position, entry) in ENTRIES.iter().enumerate() {
+            extractor.codes.push((entry.alpha2, position));
+
+            for &name in Some(&entry.name).into_iter().chain(entry.aliases()) {
+                if is_abbreviation(name) {
+                    extractor.codes.push((name, position));
+                } else {
+                    extractor.insert(name, position);
+                }
+            }
+        }
+
+        extractor.codes.sort();
+
+        extractor
+    }
+
+    /// Sets whether bare two-letter codes and abbreviations, such as "DE" and
+    /// "UK", are left out, as they are easily confused with other words in
+    /// upper case. They are left out by default.
+    pub fn ignore_two_letter_codes(mut self, ignore: bool) -> Extractor {
+        self.ignore_two_letter_codes = ignore;
+        self
+    }
+
+    /// Sets whether abbreviations of three letters or more, such as "USA" and
+    /// "CAR", are left out, as they are easily confused with words in text
+    /// written in upper case. They are left out by default.
+    pub fn ignore_abbreviations(mut self, ignore: bool) -> Extractor {
+        self.ignore_abbreviations = ignore;
+        self
+    }
+
+    /// Returns the countries mentioned in the text, in the order they appear.
+    ///
+    /// A mention starts and ends at word boundaries, and names and aliases
+    /// are matched as [`name_normalized`] matches names, but must start with
+    /// a capital letter, so that "chad" or "turkey" is not mistaken for a
+    /// country. Where mentions overlap, the one starting first is kept, and
+    /// then the longest one: "Guinea-Bissau" is not a mention of Guinea.
+    ///
+    /// [`name_normalized`]: fn.name_normalized.html
+    pub fn extract<'a>(&self, text: &'a str) -> Vec<Mention<'a>> {
+        let mut mentions = Vec::new();
+        let mut next = 0;
+        let mut previous = ' ';
+
+        for (start, c) in text.char_indices() {
+            let word_start = !previous.is_alphanumeric();
+
+            previous = c;
+
+            if start < next || !word_start || !c.is_uppercase() {
+                continue;
+            }
+
+            let name = self.name_at(text, start);
+            let code = self.code_at(text, start);
+            let found = match (name, code) {
+                (Some(name), Some(code)) if code.0 > name.0 => Some(code),
+                (Some(name), _) => Some(name),
+                (None, code) => code,
+            };
+
+            if let Some((end, position)) = found {
+                mentions.push(Mention {
+                    span: start..end,
+                    text: &text[start..end],
+                    code: CountryCode::from(&ENTRIES[position]),
+                });
+                next = end;
+            }
+        }
+
+        mentions
+    }
+
+    fn insert(&mut self, name: &str, position: usize) {
+        let mut node = 0;
+
+        for c in fold::fold(name) {
+            node = match self.nodes[node]
+                .children
+                .binary_search_by_key(&c, |&(c, _)| c)
+            {
+                Ok(child) => self.nodes[node].children[child].1,
+                Err(child) => {
+                    self.nodes.push(Node::default());
+
+                    let new = self.nodes.len() - 1;
+
+                    self.nodes[node].children.insert(child, (c, new));
+                    new
+                }
+            };
+        }
+
+        self.nodes[node].entry.get_or_insert(position);
+    }
+
+    // Returns the end of the longest name or alias starting at `start`, and
+    // the position of its entry.
+    fn name_at(&self, text: &str, start: usize) -> Option<(usize, usize)> {
+        let rest = &text[start..];
+        let mut folded = fold::fold_indices(rest).peekable();
+        let mut node = 0;
+        let mut found = None;
+
+        while let Some((i, c)) = folded.next() {
+            let children = &self.nodes[node].children;
+
+            node = match children.binary_search_by_key(&c, |&(c, _)| c) {
+                Ok(child) => children[child].1,
+                Err(_) => break,
+            };
+
+            let entry = match self.nodes[node].entry {
+                Some(entry) => entry,
+                None => continue,
+            };
+            let end = i + rest[i..].chars().next().map_or(0, char::len_utf8);
+
+            // The name must not end within the folding of a character, as
+            // within the "ae" of "æ", nor within a word.
+            let whole = folded.peek().is_none_or(|&(next, _)| next >= end);
+
+            if whole && is_boundary(rest, end) {
+                found = Some((start + end, entry));
+            }
+        }
+
+        found
+    }
+
+    // Returns the end of the abbreviation or code making up the word starting
+    // at `start`, and the position of its entry.
+    fn code_at(&self, text: &str, start: usize) -> Option<(usize, usize)> {
+        let rest = &text[start..];
+        let len = rest
+            .find(|c: char| !c.is_alphanumeric())
+            .unwrap_or(rest.len());
+        let word = &rest[..len];
+
+        if self.ignore_two_letter_codes && word.len() == 2 {
+            return None;
+        }
+
+        if self.ignore_abbreviations && word.len() > 2 {
+            return None;
+        }
+
+        self.codes
+            .binary_search_by_key(&word, |&(code, _)| code)
+            .ok()
+            .map(|code| (start + len, self.codes[code].1))
+    }
+}
+
+impl Default for Extractor {
+    fn default() -> Extractor {
+        Extractor::new()
+    }
+}
+
+// Returns whether the name is an abbreviation in upper case, such as "UK".
+fn is_abbreviation(name: &str) -> bool {
+    name.bytes().all(|b| b.is_ascii_uppercase())
+}
+
+// Returns whether a word of the text may end at the offset.
+fn is_boundary(text: &str, end: usize) -> bool {
+    text[end..]
+        .chars()
+        .next()
+        .is_none_or(|c| !c.is_alphanumeric())
+}
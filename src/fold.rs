@@ -0,0 +1,182 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 3166-1?
+//
+// | ISO 3166-1 is part of the ISO 3166 standard published by the International
+// | Organization for Standardization (ISO), and defines codes for the names of
+// | countries, dependent territories, and special areas of geographical
+// | interest.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_3166-1)
+//
+// Originally by zeyla on GitHub.
+
+// Folding of names into a form that ignores how they were typed: accents,
+// letter case, kinds of apostrophes and hyphens, punctuation, and spacing.
+//
+// Letters are decomposed into their canonical (NFD) form and their combining
+// marks dropped, so "ô", "o\u{302}" and "ǘ" fold to plain letters whichever
+// Unicode normalization form a name is in. The few letters and ligatures with
+// no decomposition, such as "ø" and "æ", are folded by hand.
+
+use core::char::ToLowercase;
+use core::str::CharIndices;
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::{Decompositions, UnicodeNormalization};
+
+/// The characters of a folded name, with the byte offsets of the characters
+/// of the name they were folded from.
+#[derive(Clone)]
+pub(crate) struct Folded<'a> {
+    chars: CharIndices<'a>,
+    // The decomposed lowercase form of the character being folded.
+    lower: Option<(usize, Decompositions<ToLowercase>)>,
+    // A character to fold once the space due before it is returned.
+    held: Option<(usize, char)>,
+    // The second letter of a ligature, such as the "e" of "æ".
+    pending: Option<(usize, char)>,
+    // Where the space due before the next character is.
+    space: Option<usize>,
+    started: bool,
+}
+
+/// Folds a name, so that "Côte d’Ivoire" and "cote d'ivoire" yield the same
+/// characters.
+///
+/// Letters are lowercased and stripped of their accents, apostrophes become
+/// `'`, and hyphens, the punctuation `.`, `,`, `(` and `)`, and runs of
+/// whitespace become a single space, trimmed at either end.
+pub(crate) fn fold(name: &str) -> impl Iterator<Item = char> + '_ {
+    fold_indices(name).map(|(_, c)| c)
+}
+
+/// Folds a name as `fold` does, along with the byte offset of the character
+/// each folded one comes from.
+pub(crate) fn fold_indices(name: &str) -> Folded<'_> {
+    Folded {
+        chars: name.char_indices(),
+        lower: None,
+        held: None,
+        pending: None,
+        space: None,
+        started: false,
+    }
+}
+
+/// Returns whether two names are equal once folded.
+pub(crate) fn eq(a: &str, b: &str) -> bool {
+    fold(a).eq(fold(b))
+}
+
+impl<'a> Folded<'a> {
+    fn lowercase(&mut self) -> Option<(usize, char)> {
+        loop {
+            if let Some((i, ref mut lower)) = self.lower {
+                if let Some(c) = lower.next() {
+                    return Some((i, c));
+                }
+            }
+
+            let (i, c) = self.chars.next()?;
+
+            self.lower = Some((i, c.to_lowercase().nfd()));
+        }
+    }
+}
+
+impl<'a> Iterator for Folded<'a> {
+    type Item = (usize, char);
+
+    fn next(&mut self) -> Option<(usize, char)> {
+        if let Some(folded) = self.pending.take() {
+            return Some(folded);
+        }
+
+        loop {
+            let (i, c) = match self.held.take() {
+                Some(held) => held,
+                None => self.lowercase()?,
+            };
+
+            if c.is_whitespace() || is_hyphen(c) || is_punctuation(c) {
+                if self.started && self.space.is_none() {
+                    self.space = Some(i);
+                }
+
+                continue;
+            }
+
+            if is_combining_mark(c) {
+                continue;
+            }
+
+            if let Some(space) = self.space.take() {
+                self.held = Some((i, c));
+
+                return Some((space, ' '));
+            }
+
+            let (first, second) = match letter(c) {
+                Some(folded) => folded,
+                None if is_apostrophe(c) => ('\'', None),
+                None => (c, None),
+            };
+
+            self.started = true;
+            self.pending = second.map(|second| (i, second));
+
+            return Some((i, first));
+        }
+    }
+}
+
+fn is_apostrophe(c: char) -> bool {
+    matches!(
+        c,
+        '\'' | '`' | '´' | 'ʹ' | 'ʻ' | 'ʼ' | '‘' | '’' | '‛' | '′'
+    )
+}
+
+fn is_hyphen(c: char) -> bool {
+    matches!(c, '-' | '‐' | '‑' | '‒' | '–' | '—' | '―' | '−' | '_')
+}
+
+// Returns whether the character is punctuation that only separates words, as
+// in "Korea, Republic of" or "St. Lucia".
+fn is_punctuation(c: char) -> bool {
+    matches!(c, '.' | ',' | '(' | ')')
+}
+
+// Returns the plain form of a lowercase letter that has no canonical
+// decomposition, such as "ø", or of a ligature.
+fn letter(c: char) -> Option<(char, Option<char>)> {
+    let base = match c {
+        'đ' | 'ð' => 'd',
+        'ħ' => 'h',
+        'ı' => 'i',
+        'ŀ' | 'ł' => 'l',
+        'ø' => 'o',
+        'ŧ' => 't',
+        'æ' => return Some(('a', Some('e'))),
+        'œ' => return Some(('o', Some('e'))),
+        'ß' => return Some(('s', Some('s'))),
+        'þ' => return Some(('t', Some('h'))),
+        _ => return None,
+    };
+
+    Some((base, None))
+}
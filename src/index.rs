@@ -0,0 +1,197 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 3166-1?
+//
+// | ISO 3166-1 is part of the ISO 3166 standard published by the International
+// | Organization for Standardization (ISO), and defines codes for the names of
+// | countries, dependent territories, and special areas of geographical
+// | interest.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_3166-1)
+//
+// Originally by zeyla on GitHub.
+
+// Constant-time indexes into the table of entries, built at compile time.
+//
+// Each index maps a code to the position of its entry in `ENTRIES`, with
+// `NONE` marking codes that are not assigned.
+
+use codes::ENTRIES;
+use core::ops::{Bound, RangeBounds};
+use Entry;
+
+const NONE: u8 = u8::MAX;
+
+// Every pair of letters, from "AA" to "ZZ".
+static ALPHA2: [u8; 26 * 26] = build_alpha2();
+// Every triple of letters, from "AAA" to "ZZZ".
+static ALPHA3: [u8; 26 * 26 * 26] = build_alpha3();
+// Every numeric code, from "000" to "999".
+static NUMERIC: [u8; 1000] = build_numeric();
+
+/// Returns the entry with the given Alpha2 code if one exists.
+pub(crate) const fn alpha2(code: &str) -> Option<&'static Entry> {
+    match letters(code.as_bytes(), 2) {
+        Some(slot) => get(ALPHA2[slot]),
+        None => None,
+    }
+}
+
+/// Returns the entry with the given Alpha3 code if one exists.
+pub(crate) const fn alpha3(code: &str) -> Option<&'static Entry> {
+    match letters(code.as_bytes(), 3) {
+        Some(slot) => get(ALPHA3[slot]),
+        None => None,
+    }
+}
+
+/// Returns the entry with the given zero-padded numeric code if one exists.
+pub(crate) const fn num(code: &str) -> Option<&'static Entry> {
+    match digits(code.as_bytes()) {
+        Some(slot) => get(NUMERIC[slot]),
+        None => None,
+    }
+}
+
+/// Returns the entry with the given numeric code if one exists.
+pub(crate) const fn numeric(code: u16) -> Option<&'static Entry> {
+    if code < 1000 {
+        get(NUMERIC[code as usize])
+    } else {
+        None
+    }
+}
+
+/// Returns the entries with a numeric code within the given range, in order of
+/// their numeric code.
+pub(crate) fn numeric_range<R: RangeBounds<u16>>(
+    range: R,
+) -> impl Iterator<Item = &'static Entry> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => usize::from(start),
+        Bound::Excluded(&start) => usize::from(start) + 1,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => usize::from(end) + 1,
+        Bound::Excluded(&end) => usize::from(end),
+        Bound::Unbounded => NUMERIC.len(),
+    };
+    let slots = NUMERIC.get(start..end.min(NUMERIC.len())).unwrap_or(&[]);
+
+    slots.iter().filter_map(|&position| get(position))
+}
+
+const fn get(position: u8) -> Option<&'static Entry> {
+    if position == NONE {
+        None
+    } else {
+        Some(&ENTRIES[position as usize])
+    }
+}
+
+// Returns the slot of a code made of exactly `len` uppercase ASCII letters.
+const fn letters(bytes: &[u8], len: usize) -> Option<usize> {
+    if bytes.len() != len {
+        return None;
+    }
+
+    let mut slot = 0;
+    let mut i = 0;
+
+    while i < len {
+        if !bytes[i].is_ascii_uppercase() {
+            return None;
+        }
+
+        slot = slot * 26 + (bytes[i] - b'A') as usize;
+        i += 1;
+    }
+
+    Some(slot)
+}
+
+// Returns the slot of a code made of exactly three ASCII digits.
+const fn digits(bytes: &[u8]) -> Option<usize> {
+    if bytes.len() != 3 {
+        return None;
+    }
+
+    let mut slot = 0;
+    let mut i = 0;
+
+    while i < 3 {
+        if !bytes[i].is_ascii_digit() {
+            return None;
+        }
+
+        slot = slot * 10 + (bytes[i] - b'0') as usize;
+        i += 1;
+    }
+
+    Some(slot)
+}
+
+const fn build_alpha2() -> [u8; 26 * 26] {
+    assert!(ENTRIES.len() < NONE as usize);
+
+    let mut index = [NONE; 26 * 26];
+    let mut i = 0;
+
+    while i < ENTRIES.len() {
+        match letters(ENTRIES[i].alpha2.as_bytes(), 2) {
+            Some(slot) => index[slot] = i as u8,
+            None => panic!("malformed Alpha2 code in the table"),
+        }
+
+        i += 1;
+    }
+
+    index
+}
+
+const fn build_alpha3() -> [u8; 26 * 26 * 26] {
+    let mut index = [NONE; 26 * 26 * 26];
+    let mut i = 0;
+
+    while i < ENTRIES.len() {
+        match letters(ENTRIES[i].alpha3.as_bytes(), 3) {
+            Some(slot) => index[slot] = i as u8,
+            None => panic!("malformed Alpha3 code in the table"),
+        }
+
+        i += 1;
+    }
+
+    index
+}
+
+const fn build_numeric() -> [u8; 1000] {
+    let mut index = [NONE; 1000];
+    let mut i = 0;
+
+    while i < ENTRIES.len() {
+        match digits(ENTRIES[i].num.as_bytes()) {
+            Some(slot) => index[slot] = i as u8,
+            None => panic!("malformed numeric code in the table"),
+        }
+
+        i += 1;
+    }
+
+    index
+}
@@ -0,0 +1,415 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 3166-1?
+//
+// | ISO 3166-1 is part of the ISO 3166 standard published by the International
+// | Organization for Standardization (ISO), and defines codes for the names of
+// | countries, dependent territories, and special areas of geographical
+// | interest.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_3166-1)
+//
+// Originally by zeyla on GitHub.
+
+//! Codes formerly used in ISO 3166-1, as listed by ISO 3166-3.
+//!
+//! When a country is renamed, merged, split, or dissolved, its codes are
+//! withdrawn from ISO 3166-1 and given a four-letter code in ISO 3166-3. The
+//! first two letters are the withdrawn Alpha2 code. The last two are the Alpha2
+//! code of the successor, or `"HH"` if the area was split between several, or
+//! `"AA"` and `"XX"` in a few special cases.
+//!
+//! # Examples
+//!
+//! Resolving a withdrawn code to the countries that replaced it:
+//!
+//! ```rust
+//! use iso3166_1::{iso3166_3, Country};
+//!
+//! let former = iso3166_3::alpha2("AN").unwrap();
+//!
+//! assert_eq!(former.code, "ANHH");
+//! assert_eq!(former.successors, &[Country::BQ, Country::CW, Country::SX]);
+//! ```
+
+// Where only the year a code was withdrawn is known, it is taken to be the
+// 1st of January of that year, as in `as_of`. Run 'make update-changes' after
+// editing the list, to rebuild the changes in `changes` from it.
+//
+// Source:
+// https://en.wikipedia.org/wiki/ISO_3166-3
+
+use as_of::{between, until};
+use Country::*;
+use {Country, Date, Validity};
+
+/// A country whose codes have been withdrawn from ISO 3166-1.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct FormerCountry {
+    /// Four-letter ISO 3166-3 code, such as `"ANHH"`
+    pub code: &'static str,
+    /// English short name the country was known by
+    pub name: &'static str,
+    /// The withdrawn Alpha2 code
+    pub alpha2: &'static str,
+    /// The withdrawn Alpha3 code
+    pub alpha3: &'static str,
+    /// The withdrawn numeric code, if the country had one
+    pub num: Option<&'static str>,
+    /// When the codes were used, until the day they were withdrawn
+    pub validity: Validity,
+    /// The current countries whose codes now cover the same area
+    pub successors: &'static [Country],
+}
+
+// Some codes have been withdrawn more than once, such as "CS", so the list is
+// ordered by when each code was withdrawn, most recent first.
+static FORMER: &[FormerCountry] = &[
+    FormerCountry {
+        code: "ANHH",
+        name: "Netherlands Antilles",
+        alpha2: "AN",
+        alpha3: "ANT",
+        num: Some("530"),
+        validity: until(2010, 12, 15),
+        successors: &[BQ, CW, SX],
+    },
+    FormerCountry {
+        code: "CSXX",
+        name: "Serbia and Montenegro",
+        alpha2: "CS",
+        alpha3: "SCG",
+        num: Some("891"),
+        validity: between(Date::new(2003, 7, 23), Date::new(2006, 9, 26)),
+        successors: &[ME, RS],
+    },
+    FormerCountry {
+        code: "YUCS",
+        name: "Yugoslavia",
+        alpha2: "YU",
+        alpha3: "YUG",
+        num: Some("891"),
+        validity: until(2003, 7, 23),
+        successors: &[ME, RS],
+    },
+    FormerCountry {
+        code: "TPTL",
+        name: "East Timor",
+        alpha2: "TP",
+        alpha3: "TMP",
+        num: Some("626"),
+        validity: until(2002, 5, 20),
+        successors: &[TL],
+    },
+    FormerCountry {
+        code: "ZRCD",
+        name: "Zaire",
+        alpha2: "ZR",
+        alpha3: "ZAR",
+        num: Some("180"),
+        validity: until(1997, 5, 17),
+        successors: &[CD],
+    },
+    FormerCountry {
+        code: "FXFR",
+        name: "France, Metropolitan",
+        alpha2: "FX",
+        alpha3: "FXX",
+        num: Some("249"),
+        validity: between(Date::new(1993, 1, 1), Date::new(1997, 1, 1)),
+        successors: &[FR],
+    },
+    FormerCountry {
+        code: "CSHH",
+        name: "Czechoslovakia",
+        alpha2: "CS",
+        alpha3: "CSK",
+        num: Some("200"),
+        validity: until(1993, 1, 1),
+        successors: &[CZ, SK],
+    },
+    FormerCountry {
+        code: "NTHH",
+        name: "Neutral Zone",
+        alpha2: "NT",
+        alpha3: "NTZ",
+        num: Some("536"),
+        validity: until(1993, 1, 1),
+        successors: &[IQ, SA],
+    },
+    FormerCountry {
+        code: "BYAA",
+        name: "Byelorussian SSR",
+        alpha2: "BY",
+        alpha3: "BYS",
+        num: Some("112"),
+        validity: until(1991, 12, 26),
+        successors: &[BY],
+    },
+    FormerCountry {
+        code: "SUHH",
+        name: "USSR",
+        alpha2: "SU",
+        alpha3: "SUN",
+        num: Some("810"),
+        validity: until(1991, 12, 26),
+        successors: &[AM, AZ, EE, GE, KG, KZ, LT, LV, MD, RU, TJ, TM, UZ],
+    },
+    FormerCountry {
+        code: "DDDE",
+        name: "German Democratic Republic",
+        alpha2: "DD",
+        alpha3: "DDR",
+        num: Some("278"),
+        validity: until(1990, 10, 3),
+        successors: &[DE],
+    },
+    FormerCountry {
+        code: "YDYE",
+        name: "Yemen, Democratic",
+        alpha2: "YD",
+        alpha3: "YMD",
+        num: Some("720"),
+        validity: until(1990, 5, 22),
+        successors: &[YE],
+    },
+    FormerCountry {
+        code: "BUMM",
+        name: "Burma",
+        alpha2: "BU",
+        alpha3: "BUR",
+        num: Some("104"),
+        validity: until(1989, 6, 18),
+        successors: &[MM],
+    },
+    FormerCountry {
+        code: "JTUM",
+        name: "Johnston Island",
+        alpha2: "JT",
+        alpha3: "JTN",
+        num: Some("396"),
+        validity: until(1986, 1, 1),
+        successors: &[UM],
+    },
+    FormerCountry {
+        code: "MIUM",
+        name: "Midway Islands",
+        alpha2: "MI",
+        alpha3: "MID",
+        num: Some("488"),
+        validity: until(1986, 1, 1),
+        successors: &[UM],
+    },
+    FormerCountry {
+        code: "PCHH",
+        name: "Pacific Islands (Trust Territory)",
+        alpha2: "PC",
+        alpha3: "PCI",
+        num: Some("582"),
+        validity: until(1986, 1, 1),
+        successors: &[FM, MH, MP, PW],
+    },
+    FormerCountry {
+        code: "PUUM",
+        name: "United States Miscellaneous Pacific Islands",
+        alpha2: "PU",
+        alpha3: "PUS",
+        num: Some("849"),
+        validity: until(1986, 1, 1),
+        successors: &[UM],
+    },
+    FormerCountry {
+        code: "WKUM",
+        name: "Wake Island",
+        alpha2: "WK",
+        alpha3: "WAK",
+        num: Some("872"),
+        validity: until(1986, 1, 1),
+        successors: &[UM],
+    },
+    FormerCountry {
+        code: "HVBF",
+        name: "Upper Volta",
+        alpha2: "HV",
+        alpha3: "HVO",
+        num: Some("854"),
+        validity: until(1984, 8, 4),
+        successors: &[BF],
+    },
+    FormerCountry {
+        code: "CTKI",
+        name: "Canton and Enderbury Islands",
+        alpha2: "CT",
+        alpha3: "CTE",
+        num: Some("128"),
+        validity: until(1984, 1, 1),
+        successors: &[KI],
+    },
+    FormerCountry {
+        code: "NQAQ",
+        name: "Dronning Maud Land",
+        alpha2: "NQ",
+        alpha3: "ATN",
+        num: Some("216"),
+        validity: until(1983, 1, 1),
+        successors: &[AQ],
+    },
+    FormerCountry {
+        code: "NHVU",
+        name: "New Hebrides",
+        alpha2: "NH",
+        alpha3: "NHB",
+        num: Some("548"),
+        validity: until(1980, 7, 30),
+        successors: &[VU],
+    },
+    FormerCountry {
+        code: "RHZW",
+        name: "Southern Rhodesia",
+        alpha2: "RH",
+        alpha3: "RHO",
+        num: Some("716"),
+        validity: until(1980, 4, 18),
+        successors: &[ZW],
+    },
+    FormerCountry {
+        code: "PZPA",
+        name: "Panama Canal Zone",
+        alpha2: "PZ",
+        alpha3: "PCZ",
+        num: Some("594"),
+        validity: until(1979, 10, 1),
+        successors: &[PA],
+    },
+    FormerCountry {
+        code: "BQAQ",
+        name: "British Antarctic Territory",
+        alpha2: "BQ",
+        alpha3: "ATB",
+        num: None,
+        validity: until(1979, 1, 1),
+        successors: &[AQ],
+    },
+    FormerCountry {
+        code: "FQHH",
+        name: "French Southern and Antarctic Territories",
+        alpha2: "FQ",
+        alpha3: "ATF",
+        num: None,
+        validity: until(1979, 1, 1),
+        successors: &[AQ, TF],
+    },
+    FormerCountry {
+        code: "GEHH",
+        name: "Gilbert and Ellice Islands",
+        alpha2: "GE",
+        alpha3: "GEL",
+        num: Some("296"),
+        validity: until(1979, 1, 1),
+        successors: &[KI, TV],
+    },
+    FormerCountry {
+        code: "AIDJ",
+        name: "French Afars and Issas",
+        alpha2: "AI",
+        alpha3: "AFI",
+        num: Some("262"),
+        validity: until(1977, 6, 27),
+        successors: &[DJ],
+    },
+    FormerCountry {
+        code: "VDVN",
+        name: "Viet-Nam, Democratic Republic of",
+        alpha2: "VD",
+        alpha3: "VDR",
+        num: None,
+        validity: until(1976, 7, 2),
+        successors: &[VN],
+    },
+    FormerCountry {
+        code: "DYBJ",
+        name: "Dahomey",
+        alpha2: "DY",
+        alpha3: "DHY",
+        num: Some("204"),
+        validity: until(1975, 11, 30),
+        successors: &[BJ],
+    },
+    FormerCountry {
+        code: "SKIN",
+        name: "Sikkim",
+        alpha2: "SK",
+        alpha3: "SKM",
+        num: None,
+        validity: until(1975, 5, 16),
+        successors: &[IN],
+    },
+];
+
+/// Returns all formerly used codes, most recently withdrawn first.
+pub fn all() -> &'static [FormerCountry] {
+    FORMER
+}
+
+/// Returns the former country with the given four-letter ISO 3166-3 code, if
+/// one exists.
+pub fn code(code: &str) -> Option<&'static FormerCountry> {
+    FORMER.iter().find(|f| f.code == code)
+}
+
+/// Returns the former country most recently withdrawn with the given Alpha2
+/// code, if one exists.
+///
+/// Some Alpha2 codes have since been reassigned, such as `"GE"`. This only
+/// looks at withdrawn codes, regardless of whether a country now has it.
+pub fn alpha2(alpha2: &str) -> Option<&'static FormerCountry> {
+    FORMER.iter().find(|f| f.alpha2 == alpha2)
+}
+
+/// Returns the former country most recently withdrawn with the given Alpha3
+/// code, if one exists.
+pub fn alpha3(alpha3: &str) -> Option<&'static FormerCountry> {
+    FORMER.iter().find(|f| f.alpha3 == alpha3)
+}
+
+/// Returns the former country most recently withdrawn with the given numeric
+/// code, if one exists.
+pub fn num(num: &str) -> Option<&'static FormerCountry> {
+    FORMER.iter().find(|f| f.num == Some(num))
+}
+
+/// Returns the current countries replacing a withdrawn Alpha2, Alpha3 or
+/// four-letter ISO 3166-3 code.
+///
+/// # Examples
+///
+/// ```rust
+/// use iso3166_1::{iso3166_3, Country};
+///
+/// assert_eq!(iso3166_3::successors("ZR"), Some(&[Country::CD][..]));
+/// assert_eq!(iso3166_3::successors("SCG"), Some(&[Country::ME, Country::RS][..]));
+/// ```
+pub fn successors(code: &str) -> Option<&'static [Country]> {
+    let former = match code.len() {
+        2 => alpha2(code),
+        3 => alpha3(code),
+        4 => self::code(code),
+        _ => None,
+    };
+
+    former.map(|f| f.successors)
+}
@@ -0,0 +1,12283 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 3166-1?
+//
+// | ISO 3166-1 is part of the ISO 3166 standard published by the International
+// | Organization for Standardization (ISO), and defines codes for the names of
+// | countries, dependent territories, and special areas of geographical
+// | interest.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_3166-1)
+//
+// Originally by zeyla on GitHub.
+
+//! Names of countries in other languages, from the Unicode Common Locale Data
+//! Repository (CLDR).
+//!
+//! Locales are given as BCP 47 language tags, such as `"de"` or `"de-AT"`.
+//! When a locale has no name of its own for a country, its parent locale is
+//! tried next, so `"de-AT"` falls back to `"de"`, and `"es-MX"` to `"es-419"`
+//! and then `"es"`.
+//!
+//! This module requires the `l10n` feature.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use iso3166_1::{l10n, Country};
+//!
+//! assert_eq!(l10n::name(Country::DE, "fr"), Some("Allemagne"));
+//! assert_eq!(Country::DE.localized_name("de-AT"), Some("Deutschland"));
+//! assert!(l10n::locales().any(|locale| locale == "zh-Hant"));
+//! ```
+
+// Source:
+// https://github.com/unicode-org/cldr
+//
+// Regenerate the tables below with `scripts/update_l10n.py`.
+
+use core::str;
+use Country;
+
+// The longest tag looked up, after dropping extensions. Longer tags are cut.
+const MAX_TAG: usize = 32;
+
+struct Locale {
+    tag: &'static str,
+    names: Names,
+}
+
+enum Names {
+    // The name of every country, in the order of `Country`.
+    Full(&'static [&'static str]),
+    // The names differing from those of the parent locale.
+    Partial(&'static [(Country, &'static str)]),
+}
+
+// Locales whose parent is not found by dropping their last subtag.
+static PARENTS: &[(&str, &str)] = &[
+    ("en-AU", "en-001"),
+    ("en-CA", "en-001"),
+    ("en-GB", "en-001"),
+    ("en-IE", "en-001"),
+    ("en-IN", "en-001"),
+    ("en-NZ", "en-001"),
+    ("en-SG", "en-001"),
+    ("en-ZA", "en-001"),
+    ("es-AR", "es-419"),
+    ("es-BO", "es-419"),
+    ("es-CL", "es-419"),
+    ("es-CO", "es-419"),
+    ("es-CR", "es-419"),
+    ("es-CU", "es-419"),
+    ("es-DO", "es-419"),
+    ("es-EC", "es-419"),
+    ("es-GT", "es-419"),
+    ("es-HN", "es-419"),
+    ("es-MX", "es-419"),
+    ("es-NI", "es-419"),
+    ("es-PA", "es-419"),
+    ("es-PE", "es-419"),
+    ("es-PR", "es-419"),
+    ("es-PY", "es-419"),
+    ("es-SV", "es-419"),
+    ("es-US", "es-419"),
+    ("es-UY", "es-419"),
+    ("es-VE", "es-419"),
+    ("nb", "no"),
+    ("sr-Latn", ""),
+    ("zh-HK", "zh-Hant"),
+    ("zh-Hant", ""),
+    ("zh-MO", "zh-HK"),
+    ("zh-TW", "zh-Hant"),
+];
+
+/// Returns the name of a country in the given locale, or in the nearest
+/// locale it falls back to.
+///
+/// The tag is matched ignoring case, and with `_` read as `-`. `None` is
+/// returned if neither the locale nor any of its parents is supported.
+///
+/// # Examples
+///
+/// ```rust
+/// use iso3166_1::{l10n, Country};
+///
+/// assert_eq!(l10n::name(Country::JP, "ja"), Some("日本"));
+/// assert_eq!(l10n::name(Country::JP, "pt_BR"), Some("Japão"));
+/// assert_eq!(l10n::name(Country::JP, "tlh"), None);
+/// ```
+pub fn name(country: Country, locale: &str) -> Option<&'static str> {
+    let mut buffer = [0; MAX_TAG];
+    let mut tag = normalize(locale, &mut buffer);
+
+    while !tag.is_empty() {
+        if let Ok(i) = LOCALES.binary_search_by(|l| l.tag.cmp(tag)) {
+            match LOCALES[i].names {
+                Names::Full(names) => return Some(names[country as usize]),
+                Names::Partial(names) => {
+                    if let Some(&(_, name)) = names.iter().find(|&&(c, _)| c == country) {
+                        return Some(name);
+                    }
+                }
+            }
+        }
+
+        tag = parent(tag);
+    }
+
+    None
+}
+
+/// Returns the tags of the locales with names of their own, sorted.
+///
+/// Other locales are supported through fallback, such as `"de-DE"` through
+/// `"de"`.
+pub fn locales() -> impl Iterator<Item = &'static str> {
+    LOCALES.iter().map(|locale| locale.tag)
+}
+
+// Returns the tag a locale falls back to, or an empty tag if there is none.
+fn parent(tag: &str) -> &str {
+    if let Some(&(_, parent)) = PARENTS.iter().find(|&&(locale, _)| locale == tag) {
+        return parent;
+    }
+
+    match tag.rfind('-') {
+        Some(end) => &tag[..end],
+        None => "",
+    }
+}
+
+// Writes the tag in its canonical case, such as "zh-Hant-HK", without any
+// extensions or private use subtags.
+fn normalize<'a>(locale: &str, buffer: &'a mut [u8; MAX_TAG]) -> &'a str {
+    let mut len = 0;
+
+    for (i, subtag) in locale.trim().split(['-', '_']).enumerate() {
+        let fits = len + subtag.len() < MAX_TAG;
+
+        if subtag.len() < 2 || !subtag.is_ascii() || !fits {
+            break;
+        }
+
+        if i > 0 {
+            buffer[len] = b'-';
+            len += 1;
+        }
+
+        for (j, byte) in subtag.bytes().enumerate() {
+            buffer[len] = match (i, subtag.len(), j) {
+                (0, _, _) => byte.to_ascii_lowercase(),
+                (_, 4, 0) => byte.to_ascii_uppercase(),
+                (_, 4, _) => byte.to_ascii_lowercase(),
+                (_, 2, _) => byte.to_ascii_uppercase(),
+                _ => byte,
+            };
+            len += 1;
+        }
+    }
+
+    str::from_utf8(&buffer[..len]).unwrap_or("")
+}
+
+// Begin locales
+static LOCALES: [Locale; 57] = [
+    Locale {
+        tag: "af",
+        names: Names::Full(&AF),
+    },
+    Locale {
+        tag: "ar",
+        names: Names::Full(&AR),
+    },
+    Locale {
+        tag: "bg",
+        names: Names::Full(&BG),
+    },
+    Locale {
+        tag: "bn",
+        names: Names::Full(&BN),
+    },
+    Locale {
+        tag: "ca",
+        names: Names::Full(&CA),
+    },
+    Locale {
+        tag: "cs",
+        names: Names::Full(&CS),
+    },
+    Locale {
+        tag: "da",
+        names: Names::Full(&DA),
+    },
+    Locale {
+        tag: "de",
+        names: Names::Full(&DE),
+    },
+    Locale {
+        tag: "de-AT",
+        names: Names::Partial(DE_AT),
+    },
+    Locale {
+        tag: "de-CH",
+        names: Names::Partial(DE_CH),
+    },
+    Locale {
+        tag: "el",
+        names: Names::Full(&EL),
+    },
+    Locale {
+        tag: "en",
+        names: Names::Full(&EN),
+    },
+    Locale {
+        tag: "en-001",
+        names: Names::Partial(EN_001),
+    },
+    Locale {
+        tag: "en-AU",
+        names: Names::Partial(EN_AU),
+    },
+    Locale {
+        tag: "en-CA",
+        names: Names::Partial(EN_CA),
+    },
+    Locale {
+        tag: "es",
+        names: Names::Full(&ES),
+    },
+    Locale {
+        tag: "es-419",
+        names: Names::Partial(ES_419),
+    },
+    Locale {
+        tag: "es-MX",
+        names: Names::Partial(ES_MX),
+    },
+    Locale {
+        tag: "et",
+        names: Names::Full(&ET),
+    },
+    Locale {
+        tag: "fa",
+        names: Names::Full(&FA),
+    },
+    Locale {
+        tag: "fi",
+        names: Names::Full(&FI),
+    },
+    Locale {
+        tag: "fil",
+        names: Names::Full(&FIL),
+    },
+    Locale {
+        tag: "fr",
+        names: Names::Full(&FR),
+    },
+    Locale {
+        tag: "fr-BE",
+        names: Names::Partial(FR_BE),
+    },
+    Locale {
+        tag: "fr-CA",
+        names: Names::Partial(FR_CA),
+    },
+    Locale {
+        tag: "he",
+        names: Names::Full(&HE),
+    },
+    Locale {
+        tag: "hi",
+        names: Names::Full(&HI),
+    },
+    Locale {
+        tag: "hr",
+        names: Names::Full(&HR),
+    },
+    Locale {
+        tag: "hu",
+        names: Names::Full(&HU),
+    },
+    Locale {
+        tag: "id",
+        names: Names::Full(&ID),
+    },
+    Locale {
+        tag: "it",
+        names: Names::Full(&IT),
+    },
+    Locale {
+        tag: "ja",
+        names: Names::Full(&JA),
+    },
+    Locale {
+        tag: "ko",
+        names: Names::Full(&KO),
+    },
+    Locale {
+        tag: "lt",
+        names: Names::Full(&LT),
+    },
+    Locale {
+        tag: "lv",
+        names: Names::Full(&LV),
+    },
+    Locale {
+        tag: "ms",
+        names: Names::Full(&MS),
+    },
+    Locale {
+        tag: "nl",
+        names: Names::Full(&NL),
+    },
+    Locale {
+        tag: "no",
+        names: Names::Full(&NO),
+    },
+    Locale {
+        tag: "pl",
+        names: Names::Full(&PL),
+    },
+    Locale {
+        tag: "pt",
+        names: Names::Full(&PT),
+    },
+    Locale {
+        tag: "pt-PT",
+        names: Names::Partial(PT_PT),
+    },
+    Locale {
+        tag: "ro",
+        names: Names::Full(&RO),
+    },
+    Locale {
+        tag: "ru",
+        names: Names::Full(&RU),
+    },
+    Locale {
+        tag: "sk",
+        names: Names::Full(&SK),
+    },
+    Locale {
+        tag: "sl",
+        names: Names::Full(&SL),
+    },
+    Locale {
+        tag: "sr",
+        names: Names::Full(&SR),
+    },
+    Locale {
+        tag: "sr-Latn",
+        names: Names::Full(&SR_LATN),
+    },
+    Locale {
+        tag: "sv",
+        names: Names::Full(&SV),
+    },
+    Locale {
+        tag: "sw",
+        names: Names::Full(&SW),
+    },
+    Locale {
+        tag: "th",
+        names: Names::Full(&TH),
+    },
+    Locale {
+        tag: "tr",
+        names: Names::Full(&TR),
+    },
+    Locale {
+        tag: "uk",
+        names: Names::Full(&UK),
+    },
+    Locale {
+        tag: "ur",
+        names: Names::Full(&UR),
+    },
+    Locale {
+        tag: "vi",
+        names: Names::Full(&VI),
+    },
+    Locale {
+        tag: "zh",
+        names: Names::Full(&ZH),
+    },
+    Locale {
+        tag: "zh-HK",
+        names: Names::Partial(ZH_HK),
+    },
+    Locale {
+        tag: "zh-Hant",
+        names: Names::Full(&ZH_HANT),
+    },
+];
+
+static AF: [&str; 249] = [
+    "Afganistan",
+    "Albanië",
+    "Antarktika",
+    "Algerië",
+    "Amerikaanse Samoa",
+    "Andorra",
+    "Angola",
+    "Antigua en Barbuda",
+    "Azerbeidjan",
+    "Argentinië",
+    "Australië",
+    "Oostenryk",
+    "Bahamas",
+    "Bahrein",
+    "Bangladesj",
+    "Armenië",
+    "Barbados",
+    "België",
+    "Bermuda",
+    "Bhoetan",
+    "Bolivië",
+    "Bosnië en Herzegowina",
+    "Botswana",
+    "Bouvet-eiland",
+    "Brasilië",
+    "Belize",
+    "Brits-Indiese Oseaangebied",
+    "Salomonseilande",
+    "Britse Maagde-eilande",
+    "Broenei",
+    "Bulgarye",
+    "Mianmar (Birma)",
+    "Burundi",
+    "Belarus",
+    "Kambodja",
+    "Kameroen",
+    "Kanada",
+    "Kaap Verde",
+    "Kaaimanseilande",
+    "Sentraal-Afrikaanse Republiek",
+    "Sri Lanka",
+    "Tsjad",
+    "Chili",
+    "China",
+    "Taiwan",
+    "Kerseiland",
+    "Kokoseilande",
+    "Colombië",
+    "Comore",
+    "Mayotte",
+    "Kongo-Brazzaville",
+    "Demokratiese Republiek van die Kongo",
+    "Cookeilande",
+    "Costa Rica",
+    "Kroasië",
+    "Kuba",
+    "Siprus",
+    "Tsjeggië",
+    "Benin",
+    "Denemarke",
+    "Dominica",
+    "Dominikaanse Republiek",
+    "Ecuador",
+    "El Salvador",
+    "Ekwatoriaal-Guinee",
+    "Ethiopië",
+    "Eritrea",
+    "Estland",
+    "Faroëreilande",
+    "Falklandeilande",
+    "Suid-Georgië en die Suidelike Sandwicheilande",
+    "Fidji",
+    "Finland",
+    "Ålandeilande",
+    "Frankryk",
+    "Frans-Guyana",
+    "Frans-Polinesië",
+    "Franse Suidelike Gebiede",
+    "Djiboeti",
+    "Gaboen",
+    "Georgië",
+    "Gambië",
+    "Palestynse Grondgebiede",
+    "Duitsland",
+    "Ghana",
+    "Gibraltar",
+    "Kiribati",
+    "Griekeland",
+    "Groenland",
+    "Grenada",
+    "Guadeloupe",
+    "Guam",
+    "Guatemala",
+    "Guinee",
+    "Guyana",
+    "Haïti",
+    "Heardeiland en McDonaldeilande",
+    "Vatikaanstad",
+    "Honduras",
+    "Hongkong SAS China",
+    "Hongarye",
+    "Ysland",
+    "Indië",
+    "Indonesië",
+    "Iran",
+    "Irak",
+    "Ierland",
+    "Israel",
+    "Italië",
+    "Ivoorkus",
+    "Jamaika",
+    "Japan",
+    "Kazakstan",
+    "Jordanië",
+    "Kenia",
+    "Noord-Korea",
+    "Suid-Korea",
+    "Koeweit",
+    "Kirgistan",
+    "Laos",
+    "Libanon",
+    "Lesotho",
+    "Letland",
+    "Liberië",
+    "Libië",
+    "Liechtenstein",
+    "Litaue",
+    "Luxemburg",
+    "Macau SAS China",
+    "Madagaskar",
+    "Malawi",
+    "Maleisië",
+    "Maledive",
+    "Mali",
+    "Malta",
+    "Martinique",
+    "Mauritanië",
+    "Mauritius",
+    "Meksiko",
+    "Monaco",
+    "Mongolië",
+    "Moldowa",
+    "Montenegro",
+    "Montserrat",
+    "Marokko",
+    "Mosambiek",
+    "Oman",
+    "Namibië",
+    "Nauru",
+    "Nepal",
+    "Nederland",
+    "Curaçao",
+    "Aruba",
+    "Sint Maarten",
+    "Karibiese Nederland",
+    "Nieu-Kaledonië",
+    "Vanuatu",
+    "Nieu-Seeland",
+    "Nicaragua",
+    "Niger",
+    "Nigerië",
+    "Niue",
+    "Norfolkeiland",
+    "Noorweë",
+    "Noord-Mariane-eilande",
+    "Klein afgeleë eilande van die VSA",
+    "Mikronesië",
+    "Marshalleilande",
+    "Palau",
+    "Pakistan",
+    "Panama",
+    "Papoea-Nieu-Guinee",
+    "Paraguay",
+    "Peru",
+    "Filippyne",
+    "Pitcairneilande",
+    "Pole",
+    "Portugal",
+    "Guinee-Bissau",
+    "Oos-Timor",
+    "Puerto Rico",
+    "Katar",
+    "Réunion",
+    "Roemenië",
+    "Rusland",
+    "Rwanda",
+    "Sint Barthélemy",
+    "Sint Helena",
+    "Sint Kitts en Nevis",
+    "Anguilla",
+    "Sint Lucia",
+    "Sint Martin",
+    "Sint Pierre en Miquelon",
+    "Sint Vincent en die Grenadine",
+    "San Marino",
+    "São Tomé en Príncipe",
+    "Saoedi-Arabië",
+    "Senegal",
+    "Serwië",
+    "Seychelle",
+    "Sierra Leone",
+    "Singapoer",
+    "Slowakye",
+    "Viëtnam",
+    "Slowenië",
+    "Somalië",
+    "Suid-Afrika",
+    "Zimbabwe",
+    "Spanje",
+    "Suid-Soedan",
+    "Soedan",
+    "Wes-Sahara",
+    "Suriname",
+    "Spitsbergen en Jan Mayen",
+    "Eswatini",
+    "Swede",
+    "Switserland",
+    "Sirië",
+    "Tadjikistan",
+    "Thailand",
+    "Togo",
+    "Tokelau",
+    "Tonga",
+    "Trinidad en Tobago",
+    "Verenigde Arabiese Emirate",
+    "Tunisië",
+    "Turkye",
+    "Turkmenistan",
+    "Turks- en Caicoseilande",
+    "Tuvalu",
+    "Uganda",
+    "Oekraïne",
+    "Noord-Macedonië",
+    "Egipte",
+    "Verenigde Koninkryk",
+    "Guernsey",
+    "Jersey",
+    "Eiland Man",
+    "Tanzanië",
+    "Verenigde State van Amerika",
+    "VSA se Maagde-eilande",
+    "Burkina Faso",
+    "Uruguay",
+    "Oesbekistan",
+    "Venezuela",
+    "Wallis en Futuna",
+    "Samoa",
+    "Jemen",
+    "Zambië",
+];
+
+static AR: [&str; 249] = [
+    "أفغانستان",
+    "ألبانيا",
+    "أنتاركتيكا",
+    "الجزائر",
+    "ساموا الأمريكية",
+    "أندورا",
+    "أنغولا",
+    "أنتيغوا وبربودا",
+    "أذربيجان",
+    "الأرجنتين",
+    "أستراليا",
+    "النمسا",
+    "جزر البهاما",
+    "البحرين",
+    "بنغلاديش",
+    "أرمينيا",
+    "بربادوس",
+    "بلجيكا",
+    "برمودا",
+    "بوتان",
+    "بوليفيا",
+    "البوسنة والهرسك",
+    "بوتسوانا",
+    "جزيرة بوفيه",
+    "البرازيل",
+    "بليز",
+    "الإقليم البريطاني في المحيط الهندي",
+    "جزر سليمان",
+    "جزر فيرجن البريطانية",
+    "بروناي",
+    "بلغاريا",
+    "ميانمار (بورما)",
+    "بوروندي",
+    "بيلاروس",
+    "كمبوديا",
+    "الكاميرون",
+    "كندا",
+    "الرأس الأخضر",
+    "جزر كايمان",
+    "جمهورية أفريقيا الوسطى",
+    "سريلانكا",
+    "تشاد",
+    "تشيلي",
+    "الصين",
+    "تايوان",
+    "جزيرة كريسماس",
+    "جزر كوكوس (كيلينغ)",
+    "كولومبيا",
+    "جزر القمر",
+    "مايوت",
+    "الكونغو - برازافيل",
+    "الكونغو - كينشاسا",
+    "جزر كوك",
+    "كوستاريكا",
+    "كرواتيا",
+    "كوبا",
+    "قبرص",
+    "التشيك",
+    "بنين",
+    "الدانمرك",
+    "دومينيكا",
+    "جمهورية الدومينيكان",
+    "الإكوادور",
+    "السلفادور",
+    "غينيا الاستوائية",
+    "إثيوبيا",
+    "إريتريا",
+    "إستونيا",
+    "جزر فارو",
+    "جزر فوكلاند",
+    "جورجيا الجنوبية وجزر ساندويتش الجنوبية",
+    "فيجي",
+    "فنلندا",
+    "جزر آلاند",
+    "فرنسا",
+    "غويانا الفرنسية",
+    "بولينيزيا الفرنسية",
+    "الأقاليم الجنوبية الفرنسية",
+    "جيبوتي",
+    "الغابون",
+    "جورجيا",
+    "غامبيا",
+    "الأراضي الفلسطينية",
+    "ألمانيا",
+    "غانا",
+    "جبل طارق",
+    "كيريباتي",
+    "اليونان",
+    "غرينلاند",
+    "غرينادا",
+    "غوادلوب",
+    "غوام",
+    "غواتيمالا",
+    "غينيا",
+    "غيانا",
+    "هايتي",
+    "جزيرة هيرد وجزر ماكدونالد",
+    "الفاتيكان",
+    "هندوراس",
+    "هونغ كونغ الصينية (منطقة إدارية خاصة)",
+    "هنغاريا",
+    "آيسلندا",
+    "الهند",
+    "إندونيسيا",
+    "إيران",
+    "العراق",
+    "أيرلندا",
+    "إسرائيل",
+    "إيطاليا",
+    "ساحل العاج",
+    "جامايكا",
+    "اليابان",
+    "كازاخستان",
+    "الأردن",
+    "كينيا",
+    "كوريا الشمالية",
+    "كوريا الجنوبية",
+    "الكويت",
+    "قيرغيزستان",
+    "لاوس",
+    "لبنان",
+    "ليسوتو",
+    "لاتفيا",
+    "ليبيريا",
+    "ليبيا",
+    "ليختنشتاين",
+    "ليتوانيا",
+    "لوكسمبورغ",
+    "منطقة ماكاو الإدارية الخاصة",
+    "مدغشقر",
+    "ملاوي",
+    "ماليزيا",
+    "جزر المالديف",
+    "مالي",
+    "مالطا",
+    "جزر المارتينيك",
+    "موريتانيا",
+    "موريشيوس",
+    "المكسيك",
+    "موناكو",
+    "منغوليا",
+    "مولدوفا",
+    "الجبل الأسود",
+    "مونتسرات",
+    "المغرب",
+    "موزمبيق",
+    "عُمان",
+    "ناميبيا",
+    "ناورو",
+    "نيبال",
+    "هولندا",
+    "كوراساو",
+    "أروبا",
+    "سانت مارتن",
+    "هولندا الكاريبية",
+    "كاليدونيا الجديدة",
+    "فانواتو",
+    "نيوزيلندا",
+    "نيكاراغوا",
+    "النيجر",
+    "نيجيريا",
+    "نيوي",
+    "جزيرة نورفولك",
+    "النرويج",
+    "جزر ماريانا الشمالية",
+    "جزر الولايات المتحدة النائية",
+    "ميكرونيزيا",
+    "جزر مارشال",
+    "بالاو",
+    "باكستان",
+    "بنما",
+    "بابوا غينيا الجديدة",
+    "باراغواي",
+    "بيرو",
+    "الفلبين",
+    "جزر بيتكيرن",
+    "بولندا",
+    "البرتغال",
+    "غينيا بيساو",
+    "تيمور - ليشتي",
+    "بورتوريكو",
+    "قطر",
+    "روينيون",
+    "رومانيا",
+    "روسيا",
+    "رواندا",
+    "سان بارتليمي",
+    "سانت هيلينا",
+    "سانت كيتس ونيفيس",
+    "أنغويلا",
+    "سانت لوسيا",
+    "سان مارتن",
+    "سان بيير ومكويلون",
+    "سانت فنسنت وجزر غرينادين",
+    "سان مارينو",
+    "ساو تومي وبرينسيبي",
+    "المملكة العربية السعودية",
+    "السنغال",
+    "صربيا",
+    "سيشل",
+    "سيراليون",
+    "سنغافورة",
+    "سلوفاكيا",
+    "فيتنام",
+    "سلوفينيا",
+    "الصومال",
+    "جنوب أفريقيا",
+    "زيمبابوي",
+    "إسبانيا",
+    "جنوب السودان",
+    "السودان",
+    "الصحراء الغربية",
+    "سورينام",
+    "سفالبارد وجان ماين",
+    "إسواتيني",
+    "السويد",
+    "سويسرا",
+    "سوريا",
+    "طاجيكستان",
+    "تايلاند",
+    "توغو",
+    "توكيلاو",
+    "تونغا",
+    "ترينيداد وتوباغو",
+    "الإمارات العربية المتحدة",
+    "تونس",
+    "تركيا",
+    "تركمانستان",
+    "جزر توركس وكايكوس",
+    "توفالو",
+    "أوغندا",
+    "أوكرانيا",
+    "مقدونيا الشمالية",
+    "مصر",
+    "المملكة المتحدة",
+    "غيرنزي",
+    "جيرسي",
+    "جزيرة مان",
+    "تنزانيا",
+    "الولايات المتحدة",
+    "جزر فيرجن الأمريكية",
+    "بوركينا فاسو",
+    "أورغواي",
+    "أوزبكستان",
+    "فنزويلا",
+    "جزر والس وفوتونا",
+    "ساموا",
+    "اليمن",
+    "زامبيا",
+];
+
+static BG: [&str; 249] = [
+    "Афганистан",
+    "Албания",
+    "Антарктика",
+    "Алжир",
+    "Американска Самоа",
+    "Андора",
+    "Ангола",
+    "Антигуа и Барбуда",
+    "Азербайджан",
+    "Аржентина",
+    "Австралия",
+    "Австрия",
+    "Бахамски острови",
+    "Бахрейн",
+    "Бангладеш",
+    "Армения",
+    "Барбадос",
+    "Белгия",
+    "Бермудски острови",
+    "Бутан",
+    "Боливия",
+    "Босна и Херцеговина",
+    "Ботсвана",
+    "остров Буве",
+    "Бразилия",
+    "Белиз",
+    "Британска територия в Индийския океан",
+    "Соломонови острови",
+    "Британски Вирджински острови",
+    "Бруней Даруссалам",
+    "България",
+    "Мианмар (Бирма)",
+    "Бурунди",
+    "Беларус",
+    "Камбоджа",
+    "Камерун",
+    "Канада",
+    "Кабо Верде",
+    "Кайманови острови",
+    "Централноафриканска република",
+    "Шри Ланка",
+    "Чад",
+    "Чили",
+    "Китай",
+    "Тайван",
+    "остров Рождество",
+    "Кокосови острови (острови Кийлинг)",
+    "Колумбия",
+    "Коморски острови",
+    "Майот",
+    "Конго (Бразавил)",
+    "Конго (Киншаса)",
+    "острови Кук",
+    "Коста Рика",
+    "Хърватия",
+    "Куба",
+    "Кипър",
+    "Чехия",
+    "Бенин",
+    "Дания",
+    "Доминика",
+    "Доминиканска република",
+    "Еквадор",
+    "Салвадор",
+    "Екваториална Гвинея",
+    "Етиопия",
+    "Еритрея",
+    "Естония",
+    "Фарьорски острови",
+    "Фолкландски острови",
+    "Южна Джорджия и Южни Сандвичеви острови",
+    "Фиджи",
+    "Финландия",
+    "Оландски острови",
+    "Франция",
+    "Френска Гвиана",
+    "Френска Полинезия",
+    "Френски южни територии",
+    "Джибути",
+    "Габон",
+    "Грузия",
+    "Гамбия",
+    "Палестински територии",
+    "Германия",
+    "Гана",
+    "Гибралтар",
+    "Кирибати",
+    "Гърция",
+    "Гренландия",
+    "Гренада",
+    "Гваделупа",
+    "Гуам",
+    "Гватемала",
+    "Гвинея",
+    "Гаяна",
+    "Хаити",
+    "острови Хърд и Макдоналд",
+    "Ватикан",
+    "Хондурас",
+    "Хонконг, САР на Китай",
+    "Унгария",
+    "Исландия",
+    "Индия",
+    "Индонезия",
+    "Иран",
+    "Ирак",
+    "Ирландия",
+    "Израел",
+    "Италия",
+    "Кот д’Ивоар",
+    "Ямайка",
+    "Япония",
+    "Казахстан",
+    "Йордания",
+    "Кения",
+    "Северна Корея",
+    "Южна Корея",
+    "Кувейт",
+    "Киргизстан",
+    "Лаос",
+    "Ливан",
+    "Лесото",
+    "Латвия",
+    "Либерия",
+    "Либия",
+    "Лихтенщайн",
+    "Литва",
+    "Люксембург",
+    "Макао, САР на Китай",
+    "Мадагаскар",
+    "Малави",
+    "Малайзия",
+    "Малдиви",
+    "Мали",
+    "Малта",
+    "Мартиника",
+    "Мавритания",
+    "Мавриций",
+    "Мексико",
+    "Монако",
+    "Монголия",
+    "Молдова",
+    "Черна гора",
+    "Монтсерат",
+    "Мароко",
+    "Мозамбик",
+    "Оман",
+    "Намибия",
+    "Науру",
+    "Непал",
+    "Нидерландия",
+    "Кюрасао",
+    "Аруба",
+    "Синт Мартен",
+    "Карибска Нидерландия",
+    "Нова Каледония",
+    "Вануату",
+    "Нова Зеландия",
+    "Никарагуа",
+    "Нигер",
+    "Нигерия",
+    "Ниуе",
+    "остров Норфолк",
+    "Норвегия",
+    "Северни Мариански острови",
+    "Отдалечени острови на САЩ",
+    "Микронезия",
+    "Маршалови острови",
+    "Палау",
+    "Пакистан",
+    "Панама",
+    "Папуа-Нова Гвинея",
+    "Парагвай",
+    "Перу",
+    "Филипини",
+    "Острови Питкерн",
+    "Полша",
+    "Португалия",
+    "Гвинея-Бисау",
+    "Тимор Лесте",
+    "Пуерто Рико",
+    "Катар",
+    "Реюнион",
+    "Румъния",
+    "Русия",
+    "Руанда",
+    "Сен Бартелеми",
+    "Света Елена",
+    "Сейнт Китс и Невис",
+    "Ангуила",
+    "Сейнт Лусия",
+    "Сен Мартен",
+    "Сен Пиер и Микелон",
+    "Сейнт Винсънт и Гренадини",
+    "Сан Марино",
+    "Сао Томе и Принсипи",
+    "Саудитска Арабия",
+    "Сенегал",
+    "Сърбия",
+    "Сейшели",
+    "Сиера Леоне",
+    "Сингапур",
+    "Словакия",
+    "Виетнам",
+    "Словения",
+    "Сомалия",
+    "Южна Африка",
+    "Зимбабве",
+    "Испания",
+    "Южен Судан",
+    "Судан",
+    "Западна Сахара",
+    "Суринам",
+    "Свалбард и Ян Майен",
+    "Есватини",
+    "Швеция",
+    "Швейцария",
+    "Сирия",
+    "Таджикистан",
+    "Тайланд",
+    "Того",
+    "Токелау",
+    "Тонга",
+    "Тринидад и Тобаго",
+    "Обединени арабски емирства",
+    "Тунис",
+    "Турция",
+    "Туркменистан",
+    "острови Търкс и Кайкос",
+    "Тувалу",
+    "Уганда",
+    "Украйна",
+    "Северна Македония",
+    "Египет",
+    "Обединеното кралство",
+    "Гърнзи",
+    "Джърси",
+    "остров Ман",
+    "Танзания",
+    "Съединени щати",
+    "Американски Вирджински острови",
+    "Буркина Фасо",
+    "Уругвай",
+    "Узбекистан",
+    "Венецуела",
+    "Уолис и Футуна",
+    "Самоа",
+    "Йемен",
+    "Замбия",
+];
+
+static BN: [&str; 249] = [
+    "আফগানিস্তান",
+    "আলবেনিয়া",
+    "অ্যান্টার্কটিকা",
+    "আলজেরিয়া",
+    "আমেরিকান সামোয়া",
+    "আন্ডোরা",
+    "অ্যাঙ্গোলা",
+    "অ্যান্টিগুয়া ও বারবুডা",
+    "আজারবাইজান",
+    "আর্জেন্টিনা",
+    "অস্ট্রেলিয়া",
+    "অস্ট্রিয়া",
+    "বাহামা দ্বীপপুঞ্জ",
+    "বাহারিন",
+    "বাংলাদেশ",
+    "আর্মেনিয়া",
+    "বার্বাডোজ",
+    "বেলজিয়াম",
+    "বারমুডা",
+    "ভুটান",
+    "বলিভিয়া",
+    "বসনিয়া ও হার্জেগোভিনা",
+    "বতসোয়ানা",
+    "বোভেট দ্বীপ",
+    "ব্রাজিল",
+    "বেলিজ",
+    "ব্রিটিশ ভারত মহাসাগরীয় অঞ্চল",
+    "সলোমন দ্বীপপুঞ্জ",
+    "ব্রিটিশ ভার্জিন দ্বীপপুঞ্জ",
+    "ব্রুনেই",
+    "বুলগেরিয়া",
+    "মায়ানমার (বার্মা)",
+    "বুরুন্ডি",
+    "বেলারুশ",
+    "কম্বোডিয়া",
+    "ক্যামেরুন",
+    "কানাডা",
+    "কেপ ভার্দে",
+    "কেম্যান দ্বীপপুঞ্জ",
+    "মধ্য আফ্রিকার প্রজাতন্ত্র",
+    "শ্রীলঙ্কা",
+    "চাদ",
+    "চিলি",
+    "চীন",
+    "তাইওয়ান",
+    "ক্রিসমাস দ্বীপ",
+    "কোকোস (কিলিং) দ্বীপপুঞ্জ",
+    "কলম্বিয়া",
+    "কমোরোস",
+    "মায়োত্তে",
+    "কঙ্গো - ব্রাজাভিল",
+    "কঙ্গো-কিনশাসা",
+    "কুক দ্বীপপুঞ্জ",
+    "কোস্টারিকা",
+    "ক্রোয়েশিয়া",
+    "কিউবা",
+    "সাইপ্রাস",
+    "চেকিয়া",
+    "বেনিন",
+    "ডেনমার্ক",
+    "ডোমিনিকা",
+    "ডোমেনিকান প্রজাতন্ত্র",
+    "ইকুয়েডর",
+    "এল সালভেদর",
+    "নিরক্ষীয় গিনি",
+    "ইথিওপিয়া",
+    "ইরিত্রিয়া",
+    "এস্তোনিয়া",
+    "ফ্যারো দ্বীপপুঞ্জ",
+    "ফকল্যান্ড দ্বীপপুঞ্জ",
+    "দক্ষিণ জর্জিয়া ও দক্ষিণ স্যান্ডউইচ দ্বীপপুঞ্জ",
+    "ফিজি",
+    "ফিনল্যান্ড",
+    "অলান্ড দ্বীপপুঞ্জ",
+    "ফ্রান্স",
+    "ফরাসী গায়ানা",
+    "ফরাসী পলিনেশিয়া",
+    "ফরাসী দক্ষিণাঞ্চল",
+    "জিবুতি",
+    "গ্যাবন",
+    "জর্জিয়া",
+    "গাম্বিয়া",
+    "প্যালেস্টাইন ভূখণ্ড",
+    "জার্মানি",
+    "ঘানা",
+    "জিব্রাল্টার",
+    "কিরিবাতি",
+    "গ্রীস",
+    "গ্রীনল্যান্ড",
+    "গ্রেনাডা",
+    "গুয়াদেলৌপ",
+    "গুয়াম",
+    "গুয়াতেমালা",
+    "গিনি",
+    "গিয়ানা",
+    "হাইতি",
+    "হার্ড এবং ম্যাকডোনাল্ড দ্বীপপুঞ্জ",
+    "ভ্যাটিকান সিটি",
+    "হন্ডুরাস",
+    "হংকং এসএআর চীনা",
+    "হাঙ্গেরি",
+    "আইসল্যান্ড",
+    "ভারত",
+    "ইন্দোনেশিয়া",
+    "ইরান",
+    "ইরাক",
+    "আয়ারল্যান্ড",
+    "ইজরায়েল",
+    "ইতালি",
+    "কোট ডি‘আইভোর",
+    "জামাইকা",
+    "জাপান",
+    "কাজাখস্তান",
+    "জর্ডন",
+    "কেনিয়া",
+    "উত্তর কোরিয়া",
+    "দক্ষিণ কোরিয়া",
+    "কুয়েত",
+    "কিরগিজিস্তান",
+    "লাওস",
+    "লেবানন",
+    "লেসোথো",
+    "লাটভিয়া",
+    "লাইবেরিয়া",
+    "লিবিয়া",
+    "লিচেনস্টেইন",
+    "লিথুয়ানিয়া",
+    "লাক্সেমবার্গ",
+    "ম্যাকাও এসএআর চীন",
+    "মাদাগাস্কার",
+    "মালাউই",
+    "মালয়েশিয়া",
+    "মালদ্বীপ",
+    "মালি",
+    "মাল্টা",
+    "মার্টিনিক",
+    "মরিতানিয়া",
+    "মরিশাস",
+    "মেক্সিকো",
+    "মোনাকো",
+    "মঙ্গোলিয়া",
+    "মলডোভা",
+    "মন্টিনিগ্রো",
+    "মন্টসেরাট",
+    "মোরক্কো",
+    "মোজাম্বিক",
+    "ওমান",
+    "নামিবিয়া",
+    "নাউরু",
+    "নেপাল",
+    "নেদারল্যান্ডস",
+    "কুরাসাও",
+    "আরুবা",
+    "সিন্ট মার্টেন",
+    "ক্যারিবিয়ান নেদারল্যান্ডস",
+    "নিউ ক্যালেডোনিয়া",
+    "ভানুয়াটু",
+    "নিউজিল্যান্ড",
+    "নিকারাগুয়া",
+    "নাইজার",
+    "নাইজেরিয়া",
+    "নিউয়ে",
+    "নরফোক দ্বীপ",
+    "নরওয়ে",
+    "উত্তরাঞ্চলীয় মারিয়ানা দ্বীপপুঞ্জ",
+    "যুক্তরাষ্ট্রের পার্শ্ববর্তী দ্বীপপুঞ্জ",
+    "মাইক্রোনেশিয়া",
+    "মার্শাল দ্বীপপুঞ্জ",
+    "পালাউ",
+    "পাকিস্তান",
+    "পানামা",
+    "পাপুয়া নিউ গিনি",
+    "প্যারাগুয়ে",
+    "পেরু",
+    "ফিলিপাইন",
+    "পিটকেয়ার্ন দ্বীপপুঞ্জ",
+    "পোল্যান্ড",
+    "পর্তুগাল",
+    "গিনি-বিসাউ",
+    "তিমুর-লেস্তে",
+    "পুয়ের্তো রিকো",
+    "কাতার",
+    "রিইউনিয়ন",
+    "রোমানিয়া",
+    "রাশিয়া",
+    "রুয়ান্ডা",
+    "সেন্ট বার্থেলেমি",
+    "সেন্ট হেলেনা",
+    "সেন্ট কিটস ও নেভিস",
+    "অ্যাঙ্গুইলা",
+    "সেন্ট লুসিয়া",
+    "সেন্ট মার্টিন",
+    "সেন্ট পিয়ের ও মিকুয়েলন",
+    "সেন্ট ভিনসেন্ট ও গ্রেনাডিনস",
+    "সান মারিনো",
+    "সাওটোমা ও প্রিন্সিপি",
+    "সৌদি আরব",
+    "সেনেগাল",
+    "সার্বিয়া",
+    "সিসিলি",
+    "সিয়েরা লিওন",
+    "সিঙ্গাপুর",
+    "স্লোভাকিয়া",
+    "ভিয়েতনাম",
+    "স্লোভানিয়া",
+    "সোমালিয়া",
+    "দক্ষিণ আফ্রিকা",
+    "জিম্বাবোয়ে",
+    "স্পেন",
+    "দক্ষিণ সুদান",
+    "সুদান",
+    "পশ্চিম সাহারা",
+    "সুরিনাম",
+    "স্বালবার্ড ও জান মেয়েন",
+    "ইসওয়াতিনি",
+    "সুইডেন",
+    "সুইজারল্যান্ড",
+    "সিরিয়া",
+    "তাজিকিস্তান",
+    "থাইল্যান্ড",
+    "টোগো",
+    "টোকেলাউ",
+    "টোঙ্গা",
+    "ত্রিনিনাদ ও টোব্যাগো",
+    "সংযুক্ত আরব আমিরাত",
+    "তিউনিসিয়া",
+    "তুরস্ক",
+    "তুর্কমেনিস্তান",
+    "তুর্কস ও কাইকোস দ্বীপপুঞ্জ",
+    "টুভালু",
+    "উগান্ডা",
+    "ইউক্রেন",
+    "উত্তর ম্যাসেডোনিয়া",
+    "মিশর",
+    "যুক্তরাজ্য",
+    "গার্নসি",
+    "জার্সি",
+    "আইল অফ ম্যান",
+    "তাঞ্জানিয়া",
+    "মার্কিন যুক্তরাষ্ট্র",
+    "মার্কিন যুক্তরাষ্ট্রীয় ভার্জিন দ্বীপপুঞ্জ",
+    "বুরকিনা ফাসো",
+    "উরুগুয়ে",
+    "উজবেকিস্তান",
+    "ভেনেজুয়েলা",
+    "ওয়ালিস ও ফুটুনা",
+    "সামোয়া",
+    "ইয়েমেন",
+    "জাম্বিয়া",
+];
+
+static CA: [&str; 249] = [
+    "Afganistan",
+    "Albània",
+    "Antàrtida",
+    "Algèria",
+    "Samoa Americana",
+    "Andorra",
+    "Angola",
+    "Antigua i Barbuda",
+    "Azerbaidjan",
+    "Argentina",
+    "Austràlia",
+    "Àustria",
+    "Bahames",
+    "Bahrain",
+    "Bangladesh",
+    "Armènia",
+    "Barbados",
+    "Bèlgica",
+    "Bermudes",
+    "Bhutan",
+    "Bolívia",
+    "Bòsnia i Hercegovina",
+    "Botswana",
+    "Illa Bouvet",
+    "Brasil",
+    "Belize",
+    "Territori Britànic de l’Oceà Índic",
+    "Illes Salomó",
+    "Illes Verges Britàniques",
+    "Brunei",
+    "Bulgària",
+    "Myanmar (Birmània)",
+    "Burundi",
+    "Belarús",
+    "Cambodja",
+    "Camerun",
+    "Canadà",
+    "Cap Verd",
+    "Illes Caiman",
+    "República Centreafricana",
+    "Sri Lanka",
+    "Txad",
+    "Xile",
+    "Xina",
+    "Taiwan",
+    "Illa Christmas",
+    "Illes Cocos (Keeling)",
+    "Colòmbia",
+    "Comores",
+    "Mayotte",
+    "Congo - Brazzaville",
+    "Congo - Kinshasa",
+    "Illes Cook",
+    "Costa Rica",
+    "Croàcia",
+    "Cuba",
+    "Xipre",
+    "Txèquia",
+    "Benín",
+    "Dinamarca",
+    "Dominica",
+    "República Dominicana",
+    "Equador",
+    "El Salvador",
+    "Guinea Equatorial",
+    "Etiòpia",
+    "Eritrea",
+    "Estònia",
+    "Illes Fèroe",
+    "Illes Falkland",
+    "Illes Geòrgia del Sud i Sandwich del Sud",
+    "Fiji",
+    "Finlàndia",
+    "Illes Åland",
+    "França",
+    "Guaiana Francesa",
+    "Polinèsia Francesa",
+    "Terres Australs Antàrtiques Franceses",
+    "Djibouti",
+    "Gabon",
+    "Geòrgia",
+    "Gàmbia",
+    "Territoris palestins",
+    "Alemanya",
+    "Ghana",
+    "Gibraltar",
+    "Kiribati",
+    "Grècia",
+    "Groenlàndia",
+    "Grenada",
+    "Guadalupe",
+    "Guam",
+    "Guatemala",
+    "Guinea",
+    "Guyana",
+    "Haití",
+    "Illes Heard i McDonald",
+    "Ciutat del Vaticà",
+    "Hondures",
+    "Hong Kong (RAE Xina)",
+    "Hongria",
+    "Islàndia",
+    "Índia",
+    "Indonèsia",
+    "Iran",
+    "Iraq",
+    "Irlanda",
+    "Israel",
+    "Itàlia",
+    "Côte d’Ivoire",
+    "Jamaica",
+    "Japó",
+    "Kazakhstan",
+    "Jordània",
+    "Kenya",
+    "Corea del Nord",
+    "Corea del Sud",
+    "Kuwait",
+    "Kirguizstan",
+    "Lao",
+    "Líban",
+    "Lesotho",
+    "Letònia",
+    "Libèria",
+    "Líbia",
+    "Liechtenstein",
+    "Lituània",
+    "Luxemburg",
+    "Macau (RAE Xina)",
+    "Madagascar",
+    "Malawi",
+    "Malàisia",
+    "Maldives",
+    "Mali",
+    "Malta",
+    "Martinica",
+    "Mauritània",
+    "Maurici",
+    "Mèxic",
+    "Mònaco",
+    "Mongòlia",
+    "Moldàvia",
+    "Montenegro",
+    "Montserrat",
+    "Marroc",
+    "Moçambic",
+    "Oman",
+    "Namíbia",
+    "Nauru",
+    "Nepal",
+    "Països Baixos",
+    "Curaçao",
+    "Aruba",
+    "Sint Maarten",
+    "Carib Neerlandès",
+    "Nova Caledònia",
+    "Vanuatu",
+    "Nova Zelanda",
+    "Nicaragua",
+    "Níger",
+    "Nigèria",
+    "Niue",
+    "Illa Norfolk",
+    "Noruega",
+    "Illes Marianes del Nord",
+    "Illes Menors Allunyades dels Estats Units",
+    "Micronèsia",
+    "Illes Marshall",
+    "Palau",
+    "Pakistan",
+    "Panamà",
+    "Papua Nova Guinea",
+    "Paraguai",
+    "Perú",
+    "Filipines",
+    "Illes Pitcairn",
+    "Polònia",
+    "Portugal",
+    "Guinea Bissau",
+    "Timor-Leste",
+    "Puerto Rico",
+    "Qatar",
+    "Illa de la Reunió",
+    "Romania",
+    "Rússia",
+    "Ruanda",
+    "Saint-Barthélemy",
+    "Santa Helena",
+    "Saint Kitts i Nevis",
+    "Anguilla",
+    "Saint Lucia",
+    "Saint Martin",
+    "Saint-Pierre-et-Miquelon",
+    "Saint Vincent i les Grenadines",
+    "San Marino",
+    "São Tomé i Príncipe",
+    "Aràbia Saudí",
+    "Senegal",
+    "Sèrbia",
+    "Seychelles",
+    "Sierra Leone",
+    "Singapur",
+    "Eslovàquia",
+    "Vietnam",
+    "Eslovènia",
+    "Somàlia",
+    "Sud-àfrica",
+    "Zimbàbue",
+    "Espanya",
+    "Sudan del Sud",
+    "Sudan",
+    "Sàhara Occidental",
+    "Surinam",
+    "Svalbard i Jan Mayen",
+    "Eswatini",
+    "Suècia",
+    "Suïssa",
+    "Síria",
+    "Tadjikistan",
+    "Tailàndia",
+    "Togo",
+    "Tokelau",
+    "Tonga",
+    "Trinidad i Tobago",
+    "Emirats Àrabs Units",
+    "Tunísia",
+    "Turquia",
+    "Turkmenistan",
+    "Illes Turks i Caicos",
+    "Tuvalu",
+    "Uganda",
+    "Ucraïna",
+    "Macedònia del Nord",
+    "Egipte",
+    "Regne Unit",
+    "Guernsey",
+    "Jersey",
+    "Illa de Man",
+    "Tanzània",
+    "Estats Units",
+    "Illes Verges dels Estats Units",
+    "Burkina Faso",
+    "Uruguai",
+    "Uzbekistan",
+    "Veneçuela",
+    "Wallis i Futuna",
+    "Samoa",
+    "Iemen",
+    "Zàmbia",
+];
+
+static CS: [&str; 249] = [
+    "Afghánistán",
+    "Albánie",
+    "Antarktida",
+    "Alžírsko",
+    "Americká Samoa",
+    "Andorra",
+    "Angola",
+    "Antigua a Barbuda",
+    "Ázerbájdžán",
+    "Argentina",
+    "Austrálie",
+    "Rakousko",
+    "Bahamy",
+    "Bahrajn",
+    "Bangladéš",
+    "Arménie",
+    "Barbados",
+    "Belgie",
+    "Bermudy",
+    "Bhútán",
+    "Bolívie",
+    "Bosna a Hercegovina",
+    "Botswana",
+    "Bouvetův ostrov",
+    "Brazílie",
+    "Belize",
+    "Britské indickooceánské území",
+    "Šalamounovy ostrovy",
+    "Britské Panenské ostrovy",
+    "Brunej",
+    "Bulharsko",
+    "Myanmar (Barma)",
+    "Burundi",
+    "Bělorusko",
+    "Kambodža",
+    "Kamerun",
+    "Kanada",
+    "Kapverdy",
+    "Kajmanské ostrovy",
+    "Středoafrická republika",
+    "Srí Lanka",
+    "Čad",
+    "Chile",
+    "Čína",
+    "Tchaj-wan",
+    "Vánoční ostrov",
+    "Kokosové ostrovy",
+    "Kolumbie",
+    "Komory",
+    "Mayotte",
+    "Kongo – Brazzaville",
+    "Kongo – Kinshasa",
+    "Cookovy ostrovy",
+    "Kostarika",
+    "Chorvatsko",
+    "Kuba",
+    "Kypr",
+    "Česko",
+    "Benin",
+    "Dánsko",
+    "Dominika",
+    "Dominikánská republika",
+    "Ekvádor",
+    "Salvador",
+    "Rovníková Guinea",
+    "Etiopie",
+    "Eritrea",
+    "Estonsko",
+    "Faerské ostrovy",
+    "Falklandské ostrovy",
+    "Jižní Georgie a Jižní Sandwichovy ostrovy",
+    "Fidži",
+    "Finsko",
+    "Ålandy",
+    "Francie",
+    "Francouzská Guyana",
+    "Francouzská Polynésie",
+    "Francouzská jižní území",
+    "Džibutsko",
+    "Gabon",
+    "Gruzie",
+    "Gambie",
+    "Palestinská území",
+    "Německo",
+    "Ghana",
+    "Gibraltar",
+    "Kiribati",
+    "Řecko",
+    "Grónsko",
+    "Grenada",
+    "Guadeloupe",
+    "Guam",
+    "Guatemala",
+    "Guinea",
+    "Guyana",
+    "Haiti",
+    "Heardův ostrov a McDonaldovy ostrovy",
+    "Vatikán",
+    "Honduras",
+    "Hongkong – ZAO Číny",
+    "Maďarsko",
+    "Island",
+    "Indie",
+    "Indonésie",
+    "Írán",
+    "Irák",
+    "Irsko",
+    "Izrael",
+    "Itálie",
+    "Pobřeží slonoviny",
+    "Jamajka",
+    "Japonsko",
+    "Kazachstán",
+    "Jordánsko",
+    "Keňa",
+    "Severní Korea",
+    "Jižní Korea",
+    "Kuvajt",
+    "Kyrgyzstán",
+    "Laos",
+    "Libanon",
+    "Lesotho",
+    "Lotyšsko",
+    "Libérie",
+    "Libye",
+    "Lichtenštejnsko",
+    "Litva",
+    "Lucembursko",
+    "Macao – ZAO Číny",
+    "Madagaskar",
+    "Malawi",
+    "Malajsie",
+    "Maledivy",
+    "Mali",
+    "Malta",
+    "Martinik",
+    "Mauritánie",
+    "Mauricius",
+    "Mexiko",
+    "Monako",
+    "Mongolsko",
+    "Moldavsko",
+    "Černá Hora",
+    "Montserrat",
+    "Maroko",
+    "Mosambik",
+    "Omán",
+    "Namibie",
+    "Nauru",
+    "Nepál",
+    "Nizozemsko",
+    "Curaçao",
+    "Aruba",
+    "Svatý Martin (Nizozemsko)",
+    "Karibské Nizozemsko",
+    "Nová Kaledonie",
+    "Vanuatu",
+    "Nový Zéland",
+    "Nikaragua",
+    "Niger",
+    "Nigérie",
+    "Niue",
+    "Norfolk",
+    "Norsko",
+    "Severní Mariany",
+    "Menší odlehlé ostrovy USA",
+    "Mikronésie",
+    "Marshallovy ostrovy",
+    "Palau",
+    "Pákistán",
+    "Panama",
+    "Papua-Nová Guinea",
+    "Paraguay",
+    "Peru",
+    "Filipíny",
+    "Pitcairnovy ostrovy",
+    "Polsko",
+    "Portugalsko",
+    "Guinea-Bissau",
+    "Východní Timor",
+    "Portoriko",
+    "Katar",
+    "Réunion",
+    "Rumunsko",
+    "Rusko",
+    "Rwanda",
+    "Svatý Bartoloměj",
+    "Svatá Helena",
+    "Svatý Kryštof a Nevis",
+    "Anguilla",
+    "Svatá Lucie",
+    "Svatý Martin (Francie)",
+    "Saint-Pierre a Miquelon",
+    "Svatý Vincenc a Grenadiny",
+    "San Marino",
+    "Svatý Tomáš a Princův ostrov",
+    "Saúdská Arábie",
+    "Senegal",
+    "Srbsko",
+    "Seychely",
+    "Sierra Leone",
+    "Singapur",
+    "Slovensko",
+    "Vietnam",
+    "Slovinsko",
+    "Somálsko",
+    "Jihoafrická republika",
+    "Zimbabwe",
+    "Španělsko",
+    "Jižní Súdán",
+    "Súdán",
+    "Západní Sahara",
+    "Surinam",
+    "Špicberky a Jan Mayen",
+    "Eswatini",
+    "Švédsko",
+    "Švýcarsko",
+    "Sýrie",
+    "Tádžikistán",
+    "Thajsko",
+    "Togo",
+    "Tokelau",
+    "Tonga",
+    "Trinidad a Tobago",
+    "Spojené arabské emiráty",
+    "Tunisko",
+    "Turecko",
+    "Turkmenistán",
+    "Turks a Caicos",
+    "Tuvalu",
+    "Uganda",
+    "Ukrajina",
+    "Severní Makedonie",
+    "Egypt",
+    "Spojené království",
+    "Guernsey",
+    "Jersey",
+    "Ostrov Man",
+    "Tanzanie",
+    "Spojené státy",
+    "Americké Panenské ostrovy",
+    "Burkina Faso",
+    "Uruguay",
+    "Uzbekistán",
+    "Venezuela",
+    "Wallis a Futuna",
+    "Samoa",
+    "Jemen",
+    "Zambie",
+];
+
+static DA: [&str; 249] = [
+    "Afghanistan",
+    "Albanien",
+    "Antarktis",
+    "Algeriet",
+    "Amerikansk Samoa",
+    "Andorra",
+    "Angola",
+    "Antigua og Barbuda",
+    "Aserbajdsjan",
+    "Argentina",
+    "Australien",
+    "Østrig",
+    "Bahamas",
+    "Bahrain",
+    "Bangladesh",
+    "Armenien",
+    "Barbados",
+    "Belgien",
+    "Bermuda",
+    "Bhutan",
+    "Bolivia",
+    "Bosnien-Hercegovina",
+    "Botswana",
+    "Bouvetøen",
+    "Brasilien",
+    "Belize",
+    "Det Britiske Territorium i Det Indiske Ocean",
+    "Salomonøerne",
+    "De Britiske Jomfruøer",
+    "Brunei",
+    "Bulgarien",
+    "Myanmar (Burma)",
+    "Burundi",
+    "Belarus",
+    "Cambodja",
+    "Cameroun",
+    "Canada",
+    "Kap Verde",
+    "Caymanøerne",
+    "Den Centralafrikanske Republik",
+    "Sri Lanka",
+    "Tchad",
+    "Chile",
+    "Kina",
+    "Taiwan",
+    "Juleøen",
+    "Cocosøerne",
+    "Colombia",
+    "Comorerne",
+    "Mayotte",
+    "Congo-Brazzaville",
+    "Congo-Kinshasa",
+    "Cookøerne",
+    "Costa Rica",
+    "Kroatien",
+    "Cuba",
+    "Cypern",
+    "Tjekkiet",
+    "Benin",
+    "Danmark",
+    "Dominica",
+    "Den Dominikanske Republik",
+    "Ecuador",
+    "El Salvador",
+    "Ækvatorialguinea",
+    "Etiopien",
+    "Eritrea",
+    "Estland",
+    "Færøerne",
+    "Falklandsøerne",
+    "South Georgia og De Sydlige Sandwichøer",
+    "Fiji",
+    "Finland",
+    "Åland",
+    "Frankrig",
+    "Fransk Guyana",
+    "Fransk Polynesien",
+    "De Franske Besiddelser i Det Sydlige Indiske Ocean og Antarktis",
+    "Djibouti",
+    "Gabon",
+    "Georgien",
+    "Gambia",
+    "De palæstinensiske områder",
+    "Tyskland",
+    "Ghana",
+    "Gibraltar",
+    "Kiribati",
+    "Grækenland",
+    "Grønland",
+    "Grenada",
+    "Guadeloupe",
+    "Guam",
+    "Guatemala",
+    "Guinea",
+    "Guyana",
+    "Haiti",
+    "Heard Island og McDonald Islands",
+    "Vatikanstaten",
+    "Honduras",
+    "SAR Hongkong",
+    "Ungarn",
+    "Island",
+    "Indien",
+    "Indonesien",
+    "Iran",
+    "Irak",
+    "Irland",
+    "Israel",
+    "Italien",
+    "Elfenbenskysten",
+    "Jamaica",
+    "Japan",
+    "Kasakhstan",
+    "Jordan",
+    "Kenya",
+    "Nordkorea",
+    "Sydkorea",
+    "Kuwait",
+    "Kirgisistan",
+    "Laos",
+    "Libanon",
+    "Lesotho",
+    "Letland",
+    "Liberia",
+    "Libyen",
+    "Liechtenstein",
+    "Litauen",
+    "Luxembourg",
+    "SAR Macao",
+    "Madagaskar",
+    "Malawi",
+    "Malaysia",
+    "Maldiverne",
+    "Mali",
+    "Malta",
+    "Martinique",
+    "Mauretanien",
+    "Mauritius",
+    "Mexico",
+    "Monaco",
+    "Mongoliet",
+    "Moldova",
+    "Montenegro",
+    "Montserrat",
+    "Marokko",
+    "Mozambique",
+    "Oman",
+    "Namibia",
+    "Nauru",
+    "Nepal",
+    "Nederlandene",
+    "Curaçao",
+    "Aruba",
+    "Sint Maarten",
+    "De tidligere Nederlandske Antiller",
+    "Ny Kaledonien",
+    "Vanuatu",
+    "New Zealand",
+    "Nicaragua",
+    "Niger",
+    "Nigeria",
+    "Niue",
+    "Norfolk Island",
+    "Norge",
+    "Nordmarianerne",
+    "Amerikanske oversøiske øer",
+    "Mikronesien",
+    "Marshalløerne",
+    "Palau",
+    "Pakistan",
+    "Panama",
+    "Papua Ny Guinea",
+    "Paraguay",
+    "Peru",
+    "Filippinerne",
+    "Pitcairn",
+    "Polen",
+    "Portugal",
+    "Guinea-Bissau",
+    "Timor-Leste",
+    "Puerto Rico",
+    "Qatar",
+    "Réunion",
+    "Rumænien",
+    "Rusland",
+    "Rwanda",
+    "Saint Barthélemy",
+    "St. Helena",
+    "Saint Kitts og Nevis",
+    "Anguilla",
+    "Saint Lucia",
+    "Saint Martin",
+    "Saint Pierre og Miquelon",
+    "Saint Vincent og Grenadinerne",
+    "San Marino",
+    "São Tomé og Príncipe",
+    "Saudi-Arabien",
+    "Senegal",
+    "Serbien",
+    "Seychellerne",
+    "Sierra Leone",
+    "Singapore",
+    "Slovakiet",
+    "Vietnam",
+    "Slovenien",
+    "Somalia",
+    "Sydafrika",
+    "Zimbabwe",
+    "Spanien",
+    "Sydsudan",
+    "Sudan",
+    "Vestsahara",
+    "Surinam",
+    "Svalbard og Jan Mayen",
+    "Eswatini",
+    "Sverige",
+    "Schweiz",
+    "Syrien",
+    "Tadsjikistan",
+    "Thailand",
+    "Togo",
+    "Tokelau",
+    "Tonga",
+    "Trinidad og Tobago",
+    "De Forenede Arabiske Emirater",
+    "Tunesien",
+    "Tyrkiet",
+    "Turkmenistan",
+    "Turks- og Caicosøerne",
+    "Tuvalu",
+    "Uganda",
+    "Ukraine",
+    "Nordmakedonien",
+    "Egypten",
+    "Storbritannien",
+    "Guernsey",
+    "Jersey",
+    "Isle of Man",
+    "Tanzania",
+    "USA",
+    "De Amerikanske Jomfruøer",
+    "Burkina Faso",
+    "Uruguay",
+    "Usbekistan",
+    "Venezuela",
+    "Wallis og Futuna",
+    "Samoa",
+    "Yemen",
+    "Zambia",
+];
+
+static DE: [&str; 249] = [
+    "Afghanistan",
+    "Albanien",
+    "Antarktis",
+    "Algerien",
+    "Amerikanisch-Samoa",
+    "Andorra",
+    "Angola",
+    "Antigua und Barbuda",
+    "Aserbaidschan",
+    "Argentinien",
+    "Australien",
+    "Österreich",
+    "Bahamas",
+    "Bahrain",
+    "Bangladesch",
+    "Armenien",
+    "Barbados",
+    "Belgien",
+    "Bermuda",
+    "Bhutan",
+    "Bolivien",
+    "Bosnien und Herzegowina",
+    "Botsuana",
+    "Bouvetinsel",
+    "Brasilien",
+    "Belize",
+    "Britisches Territorium im Indischen Ozean",
+    "Salomonen",
+    "Britische Jungferninseln",
+    "Brunei Darussalam",
+    "Bulgarien",
+    "Myanmar",
+    "Burundi",
+    "Belarus",
+    "Kambodscha",
+    "Kamerun",
+    "Kanada",
+    "Cabo Verde",
+    "Kaimaninseln",
+    "Zentralafrikanische Republik",
+    "Sri Lanka",
+    "Tschad",
+    "Chile",
+    "China",
+    "Taiwan",
+    "Weihnachtsinsel",
+    "Kokosinseln",
+    "Kolumbien",
+    "Komoren",
+    "Mayotte",
+    "Kongo-Brazzaville",
+    "Kongo-Kinshasa",
+    "Cookinseln",
+    "Costa Rica",
+    "Kroatien",
+    "Kuba",
+    "Zypern",
+    "Tschechien",
+    "Benin",
+    "Dänemark",
+    "Dominica",
+    "Dominikanische Republik",
+    "Ecuador",
+    "El Salvador",
+    "Äquatorialguinea",
+    "Äthiopien",
+    "Eritrea",
+    "Estland",
+    "Färöer",
+    "Falklandinseln",
+    "Südgeorgien und die Südlichen Sandwichinseln",
+    "Fidschi",
+    "Finnland",
+    "Ålandinseln",
+    "Frankreich",
+    "Französisch-Guayana",
+    "Französisch-Polynesien",
+    "Französische Süd- und Antarktisgebiete",
+    "Dschibuti",
+    "Gabun",
+    "Georgien",
+    "Gambia",
+    "Palästinensische Autonomiegebiete",
+    "Deutschland",
+    "Ghana",
+    "Gibraltar",
+    "Kiribati",
+    "Griechenland",
+    "Grönland",
+    "Grenada",
+    "Guadeloupe",
+    "Guam",
+    "Guatemala",
+    "Guinea",
+    "Guyana",
+    "Haiti",
+    "Heard und McDonaldinseln",
+    "Vatikanstadt",
+    "Honduras",
+    "Sonderverwaltungsregion Hongkong",
+    "Ungarn",
+    "Island",
+    "Indien",
+    "Indonesien",
+    "Iran",
+    "Irak",
+    "Irland",
+    "Israel",
+    "Italien",
+    "Côte d’Ivoire",
+    "Jamaika",
+    "Japan",
+    "Kasachstan",
+    "Jordanien",
+    "Kenia",
+    "Nordkorea",
+    "Südkorea",
+    "Kuwait",
+    "Kirgisistan",
+    "Laos",
+    "Libanon",
+    "Lesotho",
+    "Lettland",
+    "Liberia",
+    "Libyen",
+    "Liechtenstein",
+    "Litauen",
+    "Luxemburg",
+    "Sonderverwaltungsregion Macau",
+    "Madagaskar",
+    "Malawi",
+    "Malaysia",
+    "Malediven",
+    "Mali",
+    "Malta",
+    "Martinique",
+    "Mauretanien",
+    "Mauritius",
+    "Mexiko",
+    "Monaco",
+    "Mongolei",
+    "Republik Moldau",
+    "Montenegro",
+    "Montserrat",
+    "Marokko",
+    "Mosambik",
+    "Oman",
+    "Namibia",
+    "Nauru",
+    "Nepal",
+    "Niederlande",
+    "Curaçao",
+    "Aruba",
+    "Sint Maarten",
+    "Karibische Niederlande",
+    "Neukaledonien",
+    "Vanuatu",
+    "Neuseeland",
+    "Nicaragua",
+    "Niger",
+    "Nigeria",
+    "Niue",
+    "Norfolkinsel",
+    "Norwegen",
+    "Nördliche Marianen",
+    "Amerikanische Überseeinseln",
+    "Mikronesien",
+    "Marshallinseln",
+    "Palau",
+    "Pakistan",
+    "Panama",
+    "Papua-Neuguinea",
+    "Paraguay",
+    "Peru",
+    "Philippinen",
+    "Pitcairninseln",
+    "Polen",
+    "Portugal",
+    "Guinea-Bissau",
+    "Timor-Leste",
+    "Puerto Rico",
+    "Katar",
+    "Réunion",
+    "Rumänien",
+    "Russland",
+    "Ruanda",
+    "St. Barthélemy",
+    "St. Helena",
+    "St. Kitts und Nevis",
+    "Anguilla",
+    "St. Lucia",
+    "St. Martin",
+    "St. Pierre und Miquelon",
+    "St. Vincent und die Grenadinen",
+    "San Marino",
+    "São Tomé und Príncipe",
+    "Saudi-Arabien",
+    "Senegal",
+    "Serbien",
+    "Seychellen",
+    "Sierra Leone",
+    "Singapur",
+    "Slowakei",
+    "Vietnam",
+    "Slowenien",
+    "Somalia",
+    "Südafrika",
+    "Simbabwe",
+    "Spanien",
+    "Südsudan",
+    "Sudan",
+    "Westsahara",
+    "Suriname",
+    "Spitzbergen und Jan Mayen",
+    "Eswatini",
+    "Schweden",
+    "Schweiz",
+    "Syrien",
+    "Tadschikistan",
+    "Thailand",
+    "Togo",
+    "Tokelau",
+    "Tonga",
+    "Trinidad und Tobago",
+    "Vereinigte Arabische Emirate",
+    "Tunesien",
+    "Türkei",
+    "Turkmenistan",
+    "Turks- und Caicosinseln",
+    "Tuvalu",
+    "Uganda",
+    "Ukraine",
+    "Nordmazedonien",
+    "Ägypten",
+    "Vereinigtes Königreich",
+    "Guernsey",
+    "Jersey",
+    "Isle of Man",
+    "Tansania",
+    "Vereinigte Staaten",
+    "Amerikanische Jungferninseln",
+    "Burkina Faso",
+    "Uruguay",
+    "Usbekistan",
+    "Venezuela",
+    "Wallis und Futuna",
+    "Samoa",
+    "Jemen",
+    "Sambia",
+];
+
+static DE_AT: &[(Country, &str)] = &[
+    (Country::SJ, "Svalbard und Jan Mayen"),
+];
+
+static DE_CH: &[(Country, &str)] = &[
+    (Country::BW, "Botswana"),
+    (Country::SB, "Salomon-Inseln"),
+    (Country::BN, "Brunei"),
+    (Country::CV, "Kapverden"),
+    (Country::TL, "Osttimor"),
+    (Country::ZW, "Zimbabwe"),
+];
+
+static EL: [&str; 249] = [
+    "Αφγανιστάν",
+    "Αλβανία",
+    "Ανταρκτική",
+    "Αλγερία",
+    "Αμερικανική Σαμόα",
+    "Ανδόρα",
+    "Αγκόλα",
+    "Αντίγκουα και Μπαρμπούντα",
+    "Αζερμπαϊτζάν",
+    "Αργεντινή",
+    "Αυστραλία",
+    "Αυστρία",
+    "Μπαχάμες",
+    "Μπαχρέιν",
+    "Μπανγκλαντές",
+    "Αρμενία",
+    "Μπαρμπέιντος",
+    "Βέλγιο",
+    "Βερμούδες",
+    "Μπουτάν",
+    "Βολιβία",
+    "Βοσνία - Ερζεγοβίνη",
+    "Μποτσουάνα",
+    "Νήσος Μπουβέ",
+    "Βραζιλία",
+    "Μπελίζ",
+    "Βρετανικά Εδάφη Ινδικού Ωκεανού",
+    "Νήσοι Σολομώντος",
+    "Βρετανικές Παρθένες Νήσοι",
+    "Μπρουνέι",
+    "Βουλγαρία",
+    "Μιανμάρ (Βιρμανία)",
+    "Μπουρούντι",
+    "Λευκορωσία",
+    "Καμπότζη",
+    "Καμερούν",
+    "Καναδάς",
+    "Πράσινο Ακρωτήριο",
+    "Νήσοι Κέιμαν",
+    "Κεντροαφρικανική Δημοκρατία",
+    "Σρι Λάνκα",
+    "Τσαντ",
+    "Χιλή",
+    "Κίνα",
+    "Ταϊβάν",
+    "Νήσος των Χριστουγέννων",
+    "Νήσοι Κόκος (Κίλινγκ)",
+    "Κολομβία",
+    "Κομόρες",
+    "Μαγιότ",
+    "Κονγκό - Μπραζαβίλ",
+    "Κονγκό - Κινσάσα",
+    "Νήσοι Κουκ",
+    "Κόστα Ρίκα",
+    "Κροατία",
+    "Κούβα",
+    "Κύπρος",
+    "Τσεχία",
+    "Μπενίν",
+    "Δανία",
+    "Ντομίνικα",
+    "Δομινικανή Δημοκρατία",
+    "Ισημερινός",
+    "Ελ Σαλβαδόρ",
+    "Ισημερινή Γουινέα",
+    "Αιθιοπία",
+    "Ερυθραία",
+    "Εσθονία",
+    "Νήσοι Φερόες",
+    "Νήσοι Φόκλαντ",
+    "Νήσοι Νότια Γεωργία και Νότιες Σάντουιτς",
+    "Φίτζι",
+    "Φινλανδία",
+    "Νήσοι Όλαντ",
+    "Γαλλία",
+    "Γαλλική Γουιάνα",
+    "Γαλλική Πολυνησία",
+    "Γαλλικά Νότια Εδάφη",
+    "Τζιμπουτί",
+    "Γκαμπόν",
+    "Γεωργία",
+    "Γκάμπια",
+    "Παλαιστινιακά Εδάφη",
+    "Γερμανία",
+    "Γκάνα",
+    "Γιβραλτάρ",
+    "Κιριμπάτι",
+    "Ελλάδα",
+    "Γροιλανδία",
+    "Γρενάδα",
+    "Γουαδελούπη",
+    "Γκουάμ",
+    "Γουατεμάλα",
+    "Γουινέα",
+    "Γουιάνα",
+    "Αϊτή",
+    "Νήσοι Χερντ και Μακντόναλντ",
+    "Βατικανό",
+    "Ονδούρα",
+    "Χονγκ Κονγκ ΕΔΠ Κίνας",
+    "Ουγγαρία",
+    "Ισλανδία",
+    "Ινδία",
+    "Ινδονησία",
+    "Ιράν",
+    "Ιράκ",
+    "Ιρλανδία",
+    "Ισραήλ",
+    "Ιταλία",
+    "Ακτή Ελεφαντοστού",
+    "Τζαμάικα",
+    "Ιαπωνία",
+    "Καζακστάν",
+    "Ιορδανία",
+    "Κένυα",
+    "Βόρεια Κορέα",
+    "Νότια Κορέα",
+    "Κουβέιτ",
+    "Κιργιστάν",
+    "Λάος",
+    "Λίβανος",
+    "Λεσότο",
+    "Λετονία",
+    "Λιβερία",
+    "Λιβύη",
+    "Λιχτενστάιν",
+    "Λιθουανία",
+    "Λουξεμβούργο",
+    "Μακάο ΕΔΠ Κίνας",
+    "Μαδαγασκάρη",
+    "Μαλάουι",
+    "Μαλαισία",
+    "Μαλδίβες",
+    "Μάλι",
+    "Μάλτα",
+    "Μαρτινίκα",
+    "Μαυριτανία",
+    "Μαυρίκιος",
+    "Μεξικό",
+    "Μονακό",
+    "Μογγολία",
+    "Μολδαβία",
+    "Μαυροβούνιο",
+    "Μονσεράτ",
+    "Μαρόκο",
+    "Μοζαμβίκη",
+    "Ομάν",
+    "Ναμίμπια",
+    "Ναουρού",
+    "Νεπάλ",
+    "Κάτω Χώρες",
+    "Κουρασάο",
+    "Αρούμπα",
+    "Άγιος Μαρτίνος (Ολλανδικό τμήμα)",
+    "Ολλανδία Καραϊβικής",
+    "Νέα Καληδονία",
+    "Βανουάτου",
+    "Νέα Ζηλανδία",
+    "Νικαράγουα",
+    "Νίγηρας",
+    "Νιγηρία",
+    "Νιούε",
+    "Νήσος Νόρφολκ",
+    "Νορβηγία",
+    "Νήσοι Βόρειες Μαριάνες",
+    "Απομακρυσμένες Νησίδες ΗΠΑ",
+    "Μικρονησία",
+    "Νήσοι Μάρσαλ",
+    "Παλάου",
+    "Πακιστάν",
+    "Παναμάς",
+    "Παπούα Νέα Γουινέα",
+    "Παραγουάη",
+    "Περού",
+    "Φιλιππίνες",
+    "Νήσοι Πίτκερν",
+    "Πολωνία",
+    "Πορτογαλία",
+    "Γουινέα Μπισάου",
+    "Τιμόρ-Λέστε",
+    "Πουέρτο Ρίκο",
+    "Κατάρ",
+    "Ρεϊνιόν",
+    "Ρουμανία",
+    "Ρωσία",
+    "Ρουάντα",
+    "Άγιος Βαρθολομαίος",
+    "Αγία Ελένη",
+    "Σεν Κιτς και Νέβις",
+    "Ανγκουίλα",
+    "Αγία Λουκία",
+    "Άγιος Μαρτίνος (Γαλλικό τμήμα)",
+    "Σεν Πιερ και Μικελόν",
+    "Άγιος Βικέντιος και Γρεναδίνες",
+    "Άγιος Μαρίνος",
+    "Σάο Τομέ και Πρίνσιπε",
+    "Σαουδική Αραβία",
+    "Σενεγάλη",
+    "Σερβία",
+    "Σεϋχέλλες",
+    "Σιέρα Λεόνε",
+    "Σιγκαπούρη",
+    "Σλοβακία",
+    "Βιετνάμ",
+    "Σλοβενία",
+    "Σομαλία",
+    "Νότια Αφρική",
+    "Ζιμπάμπουε",
+    "Ισπανία",
+    "Νότιο Σουδάν",
+    "Σουδάν",
+    "Δυτική Σαχάρα",
+    "Σουρινάμ",
+    "Σβάλμπαρντ και Γιαν Μαγιέν",
+    "Εσουατίνι",
+    "Σουηδία",
+    "Ελβετία",
+    "Συρία",
+    "Τατζικιστάν",
+    "Ταϊλάνδη",
+    "Τόγκο",
+    "Τοκελάου",
+    "Τόνγκα",
+    "Τρινιντάντ και Τομπάγκο",
+    "Ηνωμένα Αραβικά Εμιράτα",
+    "Τυνησία",
+    "Τουρκία",
+    "Τουρκμενιστάν",
+    "Νήσοι Τερκς και Κάικος",
+    "Τουβαλού",
+    "Ουγκάντα",
+    "Ουκρανία",
+    "Βόρεια Μακεδονία",
+    "Αίγυπτος",
+    "Ηνωμένο Βασίλειο",
+    "Γκέρνζι",
+    "Τζέρζι",
+    "Νήσος του Μαν",
+    "Τανζανία",
+    "Ηνωμένες Πολιτείες",
+    "Αμερικανικές Παρθένες Νήσοι",
+    "Μπουρκίνα Φάσο",
+    "Ουρουγουάη",
+    "Ουζμπεκιστάν",
+    "Βενεζουέλα",
+    "Γουάλις και Φουτούνα",
+    "Σαμόα",
+    "Υεμένη",
+    "Ζάμπια",
+];
+
+static EN: [&str; 249] = [
+    "Afghanistan",
+    "Albania",
+    "Antarctica",
+    "Algeria",
+    "American Samoa",
+    "Andorra",
+    "Angola",
+    "Antigua & Barbuda",
+    "Azerbaijan",
+    "Argentina",
+    "Australia",
+    "Austria",
+    "Bahamas",
+    "Bahrain",
+    "Bangladesh",
+    "Armenia",
+    "Barbados",
+    "Belgium",
+    "Bermuda",
+    "Bhutan",
+    "Bolivia",
+    "Bosnia & Herzegovina",
+    "Botswana",
+    "Bouvet Island",
+    "Brazil",
+    "Belize",
+    "British Indian Ocean Territory",
+    "Solomon Islands",
+    "British Virgin Islands",
+    "Brunei",
+    "Bulgaria",
+    "Myanmar (Burma)",
+    "Burundi",
+    "Belarus",
+    "Cambodia",
+    "Cameroon",
+    "Canada",
+    "Cape Verde",
+    "Cayman Islands",
+    "Central African Republic",
+    "Sri Lanka",
+    "Chad",
+    "Chile",
+    "China",
+    "Taiwan",
+    "Christmas Island",
+    "Cocos (Keeling) Islands",
+    "Colombia",
+    "Comoros",
+    "Mayotte",
+    "Congo - Brazzaville",
+    "Congo - Kinshasa",
+    "Cook Islands",
+    "Costa Rica",
+    "Croatia",
+    "Cuba",
+    "Cyprus",
+    "Czechia",
+    "Benin",
+    "Denmark",
+    "Dominica",
+    "Dominican Republic",
+    "Ecuador",
+    "El Salvador",
+    "Equatorial Guinea",
+    "Ethiopia",
+    "Eritrea",
+    "Estonia",
+    "Faroe Islands",
+    "Falkland Islands",
+    "South Georgia & South Sandwich Islands",
+    "Fiji",
+    "Finland",
+    "Åland Islands",
+    "France",
+    "French Guiana",
+    "French Polynesia",
+    "French Southern Territories",
+    "Djibouti",
+    "Gabon",
+    "Georgia",
+    "Gambia",
+    "Palestinian Territories",
+    "Germany",
+    "Ghana",
+    "Gibraltar",
+    "Kiribati",
+    "Greece",
+    "Greenland",
+    "Grenada",
+    "Guadeloupe",
+    "Guam",
+    "Guatemala",
+    "Guinea",
+    "Guyana",
+    "Haiti",
+    "Heard & McDonald Islands",
+    "Vatican City",
+    "Honduras",
+    "Hong Kong SAR China",
+    "Hungary",
+    "Iceland",
+    "India",
+    "Indonesia",
+    "Iran",
+    "Iraq",
+    "Ireland",
+    "Israel",
+    "Italy",
+    "Côte d’Ivoire",
+    "Jamaica",
+    "Japan",
+    "Kazakhstan",
+    "Jordan",
+    "Kenya",
+    "North Korea",
+    "South Korea",
+    "Kuwait",
+    "Kyrgyzstan",
+    "Laos",
+    "Lebanon",
+    "Lesotho",
+    "Latvia",
+    "Liberia",
+    "Libya",
+    "Liechtenstein",
+    "Lithuania",
+    "Luxembourg",
+    "Macao SAR China",
+    "Madagascar",
+    "Malawi",
+    "Malaysia",
+    "Maldives",
+    "Mali",
+    "Malta",
+    "Martinique",
+    "Mauritania",
+    "Mauritius",
+    "Mexico",
+    "Monaco",
+    "Mongolia",
+    "Moldova",
+    "Montenegro",
+    "Montserrat",
+    "Morocco",
+    "Mozambique",
+    "Oman",
+    "Namibia",
+    "Nauru",
+    "Nepal",
+    "Netherlands",
+    "Curaçao",
+    "Aruba",
+    "Sint Maarten",
+    "Caribbean Netherlands",
+    "New Caledonia",
+    "Vanuatu",
+    "New Zealand",
+    "Nicaragua",
+    "Niger",
+    "Nigeria",
+    "Niue",
+    "Norfolk Island",
+    "Norway",
+    "Northern Mariana Islands",
+    "U.S. Outlying Islands",
+    "Micronesia",
+    "Marshall Islands",
+    "Palau",
+    "Pakistan",
+    "Panama",
+    "Papua New Guinea",
+    "Paraguay",
+    "Peru",
+    "Philippines",
+    "Pitcairn Islands",
+    "Poland",
+    "Portugal",
+    "Guinea-Bissau",
+    "Timor-Leste",
+    "Puerto Rico",
+    "Qatar",
+    "Réunion",
+    "Romania",
+    "Russia",
+    "Rwanda",
+    "St. Barthélemy",
+    "St. Helena",
+    "St. Kitts & Nevis",
+    "Anguilla",
+    "St. Lucia",
+    "St. Martin",
+    "St. Pierre & Miquelon",
+    "St. Vincent & Grenadines",
+    "San Marino",
+    "São Tomé & Príncipe",
+    "Saudi Arabia",
+    "Senegal",
+    "Serbia",
+    "Seychelles",
+    "Sierra Leone",
+    "Singapore",
+    "Slovakia",
+    "Vietnam",
+    "Slovenia",
+    "Somalia",
+    "South Africa",
+    "Zimbabwe",
+    "Spain",
+    "South Sudan",
+    "Sudan",
+    "Western Sahara",
+    "Suriname",
+    "Svalbard & Jan Mayen",
+    "Eswatini",
+    "Sweden",
+    "Switzerland",
+    "Syria",
+    "Tajikistan",
+    "Thailand",
+    "Togo",
+    "Tokelau",
+    "Tonga",
+    "Trinidad & Tobago",
+    "United Arab Emirates",
+    "Tunisia",
+    "Türkiye",
+    "Turkmenistan",
+    "Turks & Caicos Islands",
+    "Tuvalu",
+    "Uganda",
+    "Ukraine",
+    "North Macedonia",
+    "Egypt",
+    "United Kingdom",
+    "Guernsey",
+    "Jersey",
+    "Isle of Man",
+    "Tanzania",
+    "United States",
+    "U.S. Virgin Islands",
+    "Burkina Faso",
+    "Uruguay",
+    "Uzbekistan",
+    "Venezuela",
+    "Wallis & Futuna",
+    "Samoa",
+    "Yemen",
+    "Zambia",
+];
+
+static EN_001: &[(Country, &str)] = &[
+    (Country::UM, "US Outlying Islands"),
+    (Country::BL, "St Barthélemy"),
+    (Country::SH, "St Helena"),
+    (Country::KN, "St Kitts & Nevis"),
+    (Country::LC, "St Lucia"),
+    (Country::MF, "St Martin"),
+    (Country::PM, "St Pierre & Miquelon"),
+    (Country::VC, "St Vincent & the Grenadines"),
+    (Country::VI, "US Virgin Islands"),
+];
+
+static EN_AU: &[(Country, &str)] = &[
+    (Country::BL, "St. Barthélemy"),
+    (Country::KN, "St. Kitts & Nevis"),
+    (Country::LC, "St. Lucia"),
+    (Country::MF, "St. Martin"),
+    (Country::VC, "St. Vincent & Grenadines"),
+];
+
+static EN_CA: &[(Country, &str)] = &[
+    (Country::AG, "Antigua and Barbuda"),
+    (Country::BA, "Bosnia and Herzegovina"),
+    (Country::GS, "South Georgia and South Sandwich Islands"),
+    (Country::PS, "Palestinian territories"),
+    (Country::HM, "Heard and McDonald Islands"),
+    (Country::BL, "Saint-Barthélemy"),
+    (Country::SH, "Saint Helena"),
+    (Country::KN, "Saint Kitts and Nevis"),
+    (Country::LC, "Saint Lucia"),
+    (Country::MF, "Saint Martin"),
+    (Country::PM, "Saint-Pierre-et-Miquelon"),
+    (Country::VC, "Saint Vincent and the Grenadines"),
+    (Country::ST, "São Tomé and Príncipe"),
+    (Country::SJ, "Svalbard and Jan Mayen"),
+    (Country::TT, "Trinidad and Tobago"),
+    (Country::TC, "Turks and Caicos Islands"),
+    (Country::WF, "Wallis and Futuna"),
+];
+
+static ES: [&str; 249] = [
+    "Afganistán",
+    "Albania",
+    "Antártida",
+    "Argelia",
+    "Samoa Americana",
+    "Andorra",
+    "Angola",
+    "Antigua y Barbuda",
+    "Azerbaiyán",
+    "Argentina",
+    "Australia",
+    "Austria",
+    "Bahamas",
+    "Baréin",
+    "Bangladés",
+    "Armenia",
+    "Barbados",
+    "Bélgica",
+    "Bermudas",
+    "Bután",
+    "Bolivia",
+    "Bosnia y Herzegovina",
+    "Botsuana",
+    "Isla Bouvet",
+    "Brasil",
+    "Belice",
+    "Territorio Británico del Océano Índico",
+    "Islas Salomón",
+    "Islas Vírgenes Británicas",
+    "Brunéi",
+    "Bulgaria",
+    "Myanmar (Birmania)",
+    "Burundi",
+    "Bielorrusia",
+    "Camboya",
+    "Camerún",
+    "Canadá",
+    "Cabo Verde",
+    "Islas Caimán",
+    "República Centroafricana",
+    "Sri Lanka",
+    "Chad",
+    "Chile",
+    "China",
+    "Taiwán",
+    "Isla de Navidad",
+    "Islas Cocos",
+    "Colombia",
+    "Comoras",
+    "Mayotte",
+    "Congo",
+    "República Democrática del Congo",
+    "Islas Cook",
+    "Costa Rica",
+    "Croacia",
+    "Cuba",
+    "Chipre",
+    "Chequia",
+    "Benín",
+    "Dinamarca",
+    "Dominica",
+    "República Dominicana",
+    "Ecuador",
+    "El Salvador",
+    "Guinea Ecuatorial",
+    "Etiopía",
+    "Eritrea",
+    "Estonia",
+    "Islas Feroe",
+    "Islas Malvinas",
+    "Islas Georgia del Sur y Sandwich del Sur",
+    "Fiyi",
+    "Finlandia",
+    "Islas Aland",
+    "Francia",
+    "Guayana Francesa",
+    "Polinesia Francesa",
+    "Territorios Australes Franceses",
+    "Yibuti",
+    "Gabón",
+    "Georgia",
+    "Gambia",
+    "Territorios Palestinos",
+    "Alemania",
+    "Ghana",
+    "Gibraltar",
+    "Kiribati",
+    "Grecia",
+    "Groenlandia",
+    "Granada",
+    "Guadalupe",
+    "Guam",
+    "Guatemala",
+    "Guinea",
+    "Guyana",
+    "Haití",
+    "Islas Heard y McDonald",
+    "Ciudad del Vaticano",
+    "Honduras",
+    "RAE de Hong Kong (China)",
+    "Hungría",
+    "Islandia",
+    "India",
+    "Indonesia",
+    "Irán",
+    "Irak",
+    "Irlanda",
+    "Israel",
+    "Italia",
+    "Côte d’Ivoire",
+    "Jamaica",
+    "Japón",
+    "Kazajistán",
+    "Jordania",
+    "Kenia",
+    "Corea del Norte",
+    "Corea del Sur",
+    "Kuwait",
+    "Kirguistán",
+    "Laos",
+    "Líbano",
+    "Lesoto",
+    "Letonia",
+    "Liberia",
+    "Libia",
+    "Liechtenstein",
+    "Lituania",
+    "Luxemburgo",
+    "RAE de Macao (China)",
+    "Madagascar",
+    "Malaui",
+    "Malasia",
+    "Maldivas",
+    "Mali",
+    "Malta",
+    "Martinica",
+    "Mauritania",
+    "Mauricio",
+    "México",
+    "Mónaco",
+    "Mongolia",
+    "Moldavia",
+    "Montenegro",
+    "Montserrat",
+    "Marruecos",
+    "Mozambique",
+    "Omán",
+    "Namibia",
+    "Nauru",
+    "Nepal",
+    "Países Bajos",
+    "Curazao",
+    "Aruba",
+    "Sint Maarten",
+    "Caribe neerlandés",
+    "Nueva Caledonia",
+    "Vanuatu",
+    "Nueva Zelanda",
+    "Nicaragua",
+    "Níger",
+    "Nigeria",
+    "Niue",
+    "Isla Norfolk",
+    "Noruega",
+    "Islas Marianas del Norte",
+    "Islas menores alejadas de EE. UU.",
+    "Micronesia",
+    "Islas Marshall",
+    "Palaos",
+    "Pakistán",
+    "Panamá",
+    "Papúa Nueva Guinea",
+    "Paraguay",
+    "Perú",
+    "Filipinas",
+    "Islas Pitcairn",
+    "Polonia",
+    "Portugal",
+    "Guinea-Bisáu",
+    "Timor-Leste",
+    "Puerto Rico",
+    "Catar",
+    "Reunión",
+    "Rumanía",
+    "Rusia",
+    "Ruanda",
+    "San Bartolomé",
+    "Santa Elena",
+    "San Cristóbal y Nieves",
+    "Anguila",
+    "Santa Lucía",
+    "San Martín",
+    "San Pedro y Miquelón",
+    "San Vicente y las Granadinas",
+    "San Marino",
+    "Santo Tomé y Príncipe",
+    "Arabia Saudí",
+    "Senegal",
+    "Serbia",
+    "Seychelles",
+    "Sierra Leona",
+    "Singapur",
+    "Eslovaquia",
+    "Vietnam",
+    "Eslovenia",
+    "Somalia",
+    "Sudáfrica",
+    "Zimbabue",
+    "España",
+    "Sudán del Sur",
+    "Sudán",
+    "Sáhara Occidental",
+    "Surinam",
+    "Svalbard y Jan Mayen",
+    "Esuatini",
+    "Suecia",
+    "Suiza",
+    "Siria",
+    "Tayikistán",
+    "Tailandia",
+    "Togo",
+    "Tokelau",
+    "Tonga",
+    "Trinidad y Tobago",
+    "Emiratos Árabes Unidos",
+    "Túnez",
+    "Turquía",
+    "Turkmenistán",
+    "Islas Turcas y Caicos",
+    "Tuvalu",
+    "Uganda",
+    "Ucrania",
+    "Macedonia del Norte",
+    "Egipto",
+    "Reino Unido",
+    "Guernesey",
+    "Jersey",
+    "Isla de Man",
+    "Tanzania",
+    "Estados Unidos",
+    "Islas Vírgenes de EE. UU.",
+    "Burkina Faso",
+    "Uruguay",
+    "Uzbekistán",
+    "Venezuela",
+    "Wallis y Futuna",
+    "Samoa",
+    "Yemen",
+    "Zambia",
+];
+
+static ES_419: &[(Country, &str)] = &[
+    (Country::BA, "Bosnia-Herzegovina"),
+    (Country::CG, "República del Congo"),
+    (Country::GS, "Islas Georgia del Sur y Sándwich del Sur"),
+    (Country::AX, "Islas Åland"),
+    (Country::CI, "Costa de Marfil"),
+    (Country::UM, "Islas Ultramarinas de EE.UU."),
+    (Country::TL, "Timor Oriental"),
+    (Country::RO, "Rumania"),
+    (Country::SA, "Arabia Saudita"),
+];
+
+static ES_MX: &[(Country, &str)] = &[
+    (Country::BA, "Bosnia y Herzegovina"),
+    (Country::CI, "Côte d’Ivoire"),
+    (Country::UM, "Islas menores alejadas de EE. UU."),
+    (Country::SZ, "Eswatini"),
+    (Country::GG, "Guernsey"),
+];
+
+static ET: [&str; 249] = [
+    "Afganistan",
+    "Albaania",
+    "Antarktika",
+    "Alžeeria",
+    "Ameerika Samoa",
+    "Andorra",
+    "Angola",
+    "Antigua ja Barbuda",
+    "Aserbaidžaan",
+    "Argentina",
+    "Austraalia",
+    "Austria",
+    "Bahama",
+    "Bahrein",
+    "Bangladesh",
+    "Armeenia",
+    "Barbados",
+    "Belgia",
+    "Bermuda",
+    "Bhutan",
+    "Boliivia",
+    "Bosnia ja Hertsegoviina",
+    "Botswana",
+    "Bouvet’ saar",
+    "Brasiilia",
+    "Belize",
+    "Briti India ookeani ala",
+    "Saalomoni Saared",
+    "Briti Neitsisaared",
+    "Brunei",
+    "Bulgaaria",
+    "Myanmar (Birma)",
+    "Burundi",
+    "Valgevene",
+    "Kambodža",
+    "Kamerun",
+    "Kanada",
+    "Roheneemesaared",
+    "Kaimanisaared",
+    "Kesk-Aafrika Vabariik",
+    "Sri Lanka",
+    "Tšaad",
+    "Tšiili",
+    "Hiina",
+    "Taiwan",
+    "Jõulusaar",
+    "Kookossaared",
+    "Colombia",
+    "Komoorid",
+    "Mayotte",
+    "Kongo Vabariik",
+    "Kongo DV",
+    "Cooki saared",
+    "Costa Rica",
+    "Horvaatia",
+    "Kuuba",
+    "Küpros",
+    "Tšehhi",
+    "Benin",
+    "Taani",
+    "Dominica",
+    "Dominikaani Vabariik",
+    "Ecuador",
+    "El Salvador",
+    "Ekvatoriaal-Guinea",
+    "Etioopia",
+    "Eritrea",
+    "Eesti",
+    "Fääri saared",
+    "Falklandi saared",
+    "Lõuna-Georgia ja Lõuna-Sandwichi saared",
+    "Fidži",
+    "Soome",
+    "Ahvenamaa",
+    "Prantsusmaa",
+    "Prantsuse Guajaana",
+    "Prantsuse Polüneesia",
+    "Prantsuse Lõunaalad",
+    "Djibouti",
+    "Gabon",
+    "Gruusia",
+    "Gambia",
+    "Palestiina alad",
+    "Saksamaa",
+    "Ghana",
+    "Gibraltar",
+    "Kiribati",
+    "Kreeka",
+    "Gröönimaa",
+    "Grenada",
+    "Guadeloupe",
+    "Guam",
+    "Guatemala",
+    "Guinea",
+    "Guyana",
+    "Haiti",
+    "Heardi ja McDonaldi saared",
+    "Vatikan",
+    "Honduras",
+    "Hongkongi erihalduspiirkond",
+    "Ungari",
+    "Island",
+    "India",
+    "Indoneesia",
+    "Iraan",
+    "Iraak",
+    "Iirimaa",
+    "Iisrael",
+    "Itaalia",
+    "Elevandiluurannik",
+    "Jamaica",
+    "Jaapan",
+    "Kasahstan",
+    "Jordaania",
+    "Keenia",
+    "Põhja-Korea",
+    "Lõuna-Korea",
+    "Kuveit",
+    "Kõrgõzstan",
+    "Laos",
+    "Liibanon",
+    "Lesotho",
+    "Läti",
+    "Libeeria",
+    "Liibüa",
+    "Liechtenstein",
+    "Leedu",
+    "Luksemburg",
+    "Macau erihalduspiirkond",
+    "Madagaskar",
+    "Malawi",
+    "Malaisia",
+    "Maldiivid",
+    "Mali",
+    "Malta",
+    "Martinique",
+    "Mauritaania",
+    "Mauritius",
+    "Mehhiko",
+    "Monaco",
+    "Mongoolia",
+    "Moldova",
+    "Montenegro",
+    "Montserrat",
+    "Maroko",
+    "Mosambiik",
+    "Omaan",
+    "Namiibia",
+    "Nauru",
+    "Nepal",
+    "Holland",
+    "Curaçao",
+    "Aruba",
+    "Sint Maarten",
+    "Kariibi Madalmaad",
+    "Uus-Kaledoonia",
+    "Vanuatu",
+    "Uus-Meremaa",
+    "Nicaragua",
+    "Niger",
+    "Nigeeria",
+    "Niue",
+    "Norfolk",
+    "Norra",
+    "Põhja-Mariaanid",
+    "Ühendriikide hajasaared",
+    "Mikroneesia",
+    "Marshalli Saared",
+    "Belau",
+    "Pakistan",
+    "Panama",
+    "Paapua Uus-Guinea",
+    "Paraguay",
+    "Peruu",
+    "Filipiinid",
+    "Pitcairni saared",
+    "Poola",
+    "Portugal",
+    "Guinea-Bissau",
+    "Ida-Timor",
+    "Puerto Rico",
+    "Katar",
+    "Réunion",
+    "Rumeenia",
+    "Venemaa",
+    "Rwanda",
+    "Saint-Barthélemy",
+    "Saint Helena",
+    "Saint Kitts ja Nevis",
+    "Anguilla",
+    "Saint Lucia",
+    "Saint-Martin",
+    "Saint-Pierre ja Miquelon",
+    "Saint Vincent ja Grenadiinid",
+    "San Marino",
+    "São Tomé ja Príncipe",
+    "Saudi Araabia",
+    "Senegal",
+    "Serbia",
+    "Seišellid",
+    "Sierra Leone",
+    "Singapur",
+    "Slovakkia",
+    "Vietnam",
+    "Sloveenia",
+    "Somaalia",
+    "Lõuna-Aafrika Vabariik",
+    "Zimbabwe",
+    "Hispaania",
+    "Lõuna-Sudaan",
+    "Sudaan",
+    "Lääne-Sahara",
+    "Suriname",
+    "Svalbard ja Jan Mayen",
+    "Svaasimaa",
+    "Rootsi",
+    "Šveits",
+    "Süüria",
+    "Tadžikistan",
+    "Tai",
+    "Togo",
+    "Tokelau",
+    "Tonga",
+    "Trinidad ja Tobago",
+    "Araabia Ühendemiraadid",
+    "Tuneesia",
+    "Türgi",
+    "Türkmenistan",
+    "Turks ja Caicos",
+    "Tuvalu",
+    "Uganda",
+    "Ukraina",
+    "Põhja-Makedoonia",
+    "Egiptus",
+    "Ühendkuningriik",
+    "Guernsey",
+    "Jersey",
+    "Mani saar",
+    "Tansaania",
+    "Ameerika Ühendriigid",
+    "USA Neitsisaared",
+    "Burkina Faso",
+    "Uruguay",
+    "Usbekistan",
+    "Venezuela",
+    "Wallis ja Futuna",
+    "Samoa",
+    "Jeemen",
+    "Sambia",
+];
+
+static FA: [&str; 249] = [
+    "افغانستان",
+    "آلبانی",
+    "جنوبگان",
+    "الجزایر",
+    "ساموآی امریکا",
+    "آندورا",
+    "آنگولا",
+    "آنتیگوا و باربودا",
+    "جمهوری آذربایجان",
+    "آرژانتین",
+    "استرالیا",
+    "اتریش",
+    "باهاما",
+    "بحرین",
+    "بنگلادش",
+    "ارمنستان",
+    "باربادوس",
+    "بلژیک",
+    "برمودا",
+    "بوتان",
+    "بولیوی",
+    "بوسنی و هرزگوین",
+    "بوتسوانا",
+    "جزیرهٔ بووه",
+    "برزیل",
+    "بلیز",
+    "قلمرو بریتانیا در اقیانوس هند",
+    "جزایر سلیمان",
+    "جزایر ویرجین بریتانیا",
+    "برونئی",
+    "بلغارستان",
+    "میانمار (برمه)",
+    "بوروندی",
+    "بلاروس",
+    "کامبوج",
+    "کامرون",
+    "کانادا",
+    "کیپ‌ورد",
+    "جزایر کِیمن",
+    "جمهوری افریقای مرکزی",
+    "سری‌لانکا",
+    "چاد",
+    "شیلی",
+    "چین",
+    "تایوان",
+    "جزیرهٔ کریسمس",
+    "جزایر کوکوس",
+    "کلمبیا",
+    "کومور",
+    "مایوت",
+    "کنگو - برازویل",
+    "کنگو - کینشاسا",
+    "جزایر کوک",
+    "کاستاریکا",
+    "کرواسی",
+    "کوبا",
+    "قبرس",
+    "چک",
+    "بنین",
+    "دانمارک",
+    "دومینیکا",
+    "جمهوری دومینیکن",
+    "اکوادور",
+    "السالوادور",
+    "گینهٔ استوایی",
+    "اتیوپی",
+    "اریتره",
+    "استونی",
+    "جزایر فارو",
+    "جزایر فالکلند",
+    "جورجیای جنوبی و جزایر ساندویچ جنوبی",
+    "فیجی",
+    "فنلاند",
+    "جزایر آلاند",
+    "فرانسه",
+    "گویان فرانسه",
+    "پلی‌نزی فرانسه",
+    "سرزمین‌های جنوبی فرانسه",
+    "جیبوتی",
+    "گابن",
+    "گرجستان",
+    "گامبیا",
+    "سرزمین‌های فلسطینی",
+    "آلمان",
+    "غنا",
+    "جبل‌الطارق",
+    "کیریباتی",
+    "یونان",
+    "گرینلند",
+    "گرنادا",
+    "گوادلوپ",
+    "گوام",
+    "گواتمالا",
+    "گینه",
+    "گویان",
+    "هائیتی",
+    "هرد و جزایر مک‌دونالد",
+    "واتیکان",
+    "هندوراس",
+    "هنگ‌کنگ، منطقهٔ ویژهٔ اداری چین",
+    "مجارستان",
+    "ایسلند",
+    "هند",
+    "اندونزی",
+    "ایران",
+    "عراق",
+    "ایرلند",
+    "اسرائیل",
+    "ایتالیا",
+    "ساحل عاج",
+    "جامائیکا",
+    "ژاپن",
+    "قزاقستان",
+    "اردن",
+    "کنیا",
+    "کرهٔ شمالی",
+    "کرهٔ جنوبی",
+    "کویت",
+    "قرقیزستان",
+    "لائوس",
+    "لبنان",
+    "لسوتو",
+    "لتونی",
+    "لیبریا",
+    "لیبی",
+    "لیختن‌اشتاین",
+    "لیتوانی",
+    "لوکزامبورگ",
+    "ماکائو، منطقهٔ ویژهٔ اداری چین",
+    "ماداگاسکار",
+    "مالاوی",
+    "مالزی",
+    "مالدیو",
+    "مالی",
+    "مالت",
+    "مارتینیک",
+    "موریتانی",
+    "موریس",
+    "مکزیک",
+    "موناکو",
+    "مغولستان",
+    "مولداوی",
+    "مونته‌نگرو",
+    "مونت‌سرات",
+    "مراکش",
+    "موزامبیک",
+    "عمان",
+    "نامیبیا",
+    "نائورو",
+    "نپال",
+    "هلند",
+    "کوراسائو",
+    "آروبا",
+    "سنت مارتن",
+    "جزایر کارائیب هلند",
+    "کالدونیای جدید",
+    "وانواتو",
+    "نیوزیلند",
+    "نیکاراگوئه",
+    "نیجر",
+    "نیجریه",
+    "نیوئه",
+    "جزیرهٔ نورفولک",
+    "نروژ",
+    "جزایر ماریانای شمالی",
+    "جزایر دورافتادهٔ ایالات متحده",
+    "میکرونزی",
+    "جزایر مارشال",
+    "پالائو",
+    "پاکستان",
+    "پاناما",
+    "پاپوا گینهٔ نو",
+    "پاراگوئه",
+    "پرو",
+    "فیلیپین",
+    "جزایر پیت‌کرن",
+    "لهستان",
+    "پرتغال",
+    "گینهٔ بیسائو",
+    "تیمور-لسته",
+    "پورتوریکو",
+    "قطر",
+    "رئونیون",
+    "رومانی",
+    "روسیه",
+    "رواندا",
+    "سن بارتلمی",
+    "سنت هلن",
+    "سنت کیتس و نویس",
+    "آنگویلا",
+    "سنت لوسیا",
+    "سنت مارتین",
+    "سن پیر و میکلن",
+    "سنت وینسنت و گرنادین",
+    "سان‌مارینو",
+    "سائوتومه و پرینسیپ",
+    "عربستان سعودی",
+    "سنگال",
+    "صربستان",
+    "سیشل",
+    "سیرالئون",
+    "سنگاپور",
+    "اسلواکی",
+    "ویتنام",
+    "اسلوونی",
+    "سومالی",
+    "افریقای جنوبی",
+    "زیمبابوه",
+    "اسپانیا",
+    "سودان جنوبی",
+    "سودان",
+    "صحرای غربی",
+    "سورینام",
+    "سوالبارد و یان ماین",
+    "اسواتینی",
+    "سوئد",
+    "سوئیس",
+    "سوریه",
+    "تاجیکستان",
+    "تایلند",
+    "توگو",
+    "توکلائو",
+    "تونگا",
+    "ترینیداد و توباگو",
+    "امارات متحدهٔ عربی",
+    "تونس",
+    "ترکیه",
+    "ترکمنستان",
+    "جزایر تورکس و کایکوس",
+    "تووالو",
+    "اوگاندا",
+    "اوکراین",
+    "مقدونیهٔ شمالی",
+    "مصر",
+    "بریتانیا",
+    "گرنزی",
+    "جرزی",
+    "جزیرهٔ من",
+    "تانزانیا",
+    "ایالات متحده",
+    "جزایر ویرجین ایالات متحده",
+    "بورکینافاسو",
+    "اروگوئه",
+    "ازبکستان",
+    "ونزوئلا",
+    "والیس و فوتونا",
+    "ساموآ",
+    "یمن",
+    "زامبیا",
+];
+
+static FI: [&str; 249] = [
+    "Afganistan",
+    "Albania",
+    "Antarktis",
+    "Algeria",
+    "Amerikan Samoa",
+    "Andorra",
+    "Angola",
+    "Antigua ja Barbuda",
+    "Azerbaidžan",
+    "Argentiina",
+    "Australia",
+    "Itävalta",
+    "Bahama",
+    "Bahrain",
+    "Bangladesh",
+    "Armenia",
+    "Barbados",
+    "Belgia",
+    "Bermuda",
+    "Bhutan",
+    "Bolivia",
+    "Bosnia ja Hertsegovina",
+    "Botswana",
+    "Bouvet’nsaari",
+    "Brasilia",
+    "Belize",
+    "Brittiläinen Intian valtameren alue",
+    "Salomonsaaret",
+    "Brittiläiset Neitsytsaaret",
+    "Brunei",
+    "Bulgaria",
+    "Myanmar (Burma)",
+    "Burundi",
+    "Valko-Venäjä",
+    "Kambodža",
+    "Kamerun",
+    "Kanada",
+    "Kap Verde",
+    "Caymansaaret",
+    "Keski-Afrikan tasavalta",
+    "Sri Lanka",
+    "Tšad",
+    "Chile",
+    "Kiina",
+    "Taiwan",
+    "Joulusaari",
+    "Kookossaaret (Keelingsaaret)",
+    "Kolumbia",
+    "Komorit",
+    "Mayotte",
+    "Kongon tasavalta",
+    "Kongon demokraattinen tasavalta",
+    "Cookinsaaret",
+    "Costa Rica",
+    "Kroatia",
+    "Kuuba",
+    "Kypros",
+    "Tšekki",
+    "Benin",
+    "Tanska",
+    "Dominica",
+    "Dominikaaninen tasavalta",
+    "Ecuador",
+    "El Salvador",
+    "Päiväntasaajan Guinea",
+    "Etiopia",
+    "Eritrea",
+    "Viro",
+    "Färsaaret",
+    "Falklandinsaaret",
+    "Etelä-Georgia ja Eteläiset Sandwichinsaaret",
+    "Fidži",
+    "Suomi",
+    "Ahvenanmaa",
+    "Ranska",
+    "Ranskan Guayana",
+    "Ranskan Polynesia",
+    "Ranskan eteläiset ja antarktiset alueet",
+    "Djibouti",
+    "Gabon",
+    "Georgia",
+    "Gambia",
+    "Palestiinalaisalue",
+    "Saksa",
+    "Ghana",
+    "Gibraltar",
+    "Kiribati",
+    "Kreikka",
+    "Grönlanti",
+    "Grenada",
+    "Guadeloupe",
+    "Guam",
+    "Guatemala",
+    "Guinea",
+    "Guyana",
+    "Haiti",
+    "Heard ja McDonaldinsaaret",
+    "Vatikaani",
+    "Honduras",
+    "Hongkong – Kiinan erityishallintoalue",
+    "Unkari",
+    "Islanti",
+    "Intia",
+    "Indonesia",
+    "Iran",
+    "Irak",
+    "Irlanti",
+    "Israel",
+    "Italia",
+    "Norsunluurannikko",
+    "Jamaika",
+    "Japani",
+    "Kazakstan",
+    "Jordania",
+    "Kenia",
+    "Pohjois-Korea",
+    "Etelä-Korea",
+    "Kuwait",
+    "Kirgisia",
+    "Laos",
+    "Libanon",
+    "Lesotho",
+    "Latvia",
+    "Liberia",
+    "Libya",
+    "Liechtenstein",
+    "Liettua",
+    "Luxemburg",
+    "Macao – Kiinan erityishallintoalue",
+    "Madagaskar",
+    "Malawi",
+    "Malesia",
+    "Malediivit",
+    "Mali",
+    "Malta",
+    "Martinique",
+    "Mauritania",
+    "Mauritius",
+    "Meksiko",
+    "Monaco",
+    "Mongolia",
+    "Moldova",
+    "Montenegro",
+    "Montserrat",
+    "Marokko",
+    "Mosambik",
+    "Oman",
+    "Namibia",
+    "Nauru",
+    "Nepal",
+    "Alankomaat",
+    "Curaçao",
+    "Aruba",
+    "Sint Maarten",
+    "Karibian Alankomaat",
+    "Uusi-Kaledonia",
+    "Vanuatu",
+    "Uusi-Seelanti",
+    "Nicaragua",
+    "Niger",
+    "Nigeria",
+    "Niue",
+    "Norfolkinsaari",
+    "Norja",
+    "Pohjois-Mariaanit",
+    "Yhdysvaltain erillissaaret",
+    "Mikronesia",
+    "Marshallinsaaret",
+    "Palau",
+    "Pakistan",
+    "Panama",
+    "Papua-Uusi-Guinea",
+    "Paraguay",
+    "Peru",
+    "Filippiinit",
+    "Pitcairn",
+    "Puola",
+    "Portugali",
+    "Guinea-Bissau",
+    "Itä-Timor",
+    "Puerto Rico",
+    "Qatar",
+    "Réunion",
+    "Romania",
+    "Venäjä",
+    "Ruanda",
+    "Saint-Barthélemy",
+    "Saint Helena",
+    "Saint Kitts ja Nevis",
+    "Anguilla",
+    "Saint Lucia",
+    "Saint-Martin",
+    "Saint-Pierre ja Miquelon",
+    "Saint Vincent ja Grenadiinit",
+    "San Marino",
+    "São Tomé ja Príncipe",
+    "Saudi-Arabia",
+    "Senegal",
+    "Serbia",
+    "Seychellit",
+    "Sierra Leone",
+    "Singapore",
+    "Slovakia",
+    "Vietnam",
+    "Slovenia",
+    "Somalia",
+    "Etelä-Afrikka",
+    "Zimbabwe",
+    "Espanja",
+    "Etelä-Sudan",
+    "Sudan",
+    "Länsi-Sahara",
+    "Suriname",
+    "Huippuvuoret ja Jan Mayen",
+    "Eswatini",
+    "Ruotsi",
+    "Sveitsi",
+    "Syyria",
+    "Tadžikistan",
+    "Thaimaa",
+    "Togo",
+    "Tokelau",
+    "Tonga",
+    "Trinidad ja Tobago",
+    "Arabiemiirikunnat",
+    "Tunisia",
+    "Turkki",
+    "Turkmenistan",
+    "Turks- ja Caicossaaret",
+    "Tuvalu",
+    "Uganda",
+    "Ukraina",
+    "Pohjois-Makedonia",
+    "Egypti",
+    "Iso-Britannia",
+    "Guernsey",
+    "Jersey",
+    "Mansaari",
+    "Tansania",
+    "Yhdysvallat",
+    "Yhdysvaltain Neitsytsaaret",
+    "Burkina Faso",
+    "Uruguay",
+    "Uzbekistan",
+    "Venezuela",
+    "Wallis ja Futuna",
+    "Samoa",
+    "Jemen",
+    "Sambia",
+];
+
+static FIL: [&str; 249] = [
+    "Afghanistan",
+    "Albania",
+    "Antarctica",
+    "Algeria",
+    "American Samoa",
+    "Andorra",
+    "Angola",
+    "Antigua & Barbuda",
+    "Azerbaijan",
+    "Argentina",
+    "Australia",
+    "Austria",
+    "Bahamas",
+    "Bahrain",
+    "Bangladesh",
+    "Armenia",
+    "Barbados",
+    "Belgium",
+    "Bermuda",
+    "Bhutan",
+    "Bolivia",
+    "Bosnia and Herzegovina",
+    "Botswana",
+    "Bouvet Island",
+    "Brazil",
+    "Belize",
+    "British Indian Ocean Territory",
+    "Solomon Islands",
+    "British Virgin Islands",
+    "Brunei",
+    "Bulgaria",
+    "Myanmar (Burma)",
+    "Burundi",
+    "Belarus",
+    "Cambodia",
+    "Cameroon",
+    "Canada",
+    "Cape Verde",
+    "Cayman Islands",
+    "Central African Republic",
+    "Sri Lanka",
+    "Chad",
+    "Chile",
+    "China",
+    "Taiwan",
+    "Christmas Island",
+    "Cocos (Keeling) Islands",
+    "Colombia",
+    "Comoros",
+    "Mayotte",
+    "Congo - Brazzaville",
+    "Congo - Kinshasa",
+    "Cook Islands",
+    "Costa Rica",
+    "Croatia",
+    "Cuba",
+    "Cyprus",
+    "Czechia",
+    "Benin",
+    "Denmark",
+    "Dominica",
+    "Dominican Republic",
+    "Ecuador",
+    "El Salvador",
+    "Equatorial Guinea",
+    "Ethiopia",
+    "Eritrea",
+    "Estonia",
+    "Faroe Islands",
+    "Falkland Islands",
+    "South Georgia & South Sandwich Islands",
+    "Fiji",
+    "Finland",
+    "Åland Islands",
+    "France",
+    "French Guiana",
+    "French Polynesia",
+    "French Southern Territories",
+    "Djibouti",
+    "Gabon",
+    "Georgia",
+    "Gambia",
+    "Palestinian Territories",
+    "Germany",
+    "Ghana",
+    "Gibraltar",
+    "Kiribati",
+    "Greece",
+    "Greenland",
+    "Grenada",
+    "Guadeloupe",
+    "Guam",
+    "Guatemala",
+    "Guinea",
+    "Guyana",
+    "Haiti",
+    "Heard & McDonald Islands",
+    "Vatican City",
+    "Honduras",
+    "Hong Kong SAR China",
+    "Hungary",
+    "Iceland",
+    "India",
+    "Indonesia",
+    "Iran",
+    "Iraq",
+    "Ireland",
+    "Israel",
+    "Italy",
+    "Côte d’Ivoire",
+    "Jamaica",
+    "Japan",
+    "Kazakhstan",
+    "Jordan",
+    "Kenya",
+    "Hilagang Korea",
+    "Timog Korea",
+    "Kuwait",
+    "Kyrgyzstan",
+    "Laos",
+    "Lebanon",
+    "Lesotho",
+    "Latvia",
+    "Liberia",
+    "Libya",
+    "Liechtenstein",
+    "Lithuania",
+    "Luxembourg",
+    "Macau SAR China",
+    "Madagascar",
+    "Malawi",
+    "Malaysia",
+    "Maldives",
+    "Mali",
+    "Malta",
+    "Martinique",
+    "Mauritania",
+    "Mauritius",
+    "Mexico",
+    "Monaco",
+    "Mongolia",
+    "Moldova",
+    "Montenegro",
+    "Montserrat",
+    "Morocco",
+    "Mozambique",
+    "Oman",
+    "Namibia",
+    "Nauru",
+    "Nepal",
+    "Netherlands",
+    "Curaçao",
+    "Aruba",
+    "Sint Maarten",
+    "Caribbean Netherlands",
+    "New Caledonia",
+    "Vanuatu",
+    "New Zealand",
+    "Nicaragua",
+    "Niger",
+    "Nigeria",
+    "Niue",
+    "Norfolk Island",
+    "Norway",
+    "Northern Mariana Islands",
+    "U.S. Outlying Islands",
+    "Micronesia",
+    "Marshall Islands",
+    "Palau",
+    "Pakistan",
+    "Panama",
+    "Papua New Guinea",
+    "Paraguay",
+    "Peru",
+    "Pilipinas",
+    "Pitcairn Islands",
+    "Poland",
+    "Portugal",
+    "Guinea-Bissau",
+    "Timor-Leste",
+    "Puerto Rico",
+    "Qatar",
+    "Réunion",
+    "Romania",
+    "Russia",
+    "Rwanda",
+    "St. Barthélemy",
+    "St. Helena",
+    "St. Kitts & Nevis",
+    "Anguilla",
+    "Saint Lucia",
+    "Saint Martin",
+    "St. Pierre & Miquelon",
+    "St. Vincent & Grenadines",
+    "San Marino",
+    "São Tomé & Príncipe",
+    "Saudi Arabia",
+    "Senegal",
+    "Serbia",
+    "Seychelles",
+    "Sierra Leone",
+    "Singapore",
+    "Slovakia",
+    "Vietnam",
+    "Slovenia",
+    "Somalia",
+    "South Africa",
+    "Zimbabwe",
+    "Spain",
+    "Timog Sudan",
+    "Sudan",
+    "Kanlurang Sahara",
+    "Suriname",
+    "Svalbard & Jan Mayen",
+    "Swaziland",
+    "Sweden",
+    "Switzerland",
+    "Syria",
+    "Tajikistan",
+    "Thailand",
+    "Togo",
+    "Tokelau",
+    "Tonga",
+    "Trinidad & Tobago",
+    "United Arab Emirates",
+    "Tunisia",
+    "Türkiye",
+    "Turkmenistan",
+    "Turks & Caicos Islands",
+    "Tuvalu",
+    "Uganda",
+    "Ukraine",
+    "North Macedonia",
+    "Egypt",
+    "United Kingdom",
+    "Guernsey",
+    "Jersey",
+    "Isle of Man",
+    "Tanzania",
+    "Estados Unidos",
+    "U.S. Virgin Islands",
+    "Burkina Faso",
+    "Uruguay",
+    "Uzbekistan",
+    "Venezuela",
+    "Wallis & Futuna",
+    "Samoa",
+    "Yemen",
+    "Zambia",
+];
+
+static FR: [&str; 249] = [
+    "Afghanistan",
+    "Albanie",
+    "Antarctique",
+    "Algérie",
+    "Samoa américaines",
+    "Andorre",
+    "Angola",
+    "Antigua-et-Barbuda",
+    "Azerbaïdjan",
+    "Argentine",
+    "Australie",
+    "Autriche",
+    "Bahamas",
+    "Bahreïn",
+    "Bangladesh",
+    "Arménie",
+    "Barbade",
+    "Belgique",
+    "Bermudes",
+    "Bhoutan",
+    "Bolivie",
+    "Bosnie-Herzégovine",
+    "Botswana",
+    "Île Bouvet",
+    "Brésil",
+    "Belize",
+    "Territoire britannique de l’océan Indien",
+    "Îles Salomon",
+    "Îles Vierges britanniques",
+    "Brunei",
+    "Bulgarie",
+    "Myanmar (Birmanie)",
+    "Burundi",
+    "Biélorussie",
+    "Cambodge",
+    "Cameroun",
+    "Canada",
+    "Cap-Vert",
+    "Îles Caïmans",
+    "République centrafricaine",
+    "Sri Lanka",
+    "Tchad",
+    "Chili",
+    "Chine",
+    "Taïwan",
+    "Île Christmas",
+    "Îles Cocos",
+    "Colombie",
+    "Comores",
+    "Mayotte",
+    "Congo-Brazzaville",
+    "Congo-Kinshasa",
+    "Îles Cook",
+    "Costa Rica",
+    "Croatie",
+    "Cuba",
+    "Chypre",
+    "Tchéquie",
+    "Bénin",
+    "Danemark",
+    "Dominique",
+    "République dominicaine",
+    "Équateur",
+    "Salvador",
+    "Guinée équatoriale",
+    "Éthiopie",
+    "Érythrée",
+    "Estonie",
+    "Îles Féroé",
+    "Îles Malouines",
+    "Géorgie du Sud-et-les Îles Sandwich du Sud",
+    "Fidji",
+    "Finlande",
+    "Îles Åland",
+    "France",
+    "Guyane française",
+    "Polynésie française",
+    "Terres australes françaises",
+    "Djibouti",
+    "Gabon",
+    "Géorgie",
+    "Gambie",
+    "Territoires palestiniens",
+    "Allemagne",
+    "Ghana",
+    "Gibraltar",
+    "Kiribati",
+    "Grèce",
+    "Groenland",
+    "Grenade",
+    "Guadeloupe",
+    "Guam",
+    "Guatemala",
+    "Guinée",
+    "Guyana",
+    "Haïti",
+    "Îles Heard-et-MacDonald",
+    "État de la Cité du Vatican",
+    "Honduras",
+    "R.A.S. chinoise de Hong Kong",
+    "Hongrie",
+    "Islande",
+    "Inde",
+    "Indonésie",
+    "Iran",
+    "Irak",
+    "Irlande",
+    "Israël",
+    "Italie",
+    "Côte d’Ivoire",
+    "Jamaïque",
+    "Japon",
+    "Kazakhstan",
+    "Jordanie",
+    "Kenya",
+    "Corée du Nord",
+    "Corée du Sud",
+    "Koweït",
+    "Kirghizstan",
+    "Laos",
+    "Liban",
+    "Lesotho",
+    "Lettonie",
+    "Liberia",
+    "Libye",
+    "Liechtenstein",
+    "Lituanie",
+    "Luxembourg",
+    "R.A.S. chinoise de Macao",
+    "Madagascar",
+    "Malawi",
+    "Malaisie",
+    "Maldives",
+    "Mali",
+    "Malte",
+    "Martinique",
+    "Mauritanie",
+    "Maurice",
+    "Mexique",
+    "Monaco",
+    "Mongolie",
+    "Moldavie",
+    "Monténégro",
+    "Montserrat",
+    "Maroc",
+    "Mozambique",
+    "Oman",
+    "Namibie",
+    "Nauru",
+    "Népal",
+    "Pays-Bas",
+    "Curaçao",
+    "Aruba",
+    "Saint-Martin (partie néerlandaise)",
+    "Pays-Bas caribéens",
+    "Nouvelle-Calédonie",
+    "Vanuatu",
+    "Nouvelle-Zélande",
+    "Nicaragua",
+    "Niger",
+    "Nigeria",
+    "Niue",
+    "Île Norfolk",
+    "Norvège",
+    "Îles Mariannes du Nord",
+    "Îles mineures éloignées des États-Unis",
+    "Micronésie",
+    "Îles Marshall",
+    "Palaos",
+    "Pakistan",
+    "Panama",
+    "Papouasie-Nouvelle-Guinée",
+    "Paraguay",
+    "Pérou",
+    "Philippines",
+    "Îles Pitcairn",
+    "Pologne",
+    "Portugal",
+    "Guinée-Bissau",
+    "Timor oriental",
+    "Porto Rico",
+    "Qatar",
+    "La Réunion",
+    "Roumanie",
+    "Russie",
+    "Rwanda",
+    "Saint-Barthélemy",
+    "Sainte-Hélène",
+    "Saint-Christophe-et-Niévès",
+    "Anguilla",
+    "Sainte-Lucie",
+    "Saint-Martin",
+    "Saint-Pierre-et-Miquelon",
+    "Saint-Vincent-et-les Grenadines",
+    "Saint-Marin",
+    "Sao Tomé-et-Principe",
+    "Arabie saoudite",
+    "Sénégal",
+    "Serbie",
+    "Seychelles",
+    "Sierra Leone",
+    "Singapour",
+    "Slovaquie",
+    "Viêt Nam",
+    "Slovénie",
+    "Somalie",
+    "Afrique du Sud",
+    "Zimbabwe",
+    "Espagne",
+    "Soudan du Sud",
+    "Soudan",
+    "Sahara occidental",
+    "Suriname",
+    "Svalbard et Jan Mayen",
+    "Eswatini",
+    "Suède",
+    "Suisse",
+    "Syrie",
+    "Tadjikistan",
+    "Thaïlande",
+    "Togo",
+    "Tokelau",
+    "Tonga",
+    "Trinité-et-Tobago",
+    "Émirats arabes unis",
+    "Tunisie",
+    "Turquie",
+    "Turkménistan",
+    "Îles Turques-et-Caïques",
+    "Tuvalu",
+    "Ouganda",
+    "Ukraine",
+    "Macédoine du Nord",
+    "Égypte",
+    "Royaume-Uni",
+    "Guernesey",
+    "Jersey",
+    "Île de Man",
+    "Tanzanie",
+    "États-Unis",
+    "Îles Vierges des États-Unis",
+    "Burkina Faso",
+    "Uruguay",
+    "Ouzbékistan",
+    "Venezuela",
+    "Wallis-et-Futuna",
+    "Samoa",
+    "Yémen",
+    "Zambie",
+];
+
+static FR_BE: &[(Country, &str)] = &[
+    (Country::GS, "Îles Géorgie du Sud et Sandwich du Sud"),
+];
+
+static FR_CA: &[(Country, &str)] = &[
+    (Country::BV, "île Bouvet"),
+    (Country::BZ, "Bélize"),
+    (Country::VG, "îles Vierges britanniques"),
+    (Country::BN, "Brunéi"),
+    (Country::MM, "Myanmar"),
+    (Country::BY, "Bélarus"),
+    (Country::CX, "île Christmas"),
+    (Country::CC, "îles Cocos (Keeling)"),
+    (Country::CK, "îles Cook"),
+    (Country::FO, "îles Féroé"),
+    (Country::FK, "îles Malouines"),
+    (Country::AX, "îles d’Åland"),
+    (Country::HM, "îles Heard et McDonald"),
+    (Country::VA, "Cité du Vatican"),
+    (Country::KG, "Kirghizistan"),
+    (Country::LR, "Libéria"),
+    (Country::SX, "Saint-Martin (Pays-Bas)"),
+    (Country::NG, "Nigéria"),
+    (Country::NF, "île Norfolk"),
+    (Country::MP, "Mariannes du Nord"),
+    (Country::UM, "îles mineures éloignées des États-Unis"),
+    (Country::PN, "îles Pitcairn"),
+    (Country::TL, "Timor-Leste"),
+    (Country::RE, "la Réunion"),
+    (Country::KN, "Saint‑Kitts‑et‑Nevis"),
+    (Country::MF, "Saint-Martin (France)"),
+    (Country::VN, "Vietnam"),
+    (Country::IM, "île de Man"),
+    (Country::VI, "îles Vierges américaines"),
+    (Country::VE, "Vénézuéla"),
+];
+
+static HE: [&str; 249] = [
+    "אפגניסטן",
+    "אלבניה",
+    "אנטארקטיקה",
+    "אלג׳יריה",
+    "סמואה האמריקנית",
+    "אנדורה",
+    "אנגולה",
+    "אנטיגואה וברבודה",
+    "אזרבייג׳ן",
+    "ארגנטינה",
+    "אוסטרליה",
+    "אוסטריה",
+    "איי בהאמה",
+    "בחריין",
+    "בנגלדש",
+    "ארמניה",
+    "ברבדוס",
+    "בלגיה",
+    "ברמודה",
+    "בהוטן",
+    "בוליביה",
+    "בוסניה והרצגובינה",
+    "בוטסואנה",
+    "האי בובה",
+    "ברזיל",
+    "בליז",
+    "הטריטוריה הבריטית באוקיינוס ההודי",
+    "איי שלמה",
+    "איי הבתולה הבריטיים",
+    "ברוניי",
+    "בולגריה",
+    "מיאנמר (בורמה)",
+    "בורונדי",
+    "בלארוס",
+    "קמבודיה",
+    "קמרון",
+    "קנדה",
+    "כף ורדה",
+    "איי קיימן",
+    "הרפובליקה המרכז-אפריקאית",
+    "סרי לנקה",
+    "צ׳אד",
+    "צ׳ילה",
+    "סין",
+    "טייוואן",
+    "אי חג המולד",
+    "איי קוקוס (קילינג)",
+    "קולומביה",
+    "קומורו",
+    "מאיוט",
+    "קונגו - ברזאויל",
+    "קונגו - קינשאסה",
+    "איי קוק",
+    "קוסטה ריקה",
+    "קרואטיה",
+    "קובה",
+    "קפריסין",
+    "צ׳כיה",
+    "בנין",
+    "דנמרק",
+    "דומיניקה",
+    "הרפובליקה הדומיניקנית",
+    "אקוודור",
+    "אל סלבדור",
+    "גינאה המשוונית",
+    "אתיופיה",
+    "אריתריאה",
+    "אסטוניה",
+    "איי פארו",
+    "איי פוקלנד",
+    "ג׳ורג׳יה הדרומית ואיי סנדוויץ׳ הדרומיים",
+    "פיג׳י",
+    "פינלנד",
+    "איי אולנד",
+    "צרפת",
+    "גיאנה הצרפתית",
+    "פולינזיה הצרפתית",
+    "הטריטוריות הדרומיות של צרפת",
+    "ג׳יבוטי",
+    "גבון",
+    "גאורגיה",
+    "גמביה",
+    "השטחים הפלסטיניים",
+    "גרמניה",
+    "גאנה",
+    "גיברלטר",
+    "קיריבאטי",
+    "יוון",
+    "גרינלנד",
+    "גרנדה",
+    "גוואדלופ",
+    "גואם",
+    "גואטמלה",
+    "גינאה",
+    "גיאנה",
+    "האיטי",
+    "איי הרד ומקדונלד",
+    "הוותיקן",
+    "הונדורס",
+    "הונג קונג (אזור מנהלי מיוחד של סין)",
+    "הונגריה",
+    "איסלנד",
+    "הודו",
+    "אינדונזיה",
+    "איראן",
+    "עיראק",
+    "אירלנד",
+    "ישראל",
+    "איטליה",
+    "חוף השנהב",
+    "ג׳מייקה",
+    "יפן",
+    "קזחסטן",
+    "ירדן",
+    "קניה",
+    "קוריאה הצפונית",
+    "קוריאה הדרומית",
+    "כווית",
+    "קירגיזסטן",
+    "לאוס",
+    "לבנון",
+    "לסוטו",
+    "לטביה",
+    "ליבריה",
+    "לוב",
+    "ליכטנשטיין",
+    "ליטא",
+    "לוקסמבורג",
+    "מקאו (אזור מנהלי מיוחד של סין)",
+    "מדגסקר",
+    "מלאווי",
+    "מלזיה",
+    "האיים המלדיביים",
+    "מאלי",
+    "מלטה",
+    "מרטיניק",
+    "מאוריטניה",
+    "מאוריציוס",
+    "מקסיקו",
+    "מונקו",
+    "מונגוליה",
+    "מולדובה",
+    "מונטנגרו",
+    "מונסראט",
+    "מרוקו",
+    "מוזמביק",
+    "עומאן",
+    "נמיביה",
+    "נאורו",
+    "נפאל",
+    "הולנד",
+    "קוראסאו",
+    "ארובה",
+    "סנט מארטן",
+    "האיים הקריביים ההולנדיים",
+    "קלדוניה החדשה",
+    "ונואטו",
+    "ניו זילנד",
+    "ניקרגואה",
+    "ניז׳ר",
+    "ניגריה",
+    "ניווה",
+    "האי נורפוק",
+    "נורווגיה",
+    "איי מריאנה הצפוניים",
+    "האיים המרוחקים הקטנים של ארה״ב",
+    "מיקרונזיה",
+    "איי מרשל",
+    "פלאו",
+    "פקיסטן",
+    "פנמה",
+    "פפואה גינאה החדשה",
+    "פרגוואי",
+    "פרו",
+    "הפיליפינים",
+    "איי פיטקרן",
+    "פולין",
+    "פורטוגל",
+    "גינאה-ביסאו",
+    "טימור-לסטה",
+    "פוארטו ריקו",
+    "קטאר",
+    "ראוניון",
+    "רומניה",
+    "רוסיה",
+    "רואנדה",
+    "סנט ברתולומיאו",
+    "סנט הלנה",
+    "סנט קיטס ונוויס",
+    "אנגווילה",
+    "סנט לוסיה",
+    "סן מרטן",
+    "סנט פייר ומיקלון",
+    "סנט וינסנט והגרנדינים",
+    "סן מרינו",
+    "סאו טומה ופרינסיפה",
+    "ערב הסעודית",
+    "סנגל",
+    "סרביה",
+    "איי סיישל",
+    "סיירה לאון",
+    "סינגפור",
+    "סלובקיה",
+    "וייטנאם",
+    "סלובניה",
+    "סומליה",
+    "דרום אפריקה",
+    "זימבבואה",
+    "ספרד",
+    "דרום סודן",
+    "סודן",
+    "סהרה המערבית",
+    "סורינאם",
+    "סבאלברד ויאן מאיין",
+    "אסוואטיני",
+    "שוודיה",
+    "שווייץ",
+    "סוריה",
+    "טג׳יקיסטן",
+    "תאילנד",
+    "טוגו",
+    "טוקלאו",
+    "טונגה",
+    "טרינידד וטובגו",
+    "איחוד האמירויות הערביות",
+    "תוניסיה",
+    "טורקיה",
+    "טורקמניסטן",
+    "איי טרקס וקייקוס",
+    "טובאלו",
+    "אוגנדה",
+    "אוקראינה",
+    "מקדוניה הצפונית",
+    "מצרים",
+    "בריטניה",
+    "גרנזי",
+    "ג׳רזי",
+    "האי מאן",
+    "טנזניה",
+    "ארצות הברית",
+    "איי הבתולה של ארצות הברית",
+    "בורקינה פאסו",
+    "אורוגוואי",
+    "אוזבקיסטן",
+    "ונצואלה",
+    "איי ווליס ופוטונה",
+    "סמואה",
+    "תימן",
+    "זמביה",
+];
+
+static HI: [&str; 249] = [
+    "अफ़गानिस्तान",
+    "अल्बानिया",
+    "अंटार्कटिका",
+    "अल्जीरिया",
+    "अमेरिकी समोआ",
+    "एंडोरा",
+    "अंगोला",
+    "एंटिगुआ और बरबुडा",
+    "अज़रबैजान",
+    "अर्जेंटीना",
+    "ऑस्ट्रेलिया",
+    "ऑस्ट्रिया",
+    "बहामास",
+    "बहरीन",
+    "बांग्लादेश",
+    "आर्मेनिया",
+    "बारबाडोस",
+    "बेल्जियम",
+    "बरमूडा",
+    "भूटान",
+    "बोलीविया",
+    "बोस्निया और हर्ज़ेगोविना",
+    "बोत्स्वाना",
+    "बोवेत द्वीप",
+    "ब्राज़ील",
+    "बेलीज़",
+    "ब्रिटिश हिंद महासागरीय क्षेत्र",
+    "सोलोमन द्वीपसमूह",
+    "ब्रिटिश वर्जिन द्वीपसमूह",
+    "ब्रूनेई",
+    "बुल्गारिया",
+    "म्यांमार (बर्मा)",
+    "बुरुंडी",
+    "बेलारूस",
+    "कंबोडिया",
+    "कैमरून",
+    "कनाडा",
+    "केप वर्ड",
+    "कैमेन द्वीपसमूह",
+    "मध्य अफ़्रीकी गणराज्य",
+    "श्रीलंका",
+    "चाड",
+    "चिली",
+    "चीन",
+    "ताइवान",
+    "क्रिसमस द्वीप",
+    "कोकोस (कीलिंग) द्वीपसमूह",
+    "कोलंबिया",
+    "कोमोरोस",
+    "मायोते",
+    "कांगो – ब्राज़ाविल",
+    "कांगो - किंशासा",
+    "कुक द्वीपसमूह",
+    "कोस्टारिका",
+    "क्रोएशिया",
+    "क्यूबा",
+    "साइप्रस",
+    "चेकिया",
+    "बेनिन",
+    "डेनमार्क",
+    "डोमिनिका",
+    "डोमिनिकन गणराज्य",
+    "इक्वाडोर",
+    "अल सल्वाडोर",
+    "इक्वेटोरियल गिनी",
+    "इथियोपिया",
+    "इरिट्रिया",
+    "एस्टोनिया",
+    "फ़ेरो द्वीपसमूह",
+    "फ़ॉकलैंड द्वीपसमूह",
+    "दक्षिण जॉर्जिया और दक्षिण सैंडविच द्वीपसमूह",
+    "फ़िजी",
+    "फ़िनलैंड",
+    "एलैंड द्वीपसमूह",
+    "फ़्रांस",
+    "फ़्रेंच गुयाना",
+    "फ़्रेंच पोलिनेशिया",
+    "फ़्रांसीसी दक्षिणी क्षेत्र",
+    "जिबूती",
+    "गैबॉन",
+    "जॉर्जिया",
+    "गाम्बिया",
+    "फ़िलिस्तीनी क्षेत्र",
+    "जर्मनी",
+    "घाना",
+    "जिब्राल्टर",
+    "किरिबाती",
+    "यूनान",
+    "ग्रीनलैंड",
+    "ग्रेनाडा",
+    "ग्वाडेलूप",
+    "गुआम",
+    "ग्वाटेमाला",
+    "गिनी",
+    "गुयाना",
+    "हैती",
+    "हर्ड द्वीप और मैकडोनॉल्ड द्वीपसमूह",
+    "वेटिकन सिटी",
+    "होंडूरास",
+    "हाँग काँग (चीन विशेष प्रशासनिक क्षेत्र)",
+    "हंगरी",
+    "आइसलैंड",
+    "भारत",
+    "इंडोनेशिया",
+    "ईरान",
+    "इराक",
+    "आयरलैंड",
+    "इज़राइल",
+    "इटली",
+    "कोत दिवुआर",
+    "जमैका",
+    "जापान",
+    "कज़ाखस्तान",
+    "जॉर्डन",
+    "केन्या",
+    "उत्तर कोरिया",
+    "दक्षिण कोरिया",
+    "कुवैत",
+    "किर्गिज़स्तान",
+    "लाओस",
+    "लेबनान",
+    "लेसोथो",
+    "लातविया",
+    "लाइबेरिया",
+    "लीबिया",
+    "लिचेंस्टीन",
+    "लिथुआनिया",
+    "लग्ज़मबर्ग",
+    "मकाऊ (विशेष प्रशासनिक क्षेत्र चीन)",
+    "मेडागास्कर",
+    "मलावी",
+    "मलेशिया",
+    "मालदीव",
+    "माली",
+    "माल्टा",
+    "मार्टीनिक",
+    "मॉरिटानिया",
+    "मॉरीशस",
+    "मैक्सिको",
+    "मोनाको",
+    "मंगोलिया",
+    "मॉल्डोवा",
+    "मोंटेनेग्रो",
+    "मोंटसेरात",
+    "मोरक्को",
+    "मोज़ांबिक",
+    "ओमान",
+    "नामीबिया",
+    "नाउरु",
+    "नेपाल",
+    "नीदरलैंड",
+    "कुरासाओ",
+    "अरूबा",
+    "सिंट मार्टिन",
+    "कैरिबियन नीदरलैंड",
+    "न्यू कैलेडोनिया",
+    "वनुआतू",
+    "न्यूज़ीलैंड",
+    "निकारागुआ",
+    "नाइजर",
+    "नाइजीरिया",
+    "नीयू",
+    "नॉरफ़ॉक द्वीप",
+    "नॉर्वे",
+    "उत्तरी मारियाना द्वीपसमूह",
+    "यू॰एस॰ आउटलाइंग द्वीपसमूह",
+    "माइक्रोनेशिया",
+    "मार्शल द्वीपसमूह",
+    "पलाऊ",
+    "पाकिस्तान",
+    "पनामा",
+    "पापुआ न्यू गिनी",
+    "पराग्वे",
+    "पेरू",
+    "फ़िलिपींस",
+    "पिटकैर्न द्वीपसमूह",
+    "पोलैंड",
+    "पुर्तगाल",
+    "गिनी-बिसाउ",
+    "तिमोर-लेस्त",
+    "पोर्टो रिको",
+    "क़तर",
+    "रियूनियन",
+    "रोमानिया",
+    "रूस",
+    "रवांडा",
+    "सेंट बार्थेलेमी",
+    "सेंट हेलेना",
+    "सेंट किट्स और नेविस",
+    "एंग्विला",
+    "सेंट लूसिया",
+    "सेंट मार्टिन",
+    "सेंट पिएरे और मिक्वेलान",
+    "सेंट विंसेंट और ग्रेनाडाइंस",
+    "सैन मेरीनो",
+    "साओ टोम और प्रिंसिपे",
+    "सऊदी अरब",
+    "सेनेगल",
+    "सर्बिया",
+    "सेशेल्स",
+    "सिएरा लियोन",
+    "सिंगापुर",
+    "स्लोवाकिया",
+    "वियतनाम",
+    "स्लोवेनिया",
+    "सोमालिया",
+    "दक्षिण अफ़्रीका",
+    "ज़िम्बाब्वे",
+    "स्पेन",
+    "दक्षिण सूडान",
+    "सूडान",
+    "पश्चिमी सहारा",
+    "सूरीनाम",
+    "स्वालबार्ड और जान मायेन",
+    "एस्वाटिनी",
+    "स्वीडन",
+    "स्विट्ज़रलैंड",
+    "सीरिया",
+    "ताजिकिस्तान",
+    "थाईलैंड",
+    "टोगो",
+    "तोकेलाउ",
+    "टोंगा",
+    "त्रिनिदाद और टोबैगो",
+    "संयुक्त अरब अमीरात",
+    "ट्यूनीशिया",
+    "तुर्किये",
+    "तुर्कमेनिस्तान",
+    "तुर्क और कैकोज़ द्वीपसमूह",
+    "तुवालू",
+    "युगांडा",
+    "यूक्रेन",
+    "उत्तरी मकदूनिया",
+    "मिस्र",
+    "यूनाइटेड किंगडम",
+    "गर्नसी",
+    "जर्सी",
+    "आइल ऑफ़ मैन",
+    "तंज़ानिया",
+    "संयुक्त राज्य",
+    "यू॰एस॰ वर्जिन द्वीपसमूह",
+    "बुर्किना फ़ासो",
+    "उरूग्वे",
+    "उज़्बेकिस्तान",
+    "वेनेज़ुएला",
+    "वालिस और फ़्यूचूना",
+    "समोआ",
+    "यमन",
+    "ज़ाम्बिया",
+];
+
+static HR: [&str; 249] = [
+    "Afganistan",
+    "Albanija",
+    "Antarktika",
+    "Alžir",
+    "Američka Samoa",
+    "Andora",
+    "Angola",
+    "Antigva i Barbuda",
+    "Azerbajdžan",
+    "Argentina",
+    "Australija",
+    "Austrija",
+    "Bahami",
+    "Bahrein",
+    "Bangladeš",
+    "Armenija",
+    "Barbados",
+    "Belgija",
+    "Bermudi",
+    "Butan",
+    "Bolivija",
+    "Bosna i Hercegovina",
+    "Bocvana",
+    "Otok Bouvet",
+    "Brazil",
+    "Belize",
+    "Britanski Indijskooceanski Teritorij",
+    "Salomonovi Otoci",
+    "Britanski Djevičanski Otoci",
+    "Brunej",
+    "Bugarska",
+    "Mjanmar (Burma)",
+    "Burundi",
+    "Bjelorusija",
+    "Kambodža",
+    "Kamerun",
+    "Kanada",
+    "Zelenortska Republika",
+    "Kajmanski Otoci",
+    "Srednjoafrička Republika",
+    "Šri Lanka",
+    "Čad",
+    "Čile",
+    "Kina",
+    "Tajvan",
+    "Božićni Otok",
+    "Kokosovi (Keelingovi) Otoci",
+    "Kolumbija",
+    "Komori",
+    "Mayotte",
+    "Kongo - Brazzaville",
+    "Kongo - Kinshasa",
+    "Cookovi Otoci",
+    "Kostarika",
+    "Hrvatska",
+    "Kuba",
+    "Cipar",
+    "Češka",
+    "Benin",
+    "Danska",
+    "Dominika",
+    "Dominikanska Republika",
+    "Ekvador",
+    "Salvador",
+    "Ekvatorska Gvineja",
+    "Etiopija",
+    "Eritreja",
+    "Estonija",
+    "Ovčji Otoci",
+    "Falklandski Otoci",
+    "Južna Georgia i Otoci Južni Sandwich",
+    "Fidži",
+    "Finska",
+    "Ålandski otoci",
+    "Francuska",
+    "Francuska Gijana",
+    "Francuska Polinezija",
+    "Francuski Južni Teritoriji",
+    "Džibuti",
+    "Gabon",
+    "Gruzija",
+    "Gambija",
+    "Palestinsko područje",
+    "Njemačka",
+    "Gana",
+    "Gibraltar",
+    "Kiribati",
+    "Grčka",
+    "Grenland",
+    "Grenada",
+    "Guadalupe",
+    "Guam",
+    "Gvatemala",
+    "Gvineja",
+    "Gvajana",
+    "Haiti",
+    "Otoci Heard i McDonald",
+    "Vatikan",
+    "Honduras",
+    "PUP Hong Kong Kina",
+    "Mađarska",
+    "Island",
+    "Indija",
+    "Indonezija",
+    "Iran",
+    "Irak",
+    "Irska",
+    "Izrael",
+    "Italija",
+    "Obala Bjelokosti",
+    "Jamajka",
+    "Japan",
+    "Kazahstan",
+    "Jordan",
+    "Kenija",
+    "Sjeverna Koreja",
+    "Južna Koreja",
+    "Kuvajt",
+    "Kirgistan",
+    "Laos",
+    "Libanon",
+    "Lesoto",
+    "Latvija",
+    "Liberija",
+    "Libija",
+    "Lihtenštajn",
+    "Litva",
+    "Luksemburg",
+    "PUP Makao Kina",
+    "Madagaskar",
+    "Malavi",
+    "Malezija",
+    "Maldivi",
+    "Mali",
+    "Malta",
+    "Martinik",
+    "Mauretanija",
+    "Mauricijus",
+    "Meksiko",
+    "Monako",
+    "Mongolija",
+    "Moldavija",
+    "Crna Gora",
+    "Montserrat",
+    "Maroko",
+    "Mozambik",
+    "Oman",
+    "Namibija",
+    "Nauru",
+    "Nepal",
+    "Nizozemska",
+    "Curaçao",
+    "Aruba",
+    "Sint Maarten",
+    "Karipski otoci Nizozemske",
+    "Nova Kaledonija",
+    "Vanuatu",
+    "Novi Zeland",
+    "Nikaragva",
+    "Niger",
+    "Nigerija",
+    "Niue",
+    "Otok Norfolk",
+    "Norveška",
+    "Sjevernomarijanski Otoci",
+    "Mali udaljeni otoci SAD-a",
+    "Mikronezija",
+    "Maršalovi Otoci",
+    "Palau",
+    "Pakistan",
+    "Panama",
+    "Papua Nova Gvineja",
+    "Paragvaj",
+    "Peru",
+    "Filipini",
+    "Pitcairnovi Otoci",
+    "Poljska",
+    "Portugal",
+    "Gvineja Bisau",
+    "Timor-Leste",
+    "Portoriko",
+    "Katar",
+    "Réunion",
+    "Rumunjska",
+    "Rusija",
+    "Ruanda",
+    "Saint Barthélemy",
+    "Sveta Helena",
+    "Sveti Kristofor i Nevis",
+    "Angvila",
+    "Sveta Lucija",
+    "Saint Martin",
+    "Sveti Petar i Mikelon",
+    "Sveti Vincent i Grenadini",
+    "San Marino",
+    "Sveti Toma i Princip",
+    "Saudijska Arabija",
+    "Senegal",
+    "Srbija",
+    "Sejšeli",
+    "Sijera Leone",
+    "Singapur",
+    "Slovačka",
+    "Vijetnam",
+    "Slovenija",
+    "Somalija",
+    "Južnoafrička Republika",
+    "Zimbabve",
+    "Španjolska",
+    "Južni Sudan",
+    "Sudan",
+    "Zapadna Sahara",
+    "Surinam",
+    "Svalbard i Jan Mayen",
+    "Esvatini",
+    "Švedska",
+    "Švicarska",
+    "Sirija",
+    "Tadžikistan",
+    "Tajland",
+    "Togo",
+    "Tokelau",
+    "Tonga",
+    "Trinidad i Tobago",
+    "Ujedinjeni Arapski Emirati",
+    "Tunis",
+    "Turska",
+    "Turkmenistan",
+    "Otoci Turks i Caicos",
+    "Tuvalu",
+    "Uganda",
+    "Ukrajina",
+    "Sjeverna Makedonija",
+    "Egipat",
+    "Ujedinjeno Kraljevstvo",
+    "Guernsey",
+    "Jersey",
+    "Otok Man",
+    "Tanzanija",
+    "Sjedinjene Američke Države",
+    "Američki Djevičanski Otoci",
+    "Burkina Faso",
+    "Urugvaj",
+    "Uzbekistan",
+    "Venezuela",
+    "Wallis i Futuna",
+    "Samoa",
+    "Jemen",
+    "Zambija",
+];
+
+static HU: [&str; 249] = [
+    "Afganisztán",
+    "Albánia",
+    "Antarktisz",
+    "Algéria",
+    "Amerikai Szamoa",
+    "Andorra",
+    "Angola",
+    "Antigua és Barbuda",
+    "Azerbajdzsán",
+    "Argentína",
+    "Ausztrália",
+    "Ausztria",
+    "Bahama-szigetek",
+    "Bahrein",
+    "Banglades",
+    "Örményország",
+    "Barbados",
+    "Belgium",
+    "Bermuda",
+    "Bhután",
+    "Bolívia",
+    "Bosznia-Hercegovina",
+    "Botswana",
+    "Bouvet-sziget",
+    "Brazília",
+    "Belize",
+    "Brit Indiai-óceáni Terület",
+    "Salamon-szigetek",
+    "Brit Virgin-szigetek",
+    "Brunei",
+    "Bulgária",
+    "Mianmar",
+    "Burundi",
+    "Belarusz",
+    "Kambodzsa",
+    "Kamerun",
+    "Kanada",
+    "Zöld-foki Köztársaság",
+    "Kajmán-szigetek",
+    "Közép-afrikai Köztársaság",
+    "Srí Lanka",
+    "Csád",
+    "Chile",
+    "Kína",
+    "Tajvan",
+    "Karácsony-sziget",
+    "Kókusz (Keeling)-szigetek",
+    "Kolumbia",
+    "Comore-szigetek",
+    "Mayotte",
+    "Kongó – Brazzaville",
+    "Kongó – Kinshasa",
+    "Cook-szigetek",
+    "Costa Rica",
+    "Horvátország",
+    "Kuba",
+    "Ciprus",
+    "Csehország",
+    "Benin",
+    "Dánia",
+    "Dominika",
+    "Dominikai Köztársaság",
+    "Ecuador",
+    "Salvador",
+    "Egyenlítői-Guinea",
+    "Etiópia",
+    "Eritrea",
+    "Észtország",
+    "Feröer szigetek",
+    "Falkland-szigetek",
+    "Déli-Georgia és Déli-Sandwich-szigetek",
+    "Fidzsi",
+    "Finnország",
+    "Åland-szigetek",
+    "Franciaország",
+    "Francia Guyana",
+    "Francia Polinézia",
+    "Francia Déli Területek",
+    "Dzsibuti",
+    "Gabon",
+    "Grúzia",
+    "Gambia",
+    "Palesztin Autonómia",
+    "Németország",
+    "Ghána",
+    "Gibraltár",
+    "Kiribati",
+    "Görögország",
+    "Grönland",
+    "Grenada",
+    "Guadeloupe",
+    "Guam",
+    "Guatemala",
+    "Guinea",
+    "Guyana",
+    "Haiti",
+    "Heard-sziget és McDonald-szigetek",
+    "Vatikán",
+    "Honduras",
+    "Hongkong KKT",
+    "Magyarország",
+    "Izland",
+    "India",
+    "Indonézia",
+    "Irán",
+    "Irak",
+    "Írország",
+    "Izrael",
+    "Olaszország",
+    "Elefántcsontpart",
+    "Jamaica",
+    "Japán",
+    "Kazahsztán",
+    "Jordánia",
+    "Kenya",
+    "Észak-Korea",
+    "Dél-Korea",
+    "Kuvait",
+    "Kirgizisztán",
+    "Laosz",
+    "Libanon",
+    "Lesotho",
+    "Lettország",
+    "Libéria",
+    "Líbia",
+    "Liechtenstein",
+    "Litvánia",
+    "Luxemburg",
+    "Makaó KKT",
+    "Madagaszkár",
+    "Malawi",
+    "Malajzia",
+    "Maldív-szigetek",
+    "Mali",
+    "Málta",
+    "Martinique",
+    "Mauritánia",
+    "Mauritius",
+    "Mexikó",
+    "Monaco",
+    "Mongólia",
+    "Moldova",
+    "Montenegró",
+    "Montserrat",
+    "Marokkó",
+    "Mozambik",
+    "Omán",
+    "Namíbia",
+    "Nauru",
+    "Nepál",
+    "Hollandia",
+    "Curaçao",
+    "Aruba",
+    "Sint Maarten",
+    "Holland Karib-térség",
+    "Új-Kaledónia",
+    "Vanuatu",
+    "Új-Zéland",
+    "Nicaragua",
+    "Niger",
+    "Nigéria",
+    "Niue",
+    "Norfolk-sziget",
+    "Norvégia",
+    "Északi Mariana-szigetek",
+    "Az USA lakatlan külbirtokai",
+    "Mikronézia",
+    "Marshall-szigetek",
+    "Palau",
+    "Pakisztán",
+    "Panama",
+    "Pápua Új-Guinea",
+    "Paraguay",
+    "Peru",
+    "Fülöp-szigetek",
+    "Pitcairn-szigetek",
+    "Lengyelország",
+    "Portugália",
+    "Bissau-Guinea",
+    "Kelet-Timor",
+    "Puerto Rico",
+    "Katar",
+    "Réunion",
+    "Románia",
+    "Oroszország",
+    "Ruanda",
+    "Saint-Barthélemy",
+    "Szent Ilona",
+    "Saint Kitts és Nevis",
+    "Anguilla",
+    "Saint Lucia",
+    "Saint Martin",
+    "Saint-Pierre és Miquelon",
+    "Saint Vincent és a Grenadine-szigetek",
+    "San Marino",
+    "São Tomé és Príncipe",
+    "Szaúd-Arábia",
+    "Szenegál",
+    "Szerbia",
+    "Seychelle-szigetek",
+    "Sierra Leone",
+    "Szingapúr",
+    "Szlovákia",
+    "Vietnám",
+    "Szlovénia",
+    "Szomália",
+    "Dél-afrikai Köztársaság",
+    "Zimbabwe",
+    "Spanyolország",
+    "Dél-Szudán",
+    "Szudán",
+    "Nyugat-Szahara",
+    "Suriname",
+    "Svalbard és Jan Mayen",
+    "Szváziföld",
+    "Svédország",
+    "Svájc",
+    "Szíria",
+    "Tádzsikisztán",
+    "Thaiföld",
+    "Togo",
+    "Tokelau",
+    "Tonga",
+    "Trinidad és Tobago",
+    "Egyesült Arab Emírségek",
+    "Tunézia",
+    "Törökország",
+    "Türkmenisztán",
+    "Turks- és Caicos-szigetek",
+    "Tuvalu",
+    "Uganda",
+    "Ukrajna",
+    "Észak-Macedónia",
+    "Egyiptom",
+    "Egyesült Királyság",
+    "Guernsey",
+    "Jersey",
+    "Man-sziget",
+    "Tanzánia",
+    "Egyesült Államok",
+    "Amerikai Virgin-szigetek",
+    "Burkina Faso",
+    "Uruguay",
+    "Üzbegisztán",
+    "Venezuela",
+    "Wallis és Futuna",
+    "Szamoa",
+    "Jemen",
+    "Zambia",
+];
+
+static ID: [&str; 249] = [
+    "Afganistan",
+    "Albania",
+    "Antarktika",
+    "Aljazair",
+    "Samoa Amerika",
+    "Andorra",
+    "Angola",
+    "Antigua dan Barbuda",
+    "Azerbaijan",
+    "Argentina",
+    "Australia",
+    "Austria",
+    "Bahama",
+    "Bahrain",
+    "Bangladesh",
+    "Armenia",
+    "Barbados",
+    "Belgia",
+    "Bermuda",
+    "Bhutan",
+    "Bolivia",
+    "Bosnia dan Herzegovina",
+    "Botswana",
+    "Pulau Bouvet",
+    "Brasil",
+    "Belize",
+    "Wilayah Inggris di Samudra Hindia",
+    "Kepulauan Solomon",
+    "Kepulauan Virgin Britania Raya",
+    "Brunei",
+    "Bulgaria",
+    "Myanmar (Burma)",
+    "Burundi",
+    "Belarus",
+    "Kamboja",
+    "Kamerun",
+    "Kanada",
+    "Tanjung Verde",
+    "Kepulauan Cayman",
+    "Republik Afrika Tengah",
+    "Sri Lanka",
+    "Chad",
+    "Cile",
+    "Tiongkok",
+    "Taiwan",
+    "Pulau Natal",
+    "Kepulauan Cocos (Keeling)",
+    "Kolombia",
+    "Komoro",
+    "Mayotte",
+    "Kongo - Brazzaville",
+    "Kongo - Kinshasa",
+    "Kepulauan Cook",
+    "Kosta Rika",
+    "Kroasia",
+    "Kuba",
+    "Siprus",
+    "Ceko",
+    "Benin",
+    "Denmark",
+    "Dominika",
+    "Republik Dominika",
+    "Ekuador",
+    "El Salvador",
+    "Guinea Ekuatorial",
+    "Etiopia",
+    "Eritrea",
+    "Estonia",
+    "Kepulauan Faroe",
+    "Kepulauan Falkland",
+    "Georgia Selatan & Kep. Sandwich Selatan",
+    "Fiji",
+    "Finlandia",
+    "Kepulauan Aland",
+    "Prancis",
+    "Guyana Prancis",
+    "Polinesia Prancis",
+    "Wilayah Selatan Prancis",
+    "Jibuti",
+    "Gabon",
+    "Georgia",
+    "Gambia",
+    "Wilayah Palestina",
+    "Jerman",
+    "Ghana",
+    "Gibraltar",
+    "Kiribati",
+    "Yunani",
+    "Greenland",
+    "Grenada",
+    "Guadeloupe",
+    "Guam",
+    "Guatemala",
+    "Guinea",
+    "Guyana",
+    "Haiti",
+    "Pulau Heard dan Kepulauan McDonald",
+    "Vatikan",
+    "Honduras",
+    "Hong Kong DAK Tiongkok",
+    "Hungaria",
+    "Islandia",
+    "India",
+    "Indonesia",
+    "Iran",
+    "Irak",
+    "Irlandia",
+    "Israel",
+    "Italia",
+    "Côte d’Ivoire",
+    "Jamaika",
+    "Jepang",
+    "Kazakhstan",
+    "Yordania",
+    "Kenya",
+    "Korea Utara",
+    "Korea Selatan",
+    "Kuwait",
+    "Kirgizstan",
+    "Laos",
+    "Lebanon",
+    "Lesotho",
+    "Latvia",
+    "Liberia",
+    "Libya",
+    "Liechtenstein",
+    "Lituania",
+    "Luksemburg",
+    "Makau DAK Tiongkok",
+    "Madagaskar",
+    "Malawi",
+    "Malaysia",
+    "Maladewa",
+    "Mali",
+    "Malta",
+    "Martinik",
+    "Mauritania",
+    "Mauritius",
+    "Meksiko",
+    "Monako",
+    "Mongolia",
+    "Moldova",
+    "Montenegro",
+    "Montserrat",
+    "Maroko",
+    "Mozambik",
+    "Oman",
+    "Namibia",
+    "Nauru",
+    "Nepal",
+    "Belanda",
+    "Curaçao",
+    "Aruba",
+    "Sint Maarten",
+    "Belanda Karibia",
+    "Kaledonia Baru",
+    "Vanuatu",
+    "Selandia Baru",
+    "Nikaragua",
+    "Niger",
+    "Nigeria",
+    "Niue",
+    "Kepulauan Norfolk",
+    "Norwegia",
+    "Kepulauan Mariana Utara",
+    "Kepulauan Terluar AS",
+    "Mikronesia",
+    "Kepulauan Marshall",
+    "Palau",
+    "Pakistan",
+    "Panama",
+    "Papua Nugini",
+    "Paraguay",
+    "Peru",
+    "Filipina",
+    "Kepulauan Pitcairn",
+    "Polandia",
+    "Portugal",
+    "Guinea-Bissau",
+    "Timor Leste",
+    "Puerto Riko",
+    "Qatar",
+    "Réunion",
+    "Rumania",
+    "Rusia",
+    "Rwanda",
+    "Saint Barthélemy",
+    "Saint Helena",
+    "Saint Kitts dan Nevis",
+    "Anguilla",
+    "Saint Lucia",
+    "Saint Martin",
+    "Saint Pierre dan Miquelon",
+    "Saint Vincent dan Grenadine",
+    "San Marino",
+    "Sao Tome dan Principe",
+    "Arab Saudi",
+    "Senegal",
+    "Serbia",
+    "Seychelles",
+    "Sierra Leone",
+    "Singapura",
+    "Slovakia",
+    "Vietnam",
+    "Slovenia",
+    "Somalia",
+    "Afrika Selatan",
+    "Zimbabwe",
+    "Spanyol",
+    "Sudan Selatan",
+    "Sudan",
+    "Sahara Barat",
+    "Suriname",
+    "Kepulauan Svalbard dan Jan Mayen",
+    "eSwatini",
+    "Swedia",
+    "Swiss",
+    "Suriah",
+    "Tajikistan",
+    "Thailand",
+    "Togo",
+    "Tokelau",
+    "Tonga",
+    "Trinidad dan Tobago",
+    "Uni Emirat Arab",
+    "Tunisia",
+    "Turki",
+    "Turkmenistan",
+    "Kepulauan Turks dan Caicos",
+    "Tuvalu",
+    "Uganda",
+    "Ukraina",
+    "Makedonia Utara",
+    "Mesir",
+    "Inggris Raya",
+    "Guernsey",
+    "Jersey",
+    "Pulau Man",
+    "Tanzania",
+    "Amerika Serikat",
+    "Kepulauan Virgin Amerika Serikat",
+    "Burkina Faso",
+    "Uruguay",
+    "Uzbekistan",
+    "Venezuela",
+    "Kepulauan Wallis dan Futuna",
+    "Samoa",
+    "Yaman",
+    "Zambia",
+];
+
+static IT: [&str; 249] = [
+    "Afghanistan",
+    "Albania",
+    "Antartide",
+    "Algeria",
+    "Samoa Americane",
+    "Andorra",
+    "Angola",
+    "Antigua e Barbuda",
+    "Azerbaigian",
+    "Argentina",
+    "Australia",
+    "Austria",
+    "Bahamas",
+    "Bahrein",
+    "Bangladesh",
+    "Armenia",
+    "Barbados",
+    "Belgio",
+    "Bermuda",
+    "Bhutan",
+    "Bolivia",
+    "Bosnia ed Erzegovina",
+    "Botswana",
+    "Isola Bouvet",
+    "Brasile",
+    "Belize",
+    "Territorio Britannico dell’Oceano Indiano",
+    "Isole Salomone",
+    "Isole Vergini Britanniche",
+    "Brunei",
+    "Bulgaria",
+    "Myanmar (Birmania)",
+    "Burundi",
+    "Bielorussia",
+    "Cambogia",
+    "Camerun",
+    "Canada",
+    "Capo Verde",
+    "Isole Cayman",
+    "Repubblica Centrafricana",
+    "Sri Lanka",
+    "Ciad",
+    "Cile",
+    "Cina",
+    "Taiwan",
+    "Isola Christmas",
+    "Isole Cocos (Keeling)",
+    "Colombia",
+    "Comore",
+    "Mayotte",
+    "Congo-Brazzaville",
+    "Congo - Kinshasa",
+    "Isole Cook",
+    "Costa Rica",
+    "Croazia",
+    "Cuba",
+    "Cipro",
+    "Cechia",
+    "Benin",
+    "Danimarca",
+    "Dominica",
+    "Repubblica Dominicana",
+    "Ecuador",
+    "El Salvador",
+    "Guinea Equatoriale",
+    "Etiopia",
+    "Eritrea",
+    "Estonia",
+    "Isole Fær Øer",
+    "Isole Falkland",
+    "Georgia del Sud e Sandwich Australi",
+    "Figi",
+    "Finlandia",
+    "Isole Åland",
+    "Francia",
+    "Guyana Francese",
+    "Polinesia Francese",
+    "Terre Australi Francesi",
+    "Gibuti",
+    "Gabon",
+    "Georgia",
+    "Gambia",
+    "Territori Palestinesi",
+    "Germania",
+    "Ghana",
+    "Gibilterra",
+    "Kiribati",
+    "Grecia",
+    "Groenlandia",
+    "Grenada",
+    "Guadalupa",
+    "Guam",
+    "Guatemala",
+    "Guinea",
+    "Guyana",
+    "Haiti",
+    "Isole Heard e McDonald",
+    "Città del Vaticano",
+    "Honduras",
+    "RAS di Hong Kong",
+    "Ungheria",
+    "Islanda",
+    "India",
+    "Indonesia",
+    "Iran",
+    "Iraq",
+    "Irlanda",
+    "Israele",
+    "Italia",
+    "Costa d’Avorio",
+    "Giamaica",
+    "Giappone",
+    "Kazakistan",
+    "Giordania",
+    "Kenya",
+    "Corea del Nord",
+    "Corea del Sud",
+    "Kuwait",
+    "Kirghizistan",
+    "Laos",
+    "Libano",
+    "Lesotho",
+    "Lettonia",
+    "Liberia",
+    "Libia",
+    "Liechtenstein",
+    "Lituania",
+    "Lussemburgo",
+    "RAS di Macao",
+    "Madagascar",
+    "Malawi",
+    "Malaysia",
+    "Maldive",
+    "Mali",
+    "Malta",
+    "Martinica",
+    "Mauritania",
+    "Mauritius",
+    "Messico",
+    "Monaco",
+    "Mongolia",
+    "Moldavia",
+    "Montenegro",
+    "Montserrat",
+    "Marocco",
+    "Mozambico",
+    "Oman",
+    "Namibia",
+    "Nauru",
+    "Nepal",
+    "Paesi Bassi",
+    "Curaçao",
+    "Aruba",
+    "Sint Maarten",
+    "Caraibi Olandesi",
+    "Nuova Caledonia",
+    "Vanuatu",
+    "Nuova Zelanda",
+    "Nicaragua",
+    "Niger",
+    "Nigeria",
+    "Niue",
+    "Isola Norfolk",
+    "Norvegia",
+    "Isole Marianne Settentrionali",
+    "Isole Minori Esterne degli Stati Uniti",
+    "Micronesia",
+    "Isole Marshall",
+    "Palau",
+    "Pakistan",
+    "Panama",
+    "Papua Nuova Guinea",
+    "Paraguay",
+    "Perù",
+    "Filippine",
+    "Isole Pitcairn",
+    "Polonia",
+    "Portogallo",
+    "Guinea-Bissau",
+    "Timor Est",
+    "Portorico",
+    "Qatar",
+    "Riunione",
+    "Romania",
+    "Russia",
+    "Ruanda",
+    "Saint-Barthélemy",
+    "Sant’Elena",
+    "Saint Kitts e Nevis",
+    "Anguilla",
+    "Saint Lucia",
+    "Saint Martin",
+    "Saint-Pierre e Miquelon",
+    "Saint Vincent e Grenadine",
+    "San Marino",
+    "São Tomé e Príncipe",
+    "Arabia Saudita",
+    "Senegal",
+    "Serbia",
+    "Seychelles",
+    "Sierra Leone",
+    "Singapore",
+    "Slovacchia",
+    "Vietnam",
+    "Slovenia",
+    "Somalia",
+    "Sudafrica",
+    "Zimbabwe",
+    "Spagna",
+    "Sud Sudan",
+    "Sudan",
+    "Sahara Occidentale",
+    "Suriname",
+    "Svalbard e Jan Mayen",
+    "Eswatini",
+    "Svezia",
+    "Svizzera",
+    "Siria",
+    "Tagikistan",
+    "Thailandia",
+    "Togo",
+    "Tokelau",
+    "Tonga",
+    "Trinidad e Tobago",
+    "Emirati Arabi Uniti",
+    "Tunisia",
+    "Turchia",
+    "Turkmenistan",
+    "Isole Turks e Caicos",
+    "Tuvalu",
+    "Uganda",
+    "Ucraina",
+    "Macedonia del Nord",
+    "Egitto",
+    "Regno Unito",
+    "Guernsey",
+    "Jersey",
+    "Isola di Man",
+    "Tanzania",
+    "Stati Uniti",
+    "Isole Vergini Americane",
+    "Burkina Faso",
+    "Uruguay",
+    "Uzbekistan",
+    "Venezuela",
+    "Wallis e Futuna",
+    "Samoa",
+    "Yemen",
+    "Zambia",
+];
+
+static JA: [&str; 249] = [
+    "アフガニスタン",
+    "アルバニア",
+    "南極",
+    "アルジェリア",
+    "米領サモア",
+    "アンドラ",
+    "アンゴラ",
+    "アンティグア・バーブーダ",
+    "アゼルバイジャン",
+    "アルゼンチン",
+    "オーストラリア",
+    "オーストリア",
+    "バハマ",
+    "バーレーン",
+    "バングラデシュ",
+    "アルメニア",
+    "バルバドス",
+    "ベルギー",
+    "バミューダ",
+    "ブータン",
+    "ボリビア",
+    "ボスニア・ヘルツェゴビナ",
+    "ボツワナ",
+    "ブーベ島",
+    "ブラジル",
+    "ベリーズ",
+    "英領インド洋地域",
+    "ソロモン諸島",
+    "英領ヴァージン諸島",
+    "ブルネイ",
+    "ブルガリア",
+    "ミャンマー (ビルマ)",
+    "ブルンジ",
+    "ベラルーシ",
+    "カンボジア",
+    "カメルーン",
+    "カナダ",
+    "カーボベルデ",
+    "ケイマン諸島",
+    "中央アフリカ共和国",
+    "スリランカ",
+    "チャド",
+    "チリ",
+    "中国",
+    "台湾",
+    "クリスマス島",
+    "ココス(キーリング)諸島",
+    "コロンビア",
+    "コモロ",
+    "マヨット",
+    "コンゴ共和国(ブラザビル)",
+    "コンゴ民主共和国(キンシャサ)",
+    "クック諸島",
+    "コスタリカ",
+    "クロアチア",
+    "キューバ",
+    "キプロス",
+    "チェコ",
+    "ベナン",
+    "デンマーク",
+    "ドミニカ国",
+    "ドミニカ共和国",
+    "エクアドル",
+    "エルサルバドル",
+    "赤道ギニア",
+    "エチオピア",
+    "エリトリア",
+    "エストニア",
+    "フェロー諸島",
+    "フォークランド諸島",
+    "サウスジョージア・サウスサンドウィッチ諸島",
+    "フィジー",
+    "フィンランド",
+    "オーランド諸島",
+    "フランス",
+    "仏領ギアナ",
+    "仏領ポリネシア",
+    "仏領極南諸島",
+    "ジブチ",
+    "ガボン",
+    "ジョージア",
+    "ガンビア",
+    "パレスチナ自治区",
+    "ドイツ",
+    "ガーナ",
+    "ジブラルタル",
+    "キリバス",
+    "ギリシャ",
+    "グリーンランド",
+    "グレナダ",
+    "グアドループ",
+    "グアム",
+    "グアテマラ",
+    "ギニア",
+    "ガイアナ",
+    "ハイチ",
+    "ハード島・マクドナルド諸島",
+    "バチカン市国",
+    "ホンジュラス",
+    "中華人民共和国香港特別行政区",
+    "ハンガリー",
+    "アイスランド",
+    "インド",
+    "インドネシア",
+    "イラン",
+    "イラク",
+    "アイルランド",
+    "イスラエル",
+    "イタリア",
+    "コートジボワール",
+    "ジャマイカ",
+    "日本",
+    "カザフスタン",
+    "ヨルダン",
+    "ケニア",
+    "北朝鮮",
+    "韓国",
+    "クウェート",
+    "キルギス",
+    "ラオス",
+    "レバノン",
+    "レソト",
+    "ラトビア",
+    "リベリア",
+    "リビア",
+    "リヒテンシュタイン",
+    "リトアニア",
+    "ルクセンブルク",
+    "中華人民共和国マカオ特別行政区",
+    "マダガスカル",
+    "マラウイ",
+    "マレーシア",
+    "モルディブ",
+    "マリ",
+    "マルタ",
+    "マルティニーク",
+    "モーリタニア",
+    "モーリシャス",
+    "メキシコ",
+    "モナコ",
+    "モンゴル",
+    "モルドバ",
+    "モンテネグロ",
+    "モントセラト",
+    "モロッコ",
+    "モザンビーク",
+    "オマーン",
+    "ナミビア",
+    "ナウル",
+    "ネパール",
+    "オランダ",
+    "キュラソー",
+    "アルバ",
+    "シント・マールテン",
+    "オランダ領カリブ",
+    "ニューカレドニア",
+    "バヌアツ",
+    "ニュージーランド",
+    "ニカラグア",
+    "ニジェール",
+    "ナイジェリア",
+    "ニウエ",
+    "ノーフォーク島",
+    "ノルウェー",
+    "北マリアナ諸島",
+    "合衆国領有小離島",
+    "ミクロネシア連邦",
+    "マーシャル諸島",
+    "パラオ",
+    "パキスタン",
+    "パナマ",
+    "パプアニューギニア",
+    "パラグアイ",
+    "ペルー",
+    "フィリピン",
+    "ピトケアン諸島",
+    "ポーランド",
+    "ポルトガル",
+    "ギニアビサウ",
+    "東ティモール",
+    "プエルトリコ",
+    "カタール",
+    "レユニオン",
+    "ルーマニア",
+    "ロシア",
+    "ルワンダ",
+    "サン・バルテルミー",
+    "セントヘレナ",
+    "セントクリストファー・ネーヴィス",
+    "アンギラ",
+    "セントルシア",
+    "サン・マルタン",
+    "サンピエール島・ミクロン島",
+    "セントビンセント及びグレナディーン諸島",
+    "サンマリノ",
+    "サントメ・プリンシペ",
+    "サウジアラビア",
+    "セネガル",
+    "セルビア",
+    "セーシェル",
+    "シエラレオネ",
+    "シンガポール",
+    "スロバキア",
+    "ベトナム",
+    "スロベニア",
+    "ソマリア",
+    "南アフリカ",
+    "ジンバブエ",
+    "スペイン",
+    "南スーダン",
+    "スーダン",
+    "西サハラ",
+    "スリナム",
+    "スバールバル諸島・ヤンマイエン島",
+    "エスワティニ",
+    "スウェーデン",
+    "スイス",
+    "シリア",
+    "タジキスタン",
+    "タイ",
+    "トーゴ",
+    "トケラウ",
+    "トンガ",
+    "トリニダード・トバゴ",
+    "アラブ首長国連邦",
+    "チュニジア",
+    "トルコ",
+    "トルクメニスタン",
+    "タークス・カイコス諸島",
+    "ツバル",
+    "ウガンダ",
+    "ウクライナ",
+    "北マケドニア",
+    "エジプト",
+    "イギリス",
+    "ガーンジー",
+    "ジャージー",
+    "マン島",
+    "タンザニア",
+    "アメリカ合衆国",
+    "米領ヴァージン諸島",
+    "ブルキナファソ",
+    "ウルグアイ",
+    "ウズベキスタン",
+    "ベネズエラ",
+    "ウォリス・フツナ",
+    "サモア",
+    "イエメン",
+    "ザンビア",
+];
+
+static KO: [&str; 249] = [
+    "아프가니스탄",
+    "알바니아",
+    "남극 대륙",
+    "알제리",
+    "아메리칸 사모아",
+    "안도라",
+    "앙골라",
+    "앤티가 바부다",
+    "아제르바이잔",
+    "아르헨티나",
+    "오스트레일리아",
+    "오스트리아",
+    "바하마",
+    "바레인",
+    "방글라데시",
+    "아르메니아",
+    "바베이도스",
+    "벨기에",
+    "버뮤다",
+    "부탄",
+    "볼리비아",
+    "보스니아 헤르체고비나",
+    "보츠와나",
+    "부베섬",
+    "브라질",
+    "벨리즈",
+    "영국령 인도양 지역",
+    "솔로몬 제도",
+    "영국령 버진아일랜드",
+    "브루나이",
+    "불가리아",
+    "미얀마",
+    "부룬디",
+    "벨라루스",
+    "캄보디아",
+    "카메룬",
+    "캐나다",
+    "카보베르데",
+    "케이맨 제도",
+    "중앙 아프리카 공화국",
+    "스리랑카",
+    "차드",
+    "칠레",
+    "중국",
+    "대만",
+    "크리스마스섬",
+    "코코스 제도",
+    "콜롬비아",
+    "코모로",
+    "마요트",
+    "콩고-브라자빌",
+    "콩고-킨샤사",
+    "쿡 제도",
+    "코스타리카",
+    "크로아티아",
+    "쿠바",
+    "키프로스",
+    "체코",
+    "베냉",
+    "덴마크",
+    "도미니카",
+    "도미니카 공화국",
+    "에콰도르",
+    "엘살바도르",
+    "적도 기니",
+    "에티오피아",
+    "에리트리아",
+    "에스토니아",
+    "페로 제도",
+    "포클랜드 제도",
+    "사우스조지아 사우스샌드위치 제도",
+    "피지",
+    "핀란드",
+    "올란드 제도",
+    "프랑스",
+    "프랑스령 기아나",
+    "프랑스령 폴리네시아",
+    "프랑스령 남방 지역",
+    "지부티",
+    "가봉",
+    "조지아",
+    "감비아",
+    "팔레스타인 지구",
+    "독일",
+    "가나",
+    "지브롤터",
+    "키리바시",
+    "그리스",
+    "그린란드",
+    "그레나다",
+    "과들루프",
+    "괌",
+    "과테말라",
+    "기니",
+    "가이아나",
+    "아이티",
+    "허드 맥도널드 제도",
+    "바티칸 시국",
+    "온두라스",
+    "홍콩(중국 특별행정구)",
+    "헝가리",
+    "아이슬란드",
+    "인도",
+    "인도네시아",
+    "이란",
+    "이라크",
+    "아일랜드",
+    "이스라엘",
+    "이탈리아",
+    "코트디부아르",
+    "자메이카",
+    "일본",
+    "카자흐스탄",
+    "요르단",
+    "케냐",
+    "북한",
+    "대한민국",
+    "쿠웨이트",
+    "키르기스스탄",
+    "라오스",
+    "레바논",
+    "레소토",
+    "라트비아",
+    "라이베리아",
+    "리비아",
+    "리히텐슈타인",
+    "리투아니아",
+    "룩셈부르크",
+    "마카오(중국 특별행정구)",
+    "마다가스카르",
+    "말라위",
+    "말레이시아",
+    "몰디브",
+    "말리",
+    "몰타",
+    "마르티니크",
+    "모리타니",
+    "모리셔스",
+    "멕시코",
+    "모나코",
+    "몽골",
+    "몰도바",
+    "몬테네그로",
+    "몬트세라트",
+    "모로코",
+    "모잠비크",
+    "오만",
+    "나미비아",
+    "나우루",
+    "네팔",
+    "네덜란드",
+    "퀴라소",
+    "아루바",
+    "신트마르턴",
+    "네덜란드령 카리브",
+    "뉴칼레도니아",
+    "바누아투",
+    "뉴질랜드",
+    "니카라과",
+    "니제르",
+    "나이지리아",
+    "니우에",
+    "노퍽섬",
+    "노르웨이",
+    "북마리아나제도",
+    "미국령 해외 제도",
+    "미크로네시아",
+    "마셜 제도",
+    "팔라우",
+    "파키스탄",
+    "파나마",
+    "파푸아뉴기니",
+    "파라과이",
+    "페루",
+    "필리핀",
+    "핏케언 제도",
+    "폴란드",
+    "포르투갈",
+    "기니비사우",
+    "동티모르",
+    "푸에르토리코",
+    "카타르",
+    "레위니옹",
+    "루마니아",
+    "러시아",
+    "르완다",
+    "생바르텔레미",
+    "세인트헬레나",
+    "세인트키츠 네비스",
+    "앵귈라",
+    "세인트루시아",
+    "생마르탱",
+    "생피에르 미클롱",
+    "세인트빈센트그레나딘",
+    "산마리노",
+    "상투메 프린시페",
+    "사우디아라비아",
+    "세네갈",
+    "세르비아",
+    "세이셸",
+    "시에라리온",
+    "싱가포르",
+    "슬로바키아",
+    "베트남",
+    "슬로베니아",
+    "소말리아",
+    "남아프리카",
+    "짐바브웨",
+    "스페인",
+    "남수단",
+    "수단",
+    "서사하라",
+    "수리남",
+    "스발바르제도-얀마웬섬",
+    "에스와티니",
+    "스웨덴",
+    "스위스",
+    "시리아",
+    "타지키스탄",
+    "태국",
+    "토고",
+    "토켈라우",
+    "통가",
+    "트리니다드 토바고",
+    "아랍에미리트",
+    "튀니지",
+    "튀르키예",
+    "투르크메니스탄",
+    "터크스 케이커스 제도",
+    "투발루",
+    "우간다",
+    "우크라이나",
+    "북마케도니아",
+    "이집트",
+    "영국",
+    "건지",
+    "저지",
+    "맨섬",
+    "탄자니아",
+    "미국",
+    "미국령 버진아일랜드",
+    "부르키나파소",
+    "우루과이",
+    "우즈베키스탄",
+    "베네수엘라",
+    "왈리스-푸투나 제도",
+    "사모아",
+    "예멘",
+    "잠비아",
+];
+
+static LT: [&str; 249] = [
+    "Afganistanas",
+    "Albanija",
+    "Antarktida",
+    "Alžyras",
+    "Amerikos Samoa",
+    "Andora",
+    "Angola",
+    "Antigva ir Barbuda",
+    "Azerbaidžanas",
+    "Argentina",
+    "Australija",
+    "Austrija",
+    "Bahamos",
+    "Bahreinas",
+    "Bangladešas",
+    "Armėnija",
+    "Barbadosas",
+    "Belgija",
+    "Bermuda",
+    "Butanas",
+    "Bolivija",
+    "Bosnija ir Hercegovina",
+    "Botsvana",
+    "Buvė Sala",
+    "Brazilija",
+    "Belizas",
+    "Indijos Vandenyno Britų Sritis",
+    "Saliamono Salos",
+    "Didžiosios Britanijos Mergelių Salos",
+    "Brunėjus",
+    "Bulgarija",
+    "Mianmaras (Birma)",
+    "Burundis",
+    "Baltarusija",
+    "Kambodža",
+    "Kamerūnas",
+    "Kanada",
+    "Žaliasis Kyšulys",
+    "Kaimanų Salos",
+    "Centrinės Afrikos Respublika",
+    "Šri Lanka",
+    "Čadas",
+    "Čilė",
+    "Kinija",
+    "Taivanas",
+    "Kalėdų Sala",
+    "Kokosų (Kilingo) Salos",
+    "Kolumbija",
+    "Komorai",
+    "Majotas",
+    "Kongas-Brazavilis",
+    "Kongas-Kinšasa",
+    "Kuko Salos",
+    "Kosta Rika",
+    "Kroatija",
+    "Kuba",
+    "Kipras",
+    "Čekija",
+    "Beninas",
+    "Danija",
+    "Dominika",
+    "Dominikos Respublika",
+    "Ekvadoras",
+    "Salvadoras",
+    "Pusiaujo Gvinėja",
+    "Etiopija",
+    "Eritrėja",
+    "Estija",
+    "Farerų Salos",
+    "Folklando Salos",
+    "Pietų Džordžija ir Pietų Sandvičo salos",
+    "Fidžis",
+    "Suomija",
+    "Alandų Salos",
+    "Prancūzija",
+    "Prancūzijos Gviana",
+    "Prancūzijos Polinezija",
+    "Prancūzijos Pietų sritys",
+    "Džibutis",
+    "Gabonas",
+    "Gruzija",
+    "Gambija",
+    "Palestinos teritorija",
+    "Vokietija",
+    "Gana",
+    "Gibraltaras",
+    "Kiribatis",
+    "Graikija",
+    "Grenlandija",
+    "Grenada",
+    "Gvadelupa",
+    "Guamas",
+    "Gvatemala",
+    "Gvinėja",
+    "Gajana",
+    "Haitis",
+    "Herdo ir Makdonaldo Salos",
+    "Vatikano Miesto Valstybė",
+    "Hondūras",
+    "Ypatingasis Administracinis Kinijos Regionas Honkongas",
+    "Vengrija",
+    "Islandija",
+    "Indija",
+    "Indonezija",
+    "Iranas",
+    "Irakas",
+    "Airija",
+    "Izraelis",
+    "Italija",
+    "Dramblio Kaulo Krantas",
+    "Jamaika",
+    "Japonija",
+    "Kazachstanas",
+    "Jordanija",
+    "Kenija",
+    "Šiaurės Korėja",
+    "Pietų Korėja",
+    "Kuveitas",
+    "Kirgizija",
+    "Laosas",
+    "Libanas",
+    "Lesotas",
+    "Latvija",
+    "Liberija",
+    "Libija",
+    "Lichtenšteinas",
+    "Lietuva",
+    "Liuksemburgas",
+    "Ypatingasis Administracinis Kinijos Regionas Makao",
+    "Madagaskaras",
+    "Malavis",
+    "Malaizija",
+    "Maldyvai",
+    "Malis",
+    "Malta",
+    "Martinika",
+    "Mauritanija",
+    "Mauricijus",
+    "Meksika",
+    "Monakas",
+    "Mongolija",
+    "Moldova",
+    "Juodkalnija",
+    "Montseratas",
+    "Marokas",
+    "Mozambikas",
+    "Omanas",
+    "Namibija",
+    "Nauru",
+    "Nepalas",
+    "Nyderlandai",
+    "Kiurasao",
+    "Aruba",
+    "Sint Martenas",
+    "Karibų Nyderlandai",
+    "Naujoji Kaledonija",
+    "Vanuatu",
+    "Naujoji Zelandija",
+    "Nikaragva",
+    "Nigeris",
+    "Nigerija",
+    "Niujė",
+    "Norfolko sala",
+    "Norvegija",
+    "Marianos Šiaurinės Salos",
+    "Jungtinių Valstijų Mažosios Tolimosios Salos",
+    "Mikronezija",
+    "Maršalo Salos",
+    "Palau",
+    "Pakistanas",
+    "Panama",
+    "Papua Naujoji Gvinėja",
+    "Paragvajus",
+    "Peru",
+    "Filipinai",
+    "Pitkerno salos",
+    "Lenkija",
+    "Portugalija",
+    "Bisau Gvinėja",
+    "Rytų Timoras",
+    "Puerto Rikas",
+    "Kataras",
+    "Reunjonas",
+    "Rumunija",
+    "Rusija",
+    "Ruanda",
+    "Sen Bartelemi",
+    "Šv. Elenos Sala",
+    "Sent Kitsas ir Nevis",
+    "Angilija",
+    "Sent Lusija",
+    "Sen Martenas",
+    "Sen Pjeras ir Mikelonas",
+    "Šventasis Vincentas ir Grenadinai",
+    "San Marinas",
+    "San Tomė ir Prinsipė",
+    "Saudo Arabija",
+    "Senegalas",
+    "Serbija",
+    "Seišeliai",
+    "Siera Leonė",
+    "Singapūras",
+    "Slovakija",
+    "Vietnamas",
+    "Slovėnija",
+    "Somalis",
+    "Pietų Afrika",
+    "Zimbabvė",
+    "Ispanija",
+    "Pietų Sudanas",
+    "Sudanas",
+    "Vakarų Sachara",
+    "Surinamas",
+    "Svalbardas ir Janas Majenas",
+    "Svazilandas",
+    "Švedija",
+    "Šveicarija",
+    "Sirija",
+    "Tadžikija",
+    "Tailandas",
+    "Togas",
+    "Tokelau",
+    "Tonga",
+    "Trinidadas ir Tobagas",
+    "Jungtiniai Arabų Emyratai",
+    "Tunisas",
+    "Turkija",
+    "Turkmėnistanas",
+    "Terkso ir Kaikoso Salos",
+    "Tuvalu",
+    "Uganda",
+    "Ukraina",
+    "Šiaurės Makedonija",
+    "Egiptas",
+    "Jungtinė Karalystė",
+    "Gernsis",
+    "Džersis",
+    "Meno Sala",
+    "Tanzanija",
+    "Jungtinės Valstijos",
+    "Jungtinių Valstijų Mergelių Salos",
+    "Burkina Fasas",
+    "Urugvajus",
+    "Uzbekistanas",
+    "Venesuela",
+    "Volisas ir Futūna",
+    "Samoa",
+    "Jemenas",
+    "Zambija",
+];
+
+static LV: [&str; 249] = [
+    "Afganistāna",
+    "Albānija",
+    "Antarktika",
+    "Alžīrija",
+    "ASV Samoa",
+    "Andora",
+    "Angola",
+    "Antigva un Barbuda",
+    "Azerbaidžāna",
+    "Argentīna",
+    "Austrālija",
+    "Austrija",
+    "Bahamu salas",
+    "Bahreina",
+    "Bangladeša",
+    "Armēnija",
+    "Barbadosa",
+    "Beļģija",
+    "Bermudu salas",
+    "Butāna",
+    "Bolīvija",
+    "Bosnija un Hercegovina",
+    "Botsvāna",
+    "Buvē sala",
+    "Brazīlija",
+    "Beliza",
+    "Indijas okeāna Britu teritorija",
+    "Zālamana salas",
+    "Britu Virdžīnas",
+    "Bruneja",
+    "Bulgārija",
+    "Mjanma (Birma)",
+    "Burundija",
+    "Baltkrievija",
+    "Kambodža",
+    "Kamerūna",
+    "Kanāda",
+    "Kaboverde",
+    "Kaimanu salas",
+    "Centrālāfrikas Republika",
+    "Šrilanka",
+    "Čada",
+    "Čīle",
+    "Ķīna",
+    "Taivāna",
+    "Ziemsvētku sala",
+    "Kokosu (Kīlinga) salas",
+    "Kolumbija",
+    "Komoru salas",
+    "Majota",
+    "Kongo (Brazavila)",
+    "Kongo (Kinšasa)",
+    "Kuka salas",
+    "Kostarika",
+    "Horvātija",
+    "Kuba",
+    "Kipra",
+    "Čehija",
+    "Benina",
+    "Dānija",
+    "Dominika",
+    "Dominikāna",
+    "Ekvadora",
+    "Salvadora",
+    "Ekvatoriālā Gvineja",
+    "Etiopija",
+    "Eritreja",
+    "Igaunija",
+    "Fēru salas",
+    "Folklenda salas",
+    "Dienviddžordžija un Dienvidsendviču salas",
+    "Fidži",
+    "Somija",
+    "Olandes salas",
+    "Francija",
+    "Francijas Gviāna",
+    "Francijas Polinēzija",
+    "Francijas Dienvidjūru teritorija",
+    "Džibutija",
+    "Gabona",
+    "Gruzija",
+    "Gambija",
+    "Palestīnas teritorijas",
+    "Vācija",
+    "Gana",
+    "Gibraltārs",
+    "Kiribati",
+    "Grieķija",
+    "Grenlande",
+    "Grenāda",
+    "Gvadelupa",
+    "Guama",
+    "Gvatemala",
+    "Gvineja",
+    "Gajāna",
+    "Haiti",
+    "Hērda sala un Makdonalda salas",
+    "Vatikāns",
+    "Hondurasa",
+    "Ķīnas īpašās pārvaldes apgabals Honkonga",
+    "Ungārija",
+    "Islande",
+    "Indija",
+    "Indonēzija",
+    "Irāna",
+    "Irāka",
+    "Īrija",
+    "Izraēla",
+    "Itālija",
+    "Kotdivuāra",
+    "Jamaika",
+    "Japāna",
+    "Kazahstāna",
+    "Jordānija",
+    "Kenija",
+    "Ziemeļkoreja",
+    "Dienvidkoreja",
+    "Kuveita",
+    "Kirgizstāna",
+    "Laosa",
+    "Libāna",
+    "Lesoto",
+    "Latvija",
+    "Libērija",
+    "Lībija",
+    "Lihtenšteina",
+    "Lietuva",
+    "Luksemburga",
+    "ĶTR īpašais administratīvais reģions Makao",
+    "Madagaskara",
+    "Malāvija",
+    "Malaizija",
+    "Maldīvija",
+    "Mali",
+    "Malta",
+    "Martinika",
+    "Mauritānija",
+    "Maurīcija",
+    "Meksika",
+    "Monako",
+    "Mongolija",
+    "Moldova",
+    "Melnkalne",
+    "Montserrata",
+    "Maroka",
+    "Mozambika",
+    "Omāna",
+    "Namībija",
+    "Nauru",
+    "Nepāla",
+    "Nīderlande",
+    "Kirasao",
+    "Aruba",
+    "Sintmārtena",
+    "Nīderlandes Karību salas",
+    "Jaunkaledonija",
+    "Vanuatu",
+    "Jaunzēlande",
+    "Nikaragva",
+    "Nigēra",
+    "Nigērija",
+    "Niue",
+    "Norfolkas sala",
+    "Norvēģija",
+    "Ziemeļu Marianas salas",
+    "ASV Mazās Aizjūras salas",
+    "Mikronēzija",
+    "Māršala salas",
+    "Palau",
+    "Pakistāna",
+    "Panama",
+    "Papua-Jaungvineja",
+    "Paragvaja",
+    "Peru",
+    "Filipīnas",
+    "Pitkērnas salas",
+    "Polija",
+    "Portugāle",
+    "Gvineja-Bisava",
+    "Austrumtimora",
+    "Puertoriko",
+    "Katara",
+    "Reinjona",
+    "Rumānija",
+    "Krievija",
+    "Ruanda",
+    "Senbartelmī",
+    "Sv.Helēnas sala",
+    "Sentkitsa un Nevisa",
+    "Angilja",
+    "Sentlūsija",
+    "Senmartēna",
+    "Senpjēra un Mikelona",
+    "Sentvinsenta un Grenadīnas",
+    "Sanmarīno",
+    "Santome un Prinsipi",
+    "Saūda Arābija",
+    "Senegāla",
+    "Serbija",
+    "Seišelu salas",
+    "Sjerraleone",
+    "Singapūra",
+    "Slovākija",
+    "Vjetnama",
+    "Slovēnija",
+    "Somālija",
+    "Dienvidāfrikas Republika",
+    "Zimbabve",
+    "Spānija",
+    "Dienvidsudāna",
+    "Sudāna",
+    "Rietumsahāra",
+    "Surinama",
+    "Svalbāra un Jana Majena sala",
+    "Svatini",
+    "Zviedrija",
+    "Šveice",
+    "Sīrija",
+    "Tadžikistāna",
+    "Taizeme",
+    "Togo",
+    "Tokelau",
+    "Tonga",
+    "Trinidāda un Tobāgo",
+    "Apvienotie Arābu Emirāti",
+    "Tunisija",
+    "Turcija",
+    "Turkmenistāna",
+    "Tērksas un Kaikosas salas",
+    "Tuvalu",
+    "Uganda",
+    "Ukraina",
+    "Ziemeļmaķedonija",
+    "Ēģipte",
+    "Apvienotā Karaliste",
+    "Gērnsija",
+    "Džērsija",
+    "Menas sala",
+    "Tanzānija",
+    "Amerikas Savienotās Valstis",
+    "ASV Virdžīnas",
+    "Burkinafaso",
+    "Urugvaja",
+    "Uzbekistāna",
+    "Venecuēla",
+    "Volisa un Futunas salas",
+    "Samoa",
+    "Jemena",
+    "Zambija",
+];
+
+static MS: [&str; 249] = [
+    "Afghanistan",
+    "Albania",
+    "Antartika",
+    "Algeria",
+    "Samoa Amerika",
+    "Andorra",
+    "Angola",
+    "Antigua dan Barbuda",
+    "Azerbaijan",
+    "Argentina",
+    "Australia",
+    "Austria",
+    "Bahamas",
+    "Bahrain",
+    "Bangladesh",
+    "Armenia",
+    "Barbados",
+    "Belgium",
+    "Bermuda",
+    "Bhutan",
+    "Bolivia",
+    "Bosnia dan Herzegovina",
+    "Botswana",
+    "Pulau Bouvet",
+    "Brazil",
+    "Belize",
+    "Wilayah Lautan Hindi British",
+    "Kepulauan Solomon",
+    "Kepulauan Virgin British",
+    "Brunei",
+    "Bulgaria",
+    "Myanmar (Burma)",
+    "Burundi",
+    "Belarus",
+    "Kemboja",
+    "Cameroon",
+    "Kanada",
+    "Cape Verde",
+    "Kepulauan Cayman",
+    "Republik Afrika Tengah",
+    "Sri Lanka",
+    "Chad",
+    "Chile",
+    "China",
+    "Taiwan",
+    "Pulau Krismas",
+    "Kepulauan Cocos (Keeling)",
+    "Colombia",
+    "Comoros",
+    "Mayotte",
+    "Congo - Brazzaville",
+    "Congo - Kinshasa",
+    "Kepulauan Cook",
+    "Costa Rica",
+    "Croatia",
+    "Cuba",
+    "Cyprus",
+    "Czechia",
+    "Benin",
+    "Denmark",
+    "Dominica",
+    "Republik Dominica",
+    "Ecuador",
+    "El Salvador",
+    "Guinea Khatulistiwa",
+    "Ethiopia",
+    "Eritrea",
+    "Estonia",
+    "Kepulauan Faroe",
+    "Kepulauan Falkland",
+    "Kepulauan Georgia Selatan & Sandwich Selatan",
+    "Fiji",
+    "Finland",
+    "Kepulauan Aland",
+    "Perancis",
+    "Guiana Perancis",
+    "Polinesia Perancis",
+    "Wilayah Selatan Perancis",
+    "Djibouti",
+    "Gabon",
+    "Georgia",
+    "Gambia",
+    "Wilayah Palestin",
+    "Jerman",
+    "Ghana",
+    "Gibraltar",
+    "Kiribati",
+    "Greece",
+    "Greenland",
+    "Grenada",
+    "Guadeloupe",
+    "Guam",
+    "Guatemala",
+    "Guinea",
+    "Guyana",
+    "Haiti",
+    "Kepulauan Heard & McDonald",
+    "Kota Vatican",
+    "Honduras",
+    "Hong Kong SAR China",
+    "Hungary",
+    "Iceland",
+    "India",
+    "Indonesia",
+    "Iran",
+    "Iraq",
+    "Ireland",
+    "Israel",
+    "Itali",
+    "Cote d’Ivoire",
+    "Jamaica",
+    "Jepun",
+    "Kazakhstan",
+    "Jordan",
+    "Kenya",
+    "Korea Utara",
+    "Korea Selatan",
+    "Kuwait",
+    "Kyrgyzstan",
+    "Laos",
+    "Lubnan",
+    "Lesotho",
+    "Latvia",
+    "Liberia",
+    "Libya",
+    "Liechtenstein",
+    "Lithuania",
+    "Luxembourg",
+    "Macau SAR China",
+    "Madagaskar",
+    "Malawi",
+    "Malaysia",
+    "Maldives",
+    "Mali",
+    "Malta",
+    "Martinique",
+    "Mauritania",
+    "Mauritius",
+    "Mexico",
+    "Monaco",
+    "Mongolia",
+    "Moldova",
+    "Montenegro",
+    "Montserrat",
+    "Maghribi",
+    "Mozambique",
+    "Oman",
+    "Namibia",
+    "Nauru",
+    "Nepal",
+    "Belanda",
+    "Curacao",
+    "Aruba",
+    "Sint Maarten",
+    "Belanda Caribbean",
+    "New Caledonia",
+    "Vanuatu",
+    "New Zealand",
+    "Nicaragua",
+    "Niger",
+    "Nigeria",
+    "Niue",
+    "Pulau Norfolk",
+    "Norway",
+    "Kepulauan Mariana Utara",
+    "Kepulauan Terpencil A.S.",
+    "Micronesia",
+    "Kepulauan Marshall",
+    "Palau",
+    "Pakistan",
+    "Panama",
+    "Papua New Guinea",
+    "Paraguay",
+    "Peru",
+    "Filipina",
+    "Kepulauan Pitcairn",
+    "Poland",
+    "Portugal",
+    "Guinea Bissau",
+    "Timor-Leste",
+    "Puerto Rico",
+    "Qatar",
+    "Reunion",
+    "Romania",
+    "Rusia",
+    "Rwanda",
+    "St. Barthelemy",
+    "Saint Helena",
+    "Saint Kitts dan Nevis",
+    "Anguilla",
+    "Saint Lucia",
+    "Saint Martin",
+    "Saint Pierre dan Miquelon",
+    "Saint Vincent dan Grenadines",
+    "San Marino",
+    "Sao Tome dan Principe",
+    "Arab Saudi",
+    "Senegal",
+    "Serbia",
+    "Seychelles",
+    "Sierra Leone",
+    "Singapura",
+    "Slovakia",
+    "Vietnam",
+    "Slovenia",
+    "Somalia",
+    "Afrika Selatan",
+    "Zimbabwe",
+    "Sepanyol",
+    "Sudan Selatan",
+    "Sudan",
+    "Sahara Barat",
+    "Surinam",
+    "Svalbard dan Jan Mayen",
+    "Eswatini",
+    "Sweden",
+    "Switzerland",
+    "Syria",
+    "Tajikistan",
+    "Thailand",
+    "Togo",
+    "Tokelau",
+    "Tonga",
+    "Trinidad dan Tobago",
+    "Emiriah Arab Bersatu",
+    "Tunisia",
+    "Turkiye",
+    "Turkmenistan",
+    "Kepulauan Turks dan Caicos",
+    "Tuvalu",
+    "Uganda",
+    "Ukraine",
+    "Macedonia Utara",
+    "Mesir",
+    "United Kingdom",
+    "Guernsey",
+    "Jersey",
+    "Isle of Man",
+    "Tanzania",
+    "Amerika Syarikat",
+    "Kepulauan Virgin A.S.",
+    "Burkina Faso",
+    "Uruguay",
+    "Uzbekistan",
+    "Venezuela",
+    "Wallis dan Futuna",
+    "Samoa",
+    "Yaman",
+    "Zambia",
+];
+
+static NL: [&str; 249] = [
+    "Afghanistan",
+    "Albanië",
+    "Antarctica",
+    "Algerije",
+    "Amerikaans-Samoa",
+    "Andorra",
+    "Angola",
+    "Antigua en Barbuda",
+    "Azerbeidzjan",
+    "Argentinië",
+    "Australië",
+    "Oostenrijk",
+    "Bahama’s",
+    "Bahrein",
+    "Bangladesh",
+    "Armenië",
+    "Barbados",
+    "België",
+    "Bermuda",
+    "Bhutan",
+    "Bolivia",
+    "Bosnië en Herzegovina",
+    "Botswana",
+    "Bouveteiland",
+    "Brazilië",
+    "Belize",
+    "Brits Indische Oceaanterritorium",
+    "Salomonseilanden",
+    "Britse Maagdeneilanden",
+    "Brunei",
+    "Bulgarije",
+    "Myanmar (Birma)",
+    "Burundi",
+    "Belarus",
+    "Cambodja",
+    "Kameroen",
+    "Canada",
+    "Kaapverdië",
+    "Kaaimaneilanden",
+    "Centraal-Afrikaanse Republiek",
+    "Sri Lanka",
+    "Tsjaad",
+    "Chili",
+    "China",
+    "Taiwan",
+    "Christmaseiland",
+    "Cocoseilanden",
+    "Colombia",
+    "Comoren",
+    "Mayotte",
+    "Congo-Brazzaville",
+    "Congo-Kinshasa",
+    "Cookeilanden",
+    "Costa Rica",
+    "Kroatië",
+    "Cuba",
+    "Cyprus",
+    "Tsjechië",
+    "Benin",
+    "Denemarken",
+    "Dominica",
+    "Dominicaanse Republiek",
+    "Ecuador",
+    "El Salvador",
+    "Equatoriaal-Guinea",
+    "Ethiopië",
+    "Eritrea",
+    "Estland",
+    "Faeröer",
+    "Falklandeilanden",
+    "Zuid-Georgia en Zuidelijke Sandwicheilanden",
+    "Fiji",
+    "Finland",
+    "Åland",
+    "Frankrijk",
+    "Frans-Guyana",
+    "Frans-Polynesië",
+    "Franse Gebieden in de zuidelijke Indische Oceaan",
+    "Djibouti",
+    "Gabon",
+    "Georgië",
+    "Gambia",
+    "Palestijnse gebieden",
+    "Duitsland",
+    "Ghana",
+    "Gibraltar",
+    "Kiribati",
+    "Griekenland",
+    "Groenland",
+    "Grenada",
+    "Guadeloupe",
+    "Guam",
+    "Guatemala",
+    "Guinee",
+    "Guyana",
+    "Haïti",
+    "Heard en McDonaldeilanden",
+    "Vaticaanstad",
+    "Honduras",
+    "Hongkong SAR van China",
+    "Hongarije",
+    "IJsland",
+    "India",
+    "Indonesië",
+    "Iran",
+    "Irak",
+    "Ierland",
+    "Israël",
+    "Italië",
+    "Ivoorkust",
+    "Jamaica",
+    "Japan",
+    "Kazachstan",
+    "Jordanië",
+    "Kenia",
+    "Noord-Korea",
+    "Zuid-Korea",
+    "Koeweit",
+    "Kirgizië",
+    "Laos",
+    "Libanon",
+    "Lesotho",
+    "Letland",
+    "Liberia",
+    "Libië",
+    "Liechtenstein",
+    "Litouwen",
+    "Luxemburg",
+    "Macau SAR van China",
+    "Madagaskar",
+    "Malawi",
+    "Maleisië",
+    "Maldiven",
+    "Mali",
+    "Malta",
+    "Martinique",
+    "Mauritanië",
+    "Mauritius",
+    "Mexico",
+    "Monaco",
+    "Mongolië",
+    "Moldavië",
+    "Montenegro",
+    "Montserrat",
+    "Marokko",
+    "Mozambique",
+    "Oman",
+    "Namibië",
+    "Nauru",
+    "Nepal",
+    "Nederland",
+    "Curaçao",
+    "Aruba",
+    "Sint-Maarten",
+    "Caribisch Nederland",
+    "Nieuw-Caledonië",
+    "Vanuatu",
+    "Nieuw-Zeeland",
+    "Nicaragua",
+    "Niger",
+    "Nigeria",
+    "Niue",
+    "Norfolk",
+    "Noorwegen",
+    "Noordelijke Marianen",
+    "Kleine afgelegen eilanden van de Verenigde Staten",
+    "Micronesia",
+    "Marshalleilanden",
+    "Palau",
+    "Pakistan",
+    "Panama",
+    "Papoea-Nieuw-Guinea",
+    "Paraguay",
+    "Peru",
+    "Filipijnen",
+    "Pitcairneilanden",
+    "Polen",
+    "Portugal",
+    "Guinee-Bissau",
+    "Oost-Timor",
+    "Puerto Rico",
+    "Qatar",
+    "Réunion",
+    "Roemenië",
+    "Rusland",
+    "Rwanda",
+    "Saint-Barthélemy",
+    "Sint-Helena",
+    "Saint Kitts en Nevis",
+    "Anguilla",
+    "Saint Lucia",
+    "Saint-Martin",
+    "Saint-Pierre en Miquelon",
+    "Saint Vincent en de Grenadines",
+    "San Marino",
+    "Sao Tomé en Principe",
+    "Saoedi-Arabië",
+    "Senegal",
+    "Servië",
+    "Seychellen",
+    "Sierra Leone",
+    "Singapore",
+    "Slowakije",
+    "Vietnam",
+    "Slovenië",
+    "Somalië",
+    "Zuid-Afrika",
+    "Zimbabwe",
+    "Spanje",
+    "Zuid-Soedan",
+    "Soedan",
+    "Westelijke Sahara",
+    "Suriname",
+    "Spitsbergen en Jan Mayen",
+    "Eswatini",
+    "Zweden",
+    "Zwitserland",
+    "Syrië",
+    "Tadzjikistan",
+    "Thailand",
+    "Togo",
+    "Tokelau",
+    "Tonga",
+    "Trinidad en Tobago",
+    "Verenigde Arabische Emiraten",
+    "Tunesië",
+    "Turkije",
+    "Turkmenistan",
+    "Turks- en Caicoseilanden",
+    "Tuvalu",
+    "Oeganda",
+    "Oekraïne",
+    "Noord-Macedonië",
+    "Egypte",
+    "Verenigd Koninkrijk",
+    "Guernsey",
+    "Jersey",
+    "Isle of Man",
+    "Tanzania",
+    "Verenigde Staten",
+    "Amerikaanse Maagdeneilanden",
+    "Burkina Faso",
+    "Uruguay",
+    "Oezbekistan",
+    "Venezuela",
+    "Wallis en Futuna",
+    "Samoa",
+    "Jemen",
+    "Zambia",
+];
+
+static NO: [&str; 249] = [
+    "Afghanistan",
+    "Albania",
+    "Antarktis",
+    "Algerie",
+    "Amerikansk Samoa",
+    "Andorra",
+    "Angola",
+    "Antigua og Barbuda",
+    "Aserbajdsjan",
+    "Argentina",
+    "Australia",
+    "Østerrike",
+    "Bahamas",
+    "Bahrain",
+    "Bangladesh",
+    "Armenia",
+    "Barbados",
+    "Belgia",
+    "Bermuda",
+    "Bhutan",
+    "Bolivia",
+    "Bosnia-Hercegovina",
+    "Botswana",
+    "Bouvetøya",
+    "Brasil",
+    "Belize",
+    "Det britiske territoriet i Indiahavet",
+    "Salomonøyene",
+    "De britiske jomfruøyene",
+    "Brunei",
+    "Bulgaria",
+    "Myanmar (Burma)",
+    "Burundi",
+    "Belarus",
+    "Kambodsja",
+    "Kamerun",
+    "Canada",
+    "Kapp Verde",
+    "Caymanøyene",
+    "Den sentralafrikanske republikk",
+    "Sri Lanka",
+    "Tsjad",
+    "Chile",
+    "Kina",
+    "Taiwan",
+    "Christmasøya",
+    "Kokosøyene",
+    "Colombia",
+    "Komorene",
+    "Mayotte",
+    "Kongo-Brazzaville",
+    "Kongo",
+    "Cookøyene",
+    "Costa Rica",
+    "Kroatia",
+    "Cuba",
+    "Kypros",
+    "Tsjekkia",
+    "Benin",
+    "Danmark",
+    "Dominica",
+    "Den dominikanske republikk",
+    "Ecuador",
+    "El Salvador",
+    "Ekvatorial-Guinea",
+    "Etiopia",
+    "Eritrea",
+    "Estland",
+    "Færøyene",
+    "Falklandsøyene",
+    "Sør-Georgia og Sør-Sandwichøyene",
+    "Fiji",
+    "Finland",
+    "Åland",
+    "Frankrike",
+    "Fransk Guyana",
+    "Fransk Polynesia",
+    "De franske sørterritorier",
+    "Djibouti",
+    "Gabon",
+    "Georgia",
+    "Gambia",
+    "Det palestinske området",
+    "Tyskland",
+    "Ghana",
+    "Gibraltar",
+    "Kiribati",
+    "Hellas",
+    "Grønland",
+    "Grenada",
+    "Guadeloupe",
+    "Guam",
+    "Guatemala",
+    "Guinea",
+    "Guyana",
+    "Haiti",
+    "Heard- og McDonaldøyene",
+    "Vatikanstaten",
+    "Honduras",
+    "Hongkong SAR Kina",
+    "Ungarn",
+    "Island",
+    "India",
+    "Indonesia",
+    "Iran",
+    "Irak",
+    "Irland",
+    "Israel",
+    "Italia",
+    "Elfenbenskysten",
+    "Jamaica",
+    "Japan",
+    "Kasakhstan",
+    "Jordan",
+    "Kenya",
+    "Nord-Korea",
+    "Sør-Korea",
+    "Kuwait",
+    "Kirgisistan",
+    "Laos",
+    "Libanon",
+    "Lesotho",
+    "Latvia",
+    "Liberia",
+    "Libya",
+    "Liechtenstein",
+    "Litauen",
+    "Luxemburg",
+    "Macao SAR Kina",
+    "Madagaskar",
+    "Malawi",
+    "Malaysia",
+    "Maldivene",
+    "Mali",
+    "Malta",
+    "Martinique",
+    "Mauritania",
+    "Mauritius",
+    "Mexico",
+    "Monaco",
+    "Mongolia",
+    "Moldova",
+    "Montenegro",
+    "Montserrat",
+    "Marokko",
+    "Mosambik",
+    "Oman",
+    "Namibia",
+    "Nauru",
+    "Nepal",
+    "Nederland",
+    "Curaçao",
+    "Aruba",
+    "Sint Maarten",
+    "Karibisk Nederland",
+    "Ny-Caledonia",
+    "Vanuatu",
+    "New Zealand",
+    "Nicaragua",
+    "Niger",
+    "Nigeria",
+    "Niue",
+    "Norfolkøya",
+    "Norge",
+    "Nord-Marianene",
+    "USAs ytre øyer",
+    "Mikronesiaføderasjonen",
+    "Marshalløyene",
+    "Palau",
+    "Pakistan",
+    "Panama",
+    "Papua Ny-Guinea",
+    "Paraguay",
+    "Peru",
+    "Filippinene",
+    "Pitcairnøyene",
+    "Polen",
+    "Portugal",
+    "Guinea-Bissau",
+    "Øst-Timor",
+    "Puerto Rico",
+    "Qatar",
+    "Réunion",
+    "Romania",
+    "Russland",
+    "Rwanda",
+    "Saint-Barthélemy",
+    "St. Helena",
+    "Saint Kitts og Nevis",
+    "Anguilla",
+    "St. Lucia",
+    "Saint-Martin",
+    "Saint-Pierre-et-Miquelon",
+    "St. Vincent og Grenadinene",
+    "San Marino",
+    "São Tomé og Príncipe",
+    "Saudi-Arabia",
+    "Senegal",
+    "Serbia",
+    "Seychellene",
+    "Sierra Leone",
+    "Singapore",
+    "Slovakia",
+    "Vietnam",
+    "Slovenia",
+    "Somalia",
+    "Sør-Afrika",
+    "Zimbabwe",
+    "Spania",
+    "Sør-Sudan",
+    "Sudan",
+    "Vest-Sahara",
+    "Surinam",
+    "Svalbard og Jan Mayen",
+    "Eswatini",
+    "Sverige",
+    "Sveits",
+    "Syria",
+    "Tadsjikistan",
+    "Thailand",
+    "Togo",
+    "Tokelau",
+    "Tonga",
+    "Trinidad og Tobago",
+    "De forente arabiske emirater",
+    "Tunisia",
+    "Tyrkia",
+    "Turkmenistan",
+    "Turks- og Caicosøyene",
+    "Tuvalu",
+    "Uganda",
+    "Ukraina",
+    "Nord-Makedonia",
+    "Egypt",
+    "Storbritannia",
+    "Guernsey",
+    "Jersey",
+    "Man",
+    "Tanzania",
+    "USA",
+    "De amerikanske jomfruøyene",
+    "Burkina Faso",
+    "Uruguay",
+    "Usbekistan",
+    "Venezuela",
+    "Wallis og Futuna",
+    "Samoa",
+    "Jemen",
+    "Zambia",
+];
+
+static PL: [&str; 249] = [
+    "Afganistan",
+    "Albania",
+    "Antarktyda",
+    "Algieria",
+    "Samoa Amerykańskie",
+    "Andora",
+    "Angola",
+    "Antigua i Barbuda",
+    "Azerbejdżan",
+    "Argentyna",
+    "Australia",
+    "Austria",
+    "Bahamy",
+    "Bahrajn",
+    "Bangladesz",
+    "Armenia",
+    "Barbados",
+    "Belgia",
+    "Bermudy",
+    "Bhutan",
+    "Boliwia",
+    "Bośnia i Hercegowina",
+    "Botswana",
+    "Wyspa Bouveta",
+    "Brazylia",
+    "Belize",
+    "Brytyjskie Terytorium Oceanu Indyjskiego",
+    "Wyspy Salomona",
+    "Brytyjskie Wyspy Dziewicze",
+    "Brunei",
+    "Bułgaria",
+    "Mjanma (Birma)",
+    "Burundi",
+    "Białoruś",
+    "Kambodża",
+    "Kamerun",
+    "Kanada",
+    "Republika Zielonego Przylądka",
+    "Kajmany",
+    "Republika Środkowoafrykańska",
+    "Sri Lanka",
+    "Czad",
+    "Chile",
+    "Chiny",
+    "Tajwan",
+    "Wyspa Bożego Narodzenia",
+    "Wyspy Kokosowe",
+    "Kolumbia",
+    "Komory",
+    "Majotta",
+    "Kongo",
+    "Demokratyczna Republika Konga",
+    "Wyspy Cooka",
+    "Kostaryka",
+    "Chorwacja",
+    "Kuba",
+    "Cypr",
+    "Czechy",
+    "Benin",
+    "Dania",
+    "Dominika",
+    "Dominikana",
+    "Ekwador",
+    "Salwador",
+    "Gwinea Równikowa",
+    "Etiopia",
+    "Erytrea",
+    "Estonia",
+    "Wyspy Owcze",
+    "Falklandy",
+    "Georgia Południowa i Sandwich Południowy",
+    "Fidżi",
+    "Finlandia",
+    "Wyspy Alandzkie",
+    "Francja",
+    "Gujana Francuska",
+    "Polinezja Francuska",
+    "Francuskie Terytoria Południowe i Antarktyczne",
+    "Dżibuti",
+    "Gabon",
+    "Gruzja",
+    "Gambia",
+    "Terytoria Palestyńskie",
+    "Niemcy",
+    "Ghana",
+    "Gibraltar",
+    "Kiribati",
+    "Grecja",
+    "Grenlandia",
+    "Grenada",
+    "Gwadelupa",
+    "Guam",
+    "Gwatemala",
+    "Gwinea",
+    "Gujana",
+    "Haiti",
+    "Wyspy Heard i McDonalda",
+    "Watykan",
+    "Honduras",
+    "SRA Hongkong (Chiny)",
+    "Węgry",
+    "Islandia",
+    "Indie",
+    "Indonezja",
+    "Iran",
+    "Irak",
+    "Irlandia",
+    "Izrael",
+    "Włochy",
+    "Côte d’Ivoire",
+    "Jamajka",
+    "Japonia",
+    "Kazachstan",
+    "Jordania",
+    "Kenia",
+    "Korea Północna",
+    "Korea Południowa",
+    "Kuwejt",
+    "Kirgistan",
+    "Laos",
+    "Liban",
+    "Lesotho",
+    "Łotwa",
+    "Liberia",
+    "Libia",
+    "Liechtenstein",
+    "Litwa",
+    "Luksemburg",
+    "SRA Makau (Chiny)",
+    "Madagaskar",
+    "Malawi",
+    "Malezja",
+    "Malediwy",
+    "Mali",
+    "Malta",
+    "Martynika",
+    "Mauretania",
+    "Mauritius",
+    "Meksyk",
+    "Monako",
+    "Mongolia",
+    "Mołdawia",
+    "Czarnogóra",
+    "Montserrat",
+    "Maroko",
+    "Mozambik",
+    "Oman",
+    "Namibia",
+    "Nauru",
+    "Nepal",
+    "Holandia",
+    "Curaçao",
+    "Aruba",
+    "Sint Maarten",
+    "Niderlandy Karaibskie",
+    "Nowa Kaledonia",
+    "Vanuatu",
+    "Nowa Zelandia",
+    "Nikaragua",
+    "Niger",
+    "Nigeria",
+    "Niue",
+    "Norfolk",
+    "Norwegia",
+    "Mariany Północne",
+    "Dalekie Wyspy Mniejsze Stanów Zjednoczonych",
+    "Mikronezja",
+    "Wyspy Marshalla",
+    "Palau",
+    "Pakistan",
+    "Panama",
+    "Papua-Nowa Gwinea",
+    "Paragwaj",
+    "Peru",
+    "Filipiny",
+    "Pitcairn",
+    "Polska",
+    "Portugalia",
+    "Gwinea Bissau",
+    "Timor Wschodni",
+    "Portoryko",
+    "Katar",
+    "Reunion",
+    "Rumunia",
+    "Rosja",
+    "Rwanda",
+    "Saint-Barthélemy",
+    "Wyspa Świętej Heleny",
+    "Saint Kitts i Nevis",
+    "Anguilla",
+    "Saint Lucia",
+    "Saint-Martin",
+    "Saint-Pierre i Miquelon",
+    "Saint Vincent i Grenadyny",
+    "San Marino",
+    "Wyspy Świętego Tomasza i Książęca",
+    "Arabia Saudyjska",
+    "Senegal",
+    "Serbia",
+    "Seszele",
+    "Sierra Leone",
+    "Singapur",
+    "Słowacja",
+    "Wietnam",
+    "Słowenia",
+    "Somalia",
+    "Republika Południowej Afryki",
+    "Zimbabwe",
+    "Hiszpania",
+    "Sudan Południowy",
+    "Sudan",
+    "Sahara Zachodnia",
+    "Surinam",
+    "Svalbard i Jan Mayen",
+    "Eswatini",
+    "Szwecja",
+    "Szwajcaria",
+    "Syria",
+    "Tadżykistan",
+    "Tajlandia",
+    "Togo",
+    "Tokelau",
+    "Tonga",
+    "Trynidad i Tobago",
+    "Zjednoczone Emiraty Arabskie",
+    "Tunezja",
+    "Turcja",
+    "Turkmenistan",
+    "Turks i Caicos",
+    "Tuvalu",
+    "Uganda",
+    "Ukraina",
+    "Macedonia Północna",
+    "Egipt",
+    "Wielka Brytania",
+    "Guernsey",
+    "Jersey",
+    "Wyspa Man",
+    "Tanzania",
+    "Stany Zjednoczone",
+    "Wyspy Dziewicze Stanów Zjednoczonych",
+    "Burkina Faso",
+    "Urugwaj",
+    "Uzbekistan",
+    "Wenezuela",
+    "Wallis i Futuna",
+    "Samoa",
+    "Jemen",
+    "Zambia",
+];
+
+static PT: [&str; 249] = [
+    "Afeganistão",
+    "Albânia",
+    "Antártida",
+    "Argélia",
+    "Samoa Americana",
+    "Andorra",
+    "Angola",
+    "Antígua e Barbuda",
+    "Azerbaijão",
+    "Argentina",
+    "Austrália",
+    "Áustria",
+    "Bahamas",
+    "Barein",
+    "Bangladesh",
+    "Armênia",
+    "Barbados",
+    "Bélgica",
+    "Bermudas",
+    "Butão",
+    "Bolívia",
+    "Bósnia e Herzegovina",
+    "Botsuana",
+    "Ilha Bouvet",
+    "Brasil",
+    "Belize",
+    "Território Britânico do Oceano Índico",
+    "Ilhas Salomão",
+    "Ilhas Virgens Britânicas",
+    "Brunei",
+    "Bulgária",
+    "Mianmar (Birmânia)",
+    "Burundi",
+    "Bielorrússia",
+    "Camboja",
+    "Camarões",
+    "Canadá",
+    "Cabo Verde",
+    "Ilhas Cayman",
+    "República Centro-Africana",
+    "Sri Lanka",
+    "Chade",
+    "Chile",
+    "China",
+    "Taiwan",
+    "Ilha Christmas",
+    "Ilhas Cocos (Keeling)",
+    "Colômbia",
+    "Comores",
+    "Mayotte",
+    "República do Congo",
+    "Congo - Kinshasa",
+    "Ilhas Cook",
+    "Costa Rica",
+    "Croácia",
+    "Cuba",
+    "Chipre",
+    "Tchéquia",
+    "Benin",
+    "Dinamarca",
+    "Dominica",
+    "República Dominicana",
+    "Equador",
+    "El Salvador",
+    "Guiné Equatorial",
+    "Etiópia",
+    "Eritreia",
+    "Estônia",
+    "Ilhas Faroé",
+    "Ilhas Malvinas",
+    "Ilhas Geórgia do Sul e Sandwich do Sul",
+    "Fiji",
+    "Finlândia",
+    "Ilhas Aland",
+    "França",
+    "Guiana Francesa",
+    "Polinésia Francesa",
+    "Territórios Franceses do Sul",
+    "Djibuti",
+    "Gabão",
+    "Geórgia",
+    "Gâmbia",
+    "Territórios palestinos",
+    "Alemanha",
+    "Gana",
+    "Gibraltar",
+    "Quiribati",
+    "Grécia",
+    "Groenlândia",
+    "Granada",
+    "Guadalupe",
+    "Guam",
+    "Guatemala",
+    "Guiné",
+    "Guiana",
+    "Haiti",
+    "Ilhas Heard e McDonald",
+    "Cidade do Vaticano",
+    "Honduras",
+    "Hong Kong, RAE da China",
+    "Hungria",
+    "Islândia",
+    "Índia",
+    "Indonésia",
+    "Irã",
+    "Iraque",
+    "Irlanda",
+    "Israel",
+    "Itália",
+    "Costa do Marfim",
+    "Jamaica",
+    "Japão",
+    "Cazaquistão",
+    "Jordânia",
+    "Quênia",
+    "Coreia do Norte",
+    "Coreia do Sul",
+    "Kuwait",
+    "Quirguistão",
+    "Laos",
+    "Líbano",
+    "Lesoto",
+    "Letônia",
+    "Libéria",
+    "Líbia",
+    "Liechtenstein",
+    "Lituânia",
+    "Luxemburgo",
+    "Macau, RAE da China",
+    "Madagascar",
+    "Malaui",
+    "Malásia",
+    "Maldivas",
+    "Mali",
+    "Malta",
+    "Martinica",
+    "Mauritânia",
+    "Maurício",
+    "México",
+    "Mônaco",
+    "Mongólia",
+    "Moldávia",
+    "Montenegro",
+    "Montserrat",
+    "Marrocos",
+    "Moçambique",
+    "Omã",
+    "Namíbia",
+    "Nauru",
+    "Nepal",
+    "Países Baixos",
+    "Curaçao",
+    "Aruba",
+    "Sint Maarten",
+    "Países Baixos Caribenhos",
+    "Nova Caledônia",
+    "Vanuatu",
+    "Nova Zelândia",
+    "Nicarágua",
+    "Níger",
+    "Nigéria",
+    "Niue",
+    "Ilha Norfolk",
+    "Noruega",
+    "Ilhas Marianas do Norte",
+    "Ilhas Menores Distantes dos EUA",
+    "Micronésia",
+    "Ilhas Marshall",
+    "Palau",
+    "Paquistão",
+    "Panamá",
+    "Papua-Nova Guiné",
+    "Paraguai",
+    "Peru",
+    "Filipinas",
+    "Ilhas Pitcairn",
+    "Polônia",
+    "Portugal",
+    "Guiné-Bissau",
+    "Timor-Leste",
+    "Porto Rico",
+    "Catar",
+    "Reunião",
+    "Romênia",
+    "Rússia",
+    "Ruanda",
+    "São Bartolomeu",
+    "Santa Helena",
+    "São Cristóvão e Névis",
+    "Anguila",
+    "Santa Lúcia",
+    "São Martinho",
+    "São Pedro e Miquelão",
+    "São Vicente e Granadinas",
+    "San Marino",
+    "São Tomé e Príncipe",
+    "Arábia Saudita",
+    "Senegal",
+    "Sérvia",
+    "Seicheles",
+    "Serra Leoa",
+    "Singapura",
+    "Eslováquia",
+    "Vietnã",
+    "Eslovênia",
+    "Somália",
+    "África do Sul",
+    "Zimbábue",
+    "Espanha",
+    "Sudão do Sul",
+    "Sudão",
+    "Saara Ocidental",
+    "Suriname",
+    "Svalbard e Jan Mayen",
+    "Essuatíni",
+    "Suécia",
+    "Suíça",
+    "Síria",
+    "Tadjiquistão",
+    "Tailândia",
+    "Togo",
+    "Tokelau",
+    "Tonga",
+    "Trinidad e Tobago",
+    "Emirados Árabes Unidos",
+    "Tunísia",
+    "Turquia",
+    "Turcomenistão",
+    "Ilhas Turcas e Caicos",
+    "Tuvalu",
+    "Uganda",
+    "Ucrânia",
+    "Macedônia do Norte",
+    "Egito",
+    "Reino Unido",
+    "Guernsey",
+    "Jersey",
+    "Ilha de Man",
+    "Tanzânia",
+    "Estados Unidos",
+    "Ilhas Virgens Americanas",
+    "Burquina Faso",
+    "Uruguai",
+    "Uzbequistão",
+    "Venezuela",
+    "Wallis e Futuna",
+    "Samoa",
+    "Iêmen",
+    "Zâmbia",
+];
+
+static PT_PT: &[(Country, &str)] = &[
+    (Country::BS, "Baamas"),
+    (Country::BH, "Barém"),
+    (Country::BD, "Bangladeche"),
+    (Country::AM, "Arménia"),
+    (Country::KY, "Ilhas Caimão"),
+    (Country::LK, "Sri Lanca"),
+    (Country::CX, "Ilha do Natal"),
+    (Country::CC, "Ilhas dos Cocos (Keeling)"),
+    (Country::YT, "Maiote"),
+    (Country::CG, "Congo-Brazzaville"),
+    (Country::CD, "Congo-Kinshasa"),
+    (Country::CZ, "Chéquia"),
+    (Country::BJ, "Benim"),
+    (Country::DM, "Domínica"),
+    (Country::SV, "Salvador"),
+    (Country::EE, "Estónia"),
+    (Country::FK, "Ilhas Falkland"),
+    (Country::AX, "Alanda"),
+    (Country::TF, "Territórios Austrais Franceses"),
+    (Country::DJ, "Jibuti"),
+    (Country::PS, "Territórios palestinianos"),
+    (Country::KI, "Quiribáti"),
+    (Country::GL, "Gronelândia"),
+    (Country::GU, "Guame"),
+    (Country::IR, "Irão"),
+    (Country::CI, "Côte d’Ivoire (Costa do Marfim)"),
+    (Country::KE, "Quénia"),
+    (Country::KW, "Koweit"),
+    (Country::LV, "Letónia"),
+    (Country::LI, "Listenstaine"),
+    (Country::MG, "Madagáscar"),
+    (Country::MW, "Maláui"),
+    (Country::MU, "Maurícia"),
+    (Country::MC, "Mónaco"),
+    (Country::MS, "Monserrate"),
+    (Country::CW, "Curaçau"),
+    (Country::SX, "São Martinho (Sint Maarten)"),
+    (Country::NC, "Nova Caledónia"),
+    (Country::NU, "Niuê"),
+    (Country::UM, "Ilhas Menores Afastadas dos EUA"),
+    (Country::PL, "Polónia"),
+    (Country::RO, "Roménia"),
+    (Country::KN, "São Cristóvão e Neves"),
+    (Country::MF, "São Martinho (Saint-Martin)"),
+    (Country::SM, "São Marinho"),
+    (Country::VN, "Vietname"),
+    (Country::SI, "Eslovénia"),
+    (Country::ZW, "Zimbabué"),
+    (Country::EH, "Sara Ocidental"),
+    (Country::TJ, "Tajiquistão"),
+    (Country::TK, "Toquelau"),
+    (Country::TT, "Trindade e Tobago"),
+    (Country::TM, "Turquemenistão"),
+    (Country::MK, "Macedónia do Norte"),
+    (Country::GG, "Guernesey"),
+    (Country::VI, "Ilhas Virgens dos EUA"),
+    (Country::UZ, "Usbequistão"),
+    (Country::YE, "Iémen"),
+];
+
+static RO: [&str; 249] = [
+    "Afganistan",
+    "Albania",
+    "Antarctica",
+    "Algeria",
+    "Samoa Americană",
+    "Andorra",
+    "Angola",
+    "Antigua și Barbuda",
+    "Azerbaidjan",
+    "Argentina",
+    "Australia",
+    "Austria",
+    "Bahamas",
+    "Bahrain",
+    "Bangladesh",
+    "Armenia",
+    "Barbados",
+    "Belgia",
+    "Bermuda",
+    "Bhutan",
+    "Bolivia",
+    "Bosnia și Herțegovina",
+    "Botswana",
+    "Insula Bouvet",
+    "Brazilia",
+    "Belize",
+    "Teritoriul Britanic din Oceanul Indian",
+    "Insulele Solomon",
+    "Insulele Virgine Britanice",
+    "Brunei",
+    "Bulgaria",
+    "Myanmar (Birmania)",
+    "Burundi",
+    "Belarus",
+    "Cambodgia",
+    "Camerun",
+    "Canada",
+    "Capul Verde",
+    "Insulele Cayman",
+    "Republica Centrafricană",
+    "Sri Lanka",
+    "Ciad",
+    "Chile",
+    "China",
+    "Taiwan",
+    "Insula Christmas",
+    "Insulele Cocos (Keeling)",
+    "Columbia",
+    "Comore",
+    "Mayotte",
+    "Congo - Brazzaville",
+    "Congo - Kinshasa",
+    "Insulele Cook",
+    "Costa Rica",
+    "Croația",
+    "Cuba",
+    "Cipru",
+    "Cehia",
+    "Benin",
+    "Danemarca",
+    "Dominica",
+    "Republica Dominicană",
+    "Ecuador",
+    "El Salvador",
+    "Guineea Ecuatorială",
+    "Etiopia",
+    "Eritreea",
+    "Estonia",
+    "Insulele Feroe",
+    "Insulele Falkland",
+    "Georgia de Sud și Insulele Sandwich de Sud",
+    "Fiji",
+    "Finlanda",
+    "Insulele Åland",
+    "Franța",
+    "Guyana Franceză",
+    "Polinezia Franceză",
+    "Teritoriile Australe și Antarctice Franceze",
+    "Djibouti",
+    "Gabon",
+    "Georgia",
+    "Gambia",
+    "Teritoriile Palestiniene",
+    "Germania",
+    "Ghana",
+    "Gibraltar",
+    "Kiribati",
+    "Grecia",
+    "Groenlanda",
+    "Grenada",
+    "Guadelupa",
+    "Guam",
+    "Guatemala",
+    "Guineea",
+    "Guyana",
+    "Haiti",
+    "Insula Heard și Insulele McDonald",
+    "Statul Cetății Vaticanului",
+    "Honduras",
+    "R.A.S. Hong Kong, China",
+    "Ungaria",
+    "Islanda",
+    "India",
+    "Indonezia",
+    "Iran",
+    "Irak",
+    "Irlanda",
+    "Israel",
+    "Italia",
+    "Côte d’Ivoire",
+    "Jamaica",
+    "Japonia",
+    "Kazahstan",
+    "Iordania",
+    "Kenya",
+    "Coreea de Nord",
+    "Coreea de Sud",
+    "Kuweit",
+    "Kârgâzstan",
+    "Laos",
+    "Liban",
+    "Lesotho",
+    "Letonia",
+    "Liberia",
+    "Libia",
+    "Liechtenstein",
+    "Lituania",
+    "Luxemburg",
+    "R.A.S. Macao, China",
+    "Madagascar",
+    "Malawi",
+    "Malaysia",
+    "Maldive",
+    "Mali",
+    "Malta",
+    "Martinica",
+    "Mauritania",
+    "Mauritius",
+    "Mexic",
+    "Monaco",
+    "Mongolia",
+    "Republica Moldova",
+    "Muntenegru",
+    "Montserrat",
+    "Maroc",
+    "Mozambic",
+    "Oman",
+    "Namibia",
+    "Nauru",
+    "Nepal",
+    "Țările de Jos",
+    "Curaçao",
+    "Aruba",
+    "Sint-Maarten",
+    "Insulele Caraibe Olandeze",
+    "Noua Caledonie",
+    "Vanuatu",
+    "Noua Zeelandă",
+    "Nicaragua",
+    "Niger",
+    "Nigeria",
+    "Niue",
+    "Insula Norfolk",
+    "Norvegia",
+    "Insulele Mariane de Nord",
+    "Insulele Îndepărtate ale S.U.A.",
+    "Micronezia",
+    "Insulele Marshall",
+    "Palau",
+    "Pakistan",
+    "Panama",
+    "Papua-Noua Guinee",
+    "Paraguay",
+    "Peru",
+    "Filipine",
+    "Insulele Pitcairn",
+    "Polonia",
+    "Portugalia",
+    "Guineea-Bissau",
+    "Timor-Leste",
+    "Puerto Rico",
+    "Qatar",
+    "Réunion",
+    "România",
+    "Rusia",
+    "Rwanda",
+    "Saint-Barthélemy",
+    "Sfânta Elena",
+    "Saint Kitts și Nevis",
+    "Anguilla",
+    "Sfânta Lucia",
+    "Sfântul Martin",
+    "Saint-Pierre și Miquelon",
+    "Saint Vincent și Grenadinele",
+    "San Marino",
+    "São Tomé și Príncipe",
+    "Arabia Saudită",
+    "Senegal",
+    "Serbia",
+    "Seychelles",
+    "Sierra Leone",
+    "Singapore",
+    "Slovacia",
+    "Vietnam",
+    "Slovenia",
+    "Somalia",
+    "Africa de Sud",
+    "Zimbabwe",
+    "Spania",
+    "Sudanul de Sud",
+    "Sudan",
+    "Sahara Occidentală",
+    "Suriname",
+    "Svalbard și Jan Mayen",
+    "Eswatini",
+    "Suedia",
+    "Elveția",
+    "Siria",
+    "Tadjikistan",
+    "Thailanda",
+    "Togo",
+    "Tokelau",
+    "Tonga",
+    "Trinidad și Tobago",
+    "Emiratele Arabe Unite",
+    "Tunisia",
+    "Turcia",
+    "Turkmenistan",
+    "Insulele Turks și Caicos",
+    "Tuvalu",
+    "Uganda",
+    "Ucraina",
+    "Macedonia de Nord",
+    "Egipt",
+    "Regatul Unit",
+    "Guernsey",
+    "Jersey",
+    "Insula Man",
+    "Tanzania",
+    "Statele Unite ale Americii",
+    "Insulele Virgine Americane",
+    "Burkina Faso",
+    "Uruguay",
+    "Uzbekistan",
+    "Venezuela",
+    "Wallis și Futuna",
+    "Samoa",
+    "Yemen",
+    "Zambia",
+];
+
+static RU: [&str; 249] = [
+    "Афганистан",
+    "Албания",
+    "Антарктида",
+    "Алжир",
+    "Американское Самоа",
+    "Андорра",
+    "Ангола",
+    "Антигуа и Барбуда",
+    "Азербайджан",
+    "Аргентина",
+    "Австралия",
+    "Австрия",
+    "Багамы",
+    "Бахрейн",
+    "Бангладеш",
+    "Армения",
+    "Барбадос",
+    "Бельгия",
+    "Бермудские о-ва",
+    "Бутан",
+    "Боливия",
+    "Босния и Герцеговина",
+    "Ботсвана",
+    "о-в Буве",
+    "Бразилия",
+    "Белиз",
+    "Британская территория в Индийском океане",
+    "Соломоновы о-ва",
+    "Виргинские о-ва (Великобритания)",
+    "Бруней",
+    "Болгария",
+    "Мьянма (Бирма)",
+    "Бурунди",
+    "Беларусь",
+    "Камбоджа",
+    "Камерун",
+    "Канада",
+    "Кабо-Верде",
+    "о-ва Кайман",
+    "Центрально-Африканская Республика",
+    "Шри-Ланка",
+    "Чад",
+    "Чили",
+    "Китай",
+    "Тайвань",
+    "о-в Рождества",
+    "Кокосовые о-ва",
+    "Колумбия",
+    "Коморы",
+    "Майотта",
+    "Конго - Браззавиль",
+    "Конго - Киншаса",
+    "о-ва Кука",
+    "Коста-Рика",
+    "Хорватия",
+    "Куба",
+    "Кипр",
+    "Чехия",
+    "Бенин",
+    "Дания",
+    "Доминика",
+    "Доминиканская Республика",
+    "Эквадор",
+    "Сальвадор",
+    "Экваториальная Гвинея",
+    "Эфиопия",
+    "Эритрея",
+    "Эстония",
+    "Фарерские о-ва",
+    "Фолклендские о-ва",
+    "Южная Георгия и Южные Сандвичевы о-ва",
+    "Фиджи",
+    "Финляндия",
+    "Аландские о-ва",
+    "Франция",
+    "Французская Гвиана",
+    "Французская Полинезия",
+    "Французские Южные территории",
+    "Джибути",
+    "Габон",
+    "Грузия",
+    "Гамбия",
+    "Палестинские территории",
+    "Германия",
+    "Гана",
+    "Гибралтар",
+    "Кирибати",
+    "Греция",
+    "Гренландия",
+    "Гренада",
+    "Гваделупа",
+    "Гуам",
+    "Гватемала",
+    "Гвинея",
+    "Гайана",
+    "Гаити",
+    "о-ва Херд и Макдональд",
+    "Ватикан",
+    "Гондурас",
+    "Гонконг (САР)",
+    "Венгрия",
+    "Исландия",
+    "Индия",
+    "Индонезия",
+    "Иран",
+    "Ирак",
+    "Ирландия",
+    "Израиль",
+    "Италия",
+    "Кот-д’Ивуар",
+    "Ямайка",
+    "Япония",
+    "Казахстан",
+    "Иордания",
+    "Кения",
+    "КНДР",
+    "Республика Корея",
+    "Кувейт",
+    "Киргизия",
+    "Лаос",
+    "Ливан",
+    "Лесото",
+    "Латвия",
+    "Либерия",
+    "Ливия",
+    "Лихтенштейн",
+    "Литва",
+    "Люксембург",
+    "Макао (САР)",
+    "Мадагаскар",
+    "Малави",
+    "Малайзия",
+    "Мальдивы",
+    "Мали",
+    "Мальта",
+    "Мартиника",
+    "Мавритания",
+    "Маврикий",
+    "Мексика",
+    "Монако",
+    "Монголия",
+    "Молдова",
+    "Черногория",
+    "Монтсеррат",
+    "Марокко",
+    "Мозамбик",
+    "Оман",
+    "Намибия",
+    "Науру",
+    "Непал",
+    "Нидерланды",
+    "Кюрасао",
+    "Аруба",
+    "Синт-Мартен",
+    "Бонэйр, Синт-Эстатиус и Саба",
+    "Новая Каледония",
+    "Вануату",
+    "Новая Зеландия",
+    "Никарагуа",
+    "Нигер",
+    "Нигерия",
+    "Ниуэ",
+    "о-в Норфолк",
+    "Норвегия",
+    "Северные Марианские о-ва",
+    "Внешние малые о-ва (США)",
+    "Федеративные Штаты Микронезии",
+    "Маршалловы о-ва",
+    "Палау",
+    "Пакистан",
+    "Панама",
+    "Папуа — Новая Гвинея",
+    "Парагвай",
+    "Перу",
+    "Филиппины",
+    "о-ва Питкэрн",
+    "Польша",
+    "Португалия",
+    "Гвинея-Бисау",
+    "Восточный Тимор",
+    "Пуэрто-Рико",
+    "Катар",
+    "Реюньон",
+    "Румыния",
+    "Россия",
+    "Руанда",
+    "Сен-Бартелеми",
+    "о-в Св. Елены",
+    "Сент-Китс и Невис",
+    "Ангилья",
+    "Сент-Люсия",
+    "Сен-Мартен",
+    "Сен-Пьер и Микелон",
+    "Сент-Винсент и Гренадины",
+    "Сан-Марино",
+    "Сан-Томе и Принсипи",
+    "Саудовская Аравия",
+    "Сенегал",
+    "Сербия",
+    "Сейшельские о-ва",
+    "Сьерра-Леоне",
+    "Сингапур",
+    "Словакия",
+    "Вьетнам",
+    "Словения",
+    "Сомали",
+    "Южно-Африканская Республика",
+    "Зимбабве",
+    "Испания",
+    "Южный Судан",
+    "Судан",
+    "Западная Сахара",
+    "Суринам",
+    "Шпицберген и Ян-Майен",
+    "Эсватини",
+    "Швеция",
+    "Швейцария",
+    "Сирия",
+    "Таджикистан",
+    "Таиланд",
+    "Того",
+    "Токелау",
+    "Тонга",
+    "Тринидад и Тобаго",
+    "ОАЭ",
+    "Тунис",
+    "Турция",
+    "Туркменистан",
+    "Тёркс и Кайкос",
+    "Тувалу",
+    "Уганда",
+    "Украина",
+    "Северная Македония",
+    "Египет",
+    "Великобритания",
+    "Гернси",
+    "Джерси",
+    "о-в Мэн",
+    "Танзания",
+    "Соединенные Штаты",
+    "Виргинские о-ва (США)",
+    "Буркина-Фасо",
+    "Уругвай",
+    "Узбекистан",
+    "Венесуэла",
+    "Уоллис и Футуна",
+    "Самоа",
+    "Йемен",
+    "Замбия",
+];
+
+static SK: [&str; 249] = [
+    "Afganistan",
+    "Albánsko",
+    "Antarktída",
+    "Alžírsko",
+    "Americká Samoa",
+    "Andorra",
+    "Angola",
+    "Antigua a Barbuda",
+    "Azerbajdžan",
+    "Argentína",
+    "Austrália",
+    "Rakúsko",
+    "Bahamy",
+    "Bahrajn",
+    "Bangladéš",
+    "Arménsko",
+    "Barbados",
+    "Belgicko",
+    "Bermudy",
+    "Bhután",
+    "Bolívia",
+    "Bosna a Hercegovina",
+    "Botswana",
+    "Bouvetov ostrov",
+    "Brazília",
+    "Belize",
+    "Britské indickooceánske územie",
+    "Šalamúnove ostrovy",
+    "Britské Panenské ostrovy",
+    "Brunej",
+    "Bulharsko",
+    "Mjanmarsko",
+    "Burundi",
+    "Bielorusko",
+    "Kambodža",
+    "Kamerun",
+    "Kanada",
+    "Kapverdy",
+    "Kajmanie ostrovy",
+    "Stredoafrická republika",
+    "Srí Lanka",
+    "Čad",
+    "Čile",
+    "Čína",
+    "Taiwan",
+    "Vianočný ostrov",
+    "Kokosové ostrovy",
+    "Kolumbia",
+    "Komory",
+    "Mayotte",
+    "Konžská republika",
+    "Konžská demokratická republika",
+    "Cookove ostrovy",
+    "Kostarika",
+    "Chorvátsko",
+    "Kuba",
+    "Cyprus",
+    "Česko",
+    "Benin",
+    "Dánsko",
+    "Dominika",
+    "Dominikánska republika",
+    "Ekvádor",
+    "Salvádor",
+    "Rovníková Guinea",
+    "Etiópia",
+    "Eritrea",
+    "Estónsko",
+    "Faerské ostrovy",
+    "Falklandy",
+    "Južná Georgia a Južné Sandwichove ostrovy",
+    "Fidži",
+    "Fínsko",
+    "Alandy",
+    "Francúzsko",
+    "Francúzska Guyana",
+    "Francúzska Polynézia",
+    "Francúzske južné a antarktické územia",
+    "Džibutsko",
+    "Gabon",
+    "Gruzínsko",
+    "Gambia",
+    "Palestínske územia",
+    "Nemecko",
+    "Ghana",
+    "Gibraltár",
+    "Kiribati",
+    "Grécko",
+    "Grónsko",
+    "Grenada",
+    "Guadeloupe",
+    "Guam",
+    "Guatemala",
+    "Guinea",
+    "Guyana",
+    "Haiti",
+    "Heardov ostrov a Macdonaldove ostrovy",
+    "Vatikán",
+    "Honduras",
+    "Hongkong – OAO Číny",
+    "Maďarsko",
+    "Island",
+    "India",
+    "Indonézia",
+    "Irán",
+    "Irak",
+    "Írsko",
+    "Izrael",
+    "Taliansko",
+    "Pobrežie Slonoviny",
+    "Jamajka",
+    "Japonsko",
+    "Kazachstan",
+    "Jordánsko",
+    "Keňa",
+    "Severná Kórea",
+    "Južná Kórea",
+    "Kuvajt",
+    "Kirgizsko",
+    "Laos",
+    "Libanon",
+    "Lesotho",
+    "Lotyšsko",
+    "Libéria",
+    "Líbya",
+    "Lichtenštajnsko",
+    "Litva",
+    "Luxembursko",
+    "Macao – OAO Číny",
+    "Madagaskar",
+    "Malawi",
+    "Malajzia",
+    "Maldivy",
+    "Mali",
+    "Malta",
+    "Martinik",
+    "Mauritánia",
+    "Maurícius",
+    "Mexiko",
+    "Monako",
+    "Mongolsko",
+    "Moldavsko",
+    "Čierna Hora",
+    "Montserrat",
+    "Maroko",
+    "Mozambik",
+    "Omán",
+    "Namíbia",
+    "Nauru",
+    "Nepál",
+    "Holandsko",
+    "Curaçao",
+    "Aruba",
+    "Svätý Martin (hol.)",
+    "Karibské Holandsko",
+    "Nová Kaledónia",
+    "Vanuatu",
+    "Nový Zéland",
+    "Nikaragua",
+    "Niger",
+    "Nigéria",
+    "Niue",
+    "Norfolk",
+    "Nórsko",
+    "Severné Mariány",
+    "Menšie odľahlé ostrovy USA",
+    "Mikronézia",
+    "Marshallove ostrovy",
+    "Palau",
+    "Pakistan",
+    "Panama",
+    "Papua-Nová Guinea",
+    "Paraguaj",
+    "Peru",
+    "Filipíny",
+    "Pitcairnove ostrovy",
+    "Poľsko",
+    "Portugalsko",
+    "Guinea-Bissau",
+    "Východný Timor",
+    "Portoriko",
+    "Katar",
+    "Réunion",
+    "Rumunsko",
+    "Rusko",
+    "Rwanda",
+    "Svätý Bartolomej",
+    "Svätá Helena",
+    "Svätý Krištof a Nevis",
+    "Anguilla",
+    "Svätá Lucia",
+    "Svätý Martin (fr.)",
+    "Saint Pierre a Miquelon",
+    "Svätý Vincent a Grenadíny",
+    "San Maríno",
+    "Svätý Tomáš a Princov ostrov",
+    "Saudská Arábia",
+    "Senegal",
+    "Srbsko",
+    "Seychely",
+    "Sierra Leone",
+    "Singapur",
+    "Slovensko",
+    "Vietnam",
+    "Slovinsko",
+    "Somálsko",
+    "Južná Afrika",
+    "Zimbabwe",
+    "Španielsko",
+    "Južný Sudán",
+    "Sudán",
+    "Západná Sahara",
+    "Surinam",
+    "Svalbard a Jan Mayen",
+    "Eswatini",
+    "Švédsko",
+    "Švajčiarsko",
+    "Sýria",
+    "Tadžikistan",
+    "Thajsko",
+    "Togo",
+    "Tokelau",
+    "Tonga",
+    "Trinidad a Tobago",
+    "Spojené arabské emiráty",
+    "Tunisko",
+    "Turecko",
+    "Turkménsko",
+    "Turks a Caicos",
+    "Tuvalu",
+    "Uganda",
+    "Ukrajina",
+    "Severné Macedónsko",
+    "Egypt",
+    "Spojené kráľovstvo",
+    "Guernsey",
+    "Jersey",
+    "Ostrov Man",
+    "Tanzánia",
+    "Spojené štáty",
+    "Americké Panenské ostrovy",
+    "Burkina Faso",
+    "Uruguaj",
+    "Uzbekistan",
+    "Venezuela",
+    "Wallis a Futuna",
+    "Samoa",
+    "Jemen",
+    "Zambia",
+];
+
+static SL: [&str; 249] = [
+    "Afganistan",
+    "Albanija",
+    "Antarktika",
+    "Alžirija",
+    "Ameriška Samoa",
+    "Andora",
+    "Angola",
+    "Antigva in Barbuda",
+    "Azerbajdžan",
+    "Argentina",
+    "Avstralija",
+    "Avstrija",
+    "Bahami",
+    "Bahrajn",
+    "Bangladeš",
+    "Armenija",
+    "Barbados",
+    "Belgija",
+    "Bermudi",
+    "Butan",
+    "Bolivija",
+    "Bosna in Hercegovina",
+    "Bocvana",
+    "Bouvetov otok",
+    "Brazilija",
+    "Belize",
+    "Britansko ozemlje v Indijskem oceanu",
+    "Salomonovi otoki",
+    "Britanski Deviški otoki",
+    "Brunej",
+    "Bolgarija",
+    "Mjanmar (Burma)",
+    "Burundi",
+    "Belorusija",
+    "Kambodža",
+    "Kamerun",
+    "Kanada",
+    "Zelenortski otoki",
+    "Kajmanski otoki",
+    "Srednjeafriška republika",
+    "Šrilanka",
+    "Čad",
+    "Čile",
+    "Kitajska",
+    "Tajvan",
+    "Božični otok",
+    "Kokosovi otoki",
+    "Kolumbija",
+    "Komori",
+    "Mayotte",
+    "Kongo - Brazzaville",
+    "Demokratična republika Kongo",
+    "Cookovi otoki",
+    "Kostarika",
+    "Hrvaška",
+    "Kuba",
+    "Ciper",
+    "Češka",
+    "Benin",
+    "Danska",
+    "Dominika",
+    "Dominikanska republika",
+    "Ekvador",
+    "Salvador",
+    "Ekvatorialna Gvineja",
+    "Etiopija",
+    "Eritreja",
+    "Estonija",
+    "Ferski otoki",
+    "Falklandski otoki",
+    "Južna Georgia in Južni Sandwichevi otoki",
+    "Fidži",
+    "Finska",
+    "Ålandski otoki",
+    "Francija",
+    "Francoska Gvajana",
+    "Francoska Polinezija",
+    "Francosko južno ozemlje",
+    "Džibuti",
+    "Gabon",
+    "Gruzija",
+    "Gambija",
+    "Palestinsko ozemlje",
+    "Nemčija",
+    "Gana",
+    "Gibraltar",
+    "Kiribati",
+    "Grčija",
+    "Grenlandija",
+    "Grenada",
+    "Guadeloupe",
+    "Guam",
+    "Gvatemala",
+    "Gvineja",
+    "Gvajana",
+    "Haiti",
+    "Heardov otok in McDonaldovi otoki",
+    "Vatikan",
+    "Honduras",
+    "Posebno upravno območje Ljudske republike Kitajske Hongkong",
+    "Madžarska",
+    "Islandija",
+    "Indija",
+    "Indonezija",
+    "Iran",
+    "Irak",
+    "Irska",
+    "Izrael",
+    "Italija",
+    "Slonokoščena obala",
+    "Jamajka",
+    "Japonska",
+    "Kazahstan",
+    "Jordanija",
+    "Kenija",
+    "Severna Koreja",
+    "Južna Koreja",
+    "Kuvajt",
+    "Kirgizistan",
+    "Laos",
+    "Libanon",
+    "Lesoto",
+    "Latvija",
+    "Liberija",
+    "Libija",
+    "Lihtenštajn",
+    "Litva",
+    "Luksemburg",
+    "Posebno upravno območje Ljudske republike Kitajske Macao",
+    "Madagaskar",
+    "Malavi",
+    "Malezija",
+    "Maldivi",
+    "Mali",
+    "Malta",
+    "Martinik",
+    "Mavretanija",
+    "Mauritius",
+    "Mehika",
+    "Monako",
+    "Mongolija",
+    "Moldavija",
+    "Črna gora",
+    "Montserrat",
+    "Maroko",
+    "Mozambik",
+    "Oman",
+    "Namibija",
+    "Nauru",
+    "Nepal",
+    "Nizozemska",
+    "Curaçao",
+    "Aruba",
+    "Sint Maarten",
+    "Nizozemski Karibi",
+    "Nova Kaledonija",
+    "Vanuatu",
+    "Nova Zelandija",
+    "Nikaragva",
+    "Niger",
+    "Nigerija",
+    "Niue",
+    "Norfolški otok",
+    "Norveška",
+    "Severni Marianski otoki",
+    "Stranski zunanji otoki Združenih držav",
+    "Mikronezija",
+    "Marshallovi otoki",
+    "Palau",
+    "Pakistan",
+    "Panama",
+    "Papua Nova Gvineja",
+    "Paragvaj",
+    "Peru",
+    "Filipini",
+    "Pitcairn",
+    "Poljska",
+    "Portugalska",
+    "Gvineja Bissau",
+    "Timor-Leste",
+    "Portoriko",
+    "Katar",
+    "Reunion",
+    "Romunija",
+    "Rusija",
+    "Ruanda",
+    "Saint Barthélemy",
+    "Sveta Helena",
+    "Saint Kitts in Nevis",
+    "Angvila",
+    "Saint Lucia",
+    "Saint Martin",
+    "Saint Pierre in Miquelon",
+    "Saint Vincent in Grenadine",
+    "San Marino",
+    "Sao Tome in Principe",
+    "Saudova Arabija",
+    "Senegal",
+    "Srbija",
+    "Sejšeli",
+    "Sierra Leone",
+    "Singapur",
+    "Slovaška",
+    "Vietnam",
+    "Slovenija",
+    "Somalija",
+    "Južnoafriška republika",
+    "Zimbabve",
+    "Španija",
+    "Južni Sudan",
+    "Sudan",
+    "Zahodna Sahara",
+    "Surinam",
+    "Svalbard in Jan Mayen",
+    "Esvatini",
+    "Švedska",
+    "Švica",
+    "Sirija",
+    "Tadžikistan",
+    "Tajska",
+    "Togo",
+    "Tokelau",
+    "Tonga",
+    "Trinidad in Tobago",
+    "Združeni arabski emirati",
+    "Tunizija",
+    "Turčija",
+    "Turkmenistan",
+    "Otoki Turks in Caicos",
+    "Tuvalu",
+    "Uganda",
+    "Ukrajina",
+    "Severna Makedonija",
+    "Egipt",
+    "Združeno kraljestvo",
+    "Guernsey",
+    "Jersey",
+    "Otok Man",
+    "Tanzanija",
+    "Združene države Amerike",
+    "Ameriški Deviški otoki",
+    "Burkina Faso",
+    "Urugvaj",
+    "Uzbekistan",
+    "Venezuela",
+    "Wallis in Futuna",
+    "Samoa",
+    "Jemen",
+    "Zambija",
+];
+
+static SR: [&str; 249] = [
+    "Авганистан",
+    "Албанија",
+    "Антарктик",
+    "Алжир",
+    "Америчка Самоа",
+    "Андора",
+    "Ангола",
+    "Антигва и Барбуда",
+    "Азербејџан",
+    "Аргентина",
+    "Аустралија",
+    "Аустрија",
+    "Бахами",
+    "Бахреин",
+    "Бангладеш",
+    "Јерменија",
+    "Барбадос",
+    "Белгија",
+    "Бермуди",
+    "Бутан",
+    "Боливија",
+    "Босна и Херцеговина",
+    "Боцвана",
+    "Острво Буве",
+    "Бразил",
+    "Белизе",
+    "Британска територија Индијског океана",
+    "Соломонска Острва",
+    "Британска Девичанска Острва",
+    "Брунеј",
+    "Бугарска",
+    "Мијанмар (Бурма)",
+    "Бурунди",
+    "Белорусија",
+    "Камбоџа",
+    "Камерун",
+    "Канада",
+    "Зеленортска Острва",
+    "Кајманска Острва",
+    "Централноафричка Република",
+    "Шри Ланка",
+    "Чад",
+    "Чиле",
+    "Кина",
+    "Тајван",
+    "Божићно Острво",
+    "Кокосова (Килингова) Острва",
+    "Колумбија",
+    "Коморска Острва",
+    "Мајот",
+    "Конго - Бразавил",
+    "Конго - Киншаса",
+    "Кукова Острва",
+    "Костарика",
+    "Хрватска",
+    "Куба",
+    "Кипар",
+    "Чешка",
+    "Бенин",
+    "Данска",
+    "Доминика",
+    "Доминиканска Република",
+    "Еквадор",
+    "Салвадор",
+    "Екваторијална Гвинеја",
+    "Етиопија",
+    "Еритреја",
+    "Естонија",
+    "Фарска Острва",
+    "Фокландска Острва",
+    "Јужна Џорџија и Јужна Сендвичка Острва",
+    "Фиџи",
+    "Финска",
+    "Оландска Острва",
+    "Француска",
+    "Француска Гвајана",
+    "Француска Полинезија",
+    "Француске Јужне Територије",
+    "Џибути",
+    "Габон",
+    "Грузија",
+    "Гамбија",
+    "Палестинске територије",
+    "Немачка",
+    "Гана",
+    "Гибралтар",
+    "Кирибати",
+    "Грчка",
+    "Гренланд",
+    "Гренада",
+    "Гваделуп",
+    "Гуам",
+    "Гватемала",
+    "Гвинеја",
+    "Гвајана",
+    "Хаити",
+    "Острво Херд и Мекдоналдова острва",
+    "Ватикан",
+    "Хондурас",
+    "САР Хонгконг (Кина)",
+    "Мађарска",
+    "Исланд",
+    "Индија",
+    "Индонезија",
+    "Иран",
+    "Ирак",
+    "Ирска",
+    "Израел",
+    "Италија",
+    "Обала Слоноваче (Кот д’Ивоар)",
+    "Јамајка",
+    "Јапан",
+    "Казахстан",
+    "Јордан",
+    "Кенија",
+    "Северна Кореја",
+    "Јужна Кореја",
+    "Кувајт",
+    "Киргистан",
+    "Лаос",
+    "Либан",
+    "Лесото",
+    "Летонија",
+    "Либерија",
+    "Либија",
+    "Лихтенштајн",
+    "Литванија",
+    "Луксембург",
+    "САР Макао (Кина)",
+    "Мадагаскар",
+    "Малави",
+    "Малезија",
+    "Малдиви",
+    "Мали",
+    "Малта",
+    "Мартиник",
+    "Мауританија",
+    "Маурицијус",
+    "Мексико",
+    "Монако",
+    "Монголија",
+    "Молдавија",
+    "Црна Гора",
+    "Монсерат",
+    "Мароко",
+    "Мозамбик",
+    "Оман",
+    "Намибија",
+    "Науру",
+    "Непал",
+    "Холандија",
+    "Курасао",
+    "Аруба",
+    "Свети Мартин (Холандија)",
+    "Карипска Холандија",
+    "Нова Каледонија",
+    "Вануату",
+    "Нови Зеланд",
+    "Никарагва",
+    "Нигер",
+    "Нигерија",
+    "Ниуе",
+    "Острво Норфок",
+    "Норвешка",
+    "Северна Маријанска Острва",
+    "Удаљена острва САД",
+    "Микронезија",
+    "Маршалска Острва",
+    "Палау",
+    "Пакистан",
+    "Панама",
+    "Папуа Нова Гвинеја",
+    "Парагвај",
+    "Перу",
+    "Филипини",
+    "Питкерн",
+    "Пољска",
+    "Португалија",
+    "Гвинеја-Бисао",
+    "Тимор-Лесте (Источни Тимор)",
+    "Порторико",
+    "Катар",
+    "Реинион",
+    "Румунија",
+    "Русија",
+    "Руанда",
+    "Свети Бартоломеј",
+    "Света Јелена",
+    "Сент Китс и Невис",
+    "Ангвила",
+    "Света Луција",
+    "Свети Мартин (Француска)",
+    "Сен Пјер и Микелон",
+    "Сент Винсент и Гренадини",
+    "Сан Марино",
+    "Сао Томе и Принципе",
+    "Саудијска Арабија",
+    "Сенегал",
+    "Србија",
+    "Сејшели",
+    "Сијера Леоне",
+    "Сингапур",
+    "Словачка",
+    "Вијетнам",
+    "Словенија",
+    "Сомалија",
+    "Јужноафричка Република",
+    "Зимбабве",
+    "Шпанија",
+    "Јужни Судан",
+    "Судан",
+    "Западна Сахара",
+    "Суринам",
+    "Свалбард и Јан Мајен",
+    "Свазиленд",
+    "Шведска",
+    "Швајцарска",
+    "Сирија",
+    "Таџикистан",
+    "Тајланд",
+    "Того",
+    "Токелау",
+    "Тонга",
+    "Тринидад и Тобаго",
+    "Уједињени Арапски Емирати",
+    "Тунис",
+    "Турска",
+    "Туркменистан",
+    "Острва Туркс и Каикос",
+    "Тувалу",
+    "Уганда",
+    "Украјина",
+    "Северна Македонија",
+    "Египат",
+    "Уједињено Краљевство",
+    "Гернзи",
+    "Џерзи",
+    "Острво Ман",
+    "Танзанија",
+    "Сједињене Државе",
+    "Америчка Девичанска Острва",
+    "Буркина Фасо",
+    "Уругвај",
+    "Узбекистан",
+    "Венецуела",
+    "Валис и Футуна",
+    "Самоа",
+    "Јемен",
+    "Замбија",
+];
+
+static SR_LATN: [&str; 249] = [
+    "Avganistan",
+    "Albanija",
+    "Antarktik",
+    "Alžir",
+    "Američka Samoa",
+    "Andora",
+    "Angola",
+    "Antigva i Barbuda",
+    "Azerbejdžan",
+    "Argentina",
+    "Australija",
+    "Austrija",
+    "Bahami",
+    "Bahrein",
+    "Bangladeš",
+    "Jermenija",
+    "Barbados",
+    "Belgija",
+    "Bermudi",
+    "Butan",
+    "Bolivija",
+    "Bosna i Hercegovina",
+    "Bocvana",
+    "Ostrvo Buve",
+    "Brazil",
+    "Belize",
+    "Britanska teritorija Indijskog okeana",
+    "Solomonska Ostrva",
+    "Britanska Devičanska Ostrva",
+    "Brunej",
+    "Bugarska",
+    "Mijanmar (Burma)",
+    "Burundi",
+    "Belorusija",
+    "Kambodža",
+    "Kamerun",
+    "Kanada",
+    "Zelenortska Ostrva",
+    "Kajmanska Ostrva",
+    "Centralnoafrička Republika",
+    "Šri Lanka",
+    "Čad",
+    "Čile",
+    "Kina",
+    "Tajvan",
+    "Božićno Ostrvo",
+    "Kokosova (Kilingova) Ostrva",
+    "Kolumbija",
+    "Komorska Ostrva",
+    "Majot",
+    "Kongo - Brazavil",
+    "Kongo - Kinšasa",
+    "Kukova Ostrva",
+    "Kostarika",
+    "Hrvatska",
+    "Kuba",
+    "Kipar",
+    "Češka",
+    "Benin",
+    "Danska",
+    "Dominika",
+    "Dominikanska Republika",
+    "Ekvador",
+    "Salvador",
+    "Ekvatorijalna Gvineja",
+    "Etiopija",
+    "Eritreja",
+    "Estonija",
+    "Farska Ostrva",
+    "Foklandska Ostrva",
+    "Južna Džordžija i Južna Sendvička Ostrva",
+    "Fidži",
+    "Finska",
+    "Olandska Ostrva",
+    "Francuska",
+    "Francuska Gvajana",
+    "Francuska Polinezija",
+    "Francuske Južne Teritorije",
+    "Džibuti",
+    "Gabon",
+    "Gruzija",
+    "Gambija",
+    "Palestinske teritorije",
+    "Nemačka",
+    "Gana",
+    "Gibraltar",
+    "Kiribati",
+    "Grčka",
+    "Grenland",
+    "Grenada",
+    "Gvadelup",
+    "Guam",
+    "Gvatemala",
+    "Gvineja",
+    "Gvajana",
+    "Haiti",
+    "Ostrvo Herd i Mekdonaldova ostrva",
+    "Vatikan",
+    "Honduras",
+    "SAR Hongkong (Kina)",
+    "Mađarska",
+    "Island",
+    "Indija",
+    "Indonezija",
+    "Iran",
+    "Irak",
+    "Irska",
+    "Izrael",
+    "Italija",
+    "Obala Slonovače (Kot d’Ivoar)",
+    "Jamajka",
+    "Japan",
+    "Kazahstan",
+    "Jordan",
+    "Kenija",
+    "Severna Koreja",
+    "Južna Koreja",
+    "Kuvajt",
+    "Kirgistan",
+    "Laos",
+    "Liban",
+    "Lesoto",
+    "Letonija",
+    "Liberija",
+    "Libija",
+    "Lihtenštajn",
+    "Litvanija",
+    "Luksemburg",
+    "SAR Makao (Kina)",
+    "Madagaskar",
+    "Malavi",
+    "Malezija",
+    "Maldivi",
+    "Mali",
+    "Malta",
+    "Martinik",
+    "Mauritanija",
+    "Mauricijus",
+    "Meksiko",
+    "Monako",
+    "Mongolija",
+    "Moldavija",
+    "Crna Gora",
+    "Monserat",
+    "Maroko",
+    "Mozambik",
+    "Oman",
+    "Namibija",
+    "Nauru",
+    "Nepal",
+    "Holandija",
+    "Kurasao",
+    "Aruba",
+    "Sveti Martin (Holandija)",
+    "Karipska Holandija",
+    "Nova Kaledonija",
+    "Vanuatu",
+    "Novi Zeland",
+    "Nikaragva",
+    "Niger",
+    "Nigerija",
+    "Niue",
+    "Ostrvo Norfok",
+    "Norveška",
+    "Severna Marijanska Ostrva",
+    "Udaljena ostrva SAD",
+    "Mikronezija",
+    "Maršalska Ostrva",
+    "Palau",
+    "Pakistan",
+    "Panama",
+    "Papua Nova Gvineja",
+    "Paragvaj",
+    "Peru",
+    "Filipini",
+    "Pitkern",
+    "Poljska",
+    "Portugalija",
+    "Gvineja-Bisao",
+    "Timor-Leste (Istočni Timor)",
+    "Portoriko",
+    "Katar",
+    "Reinion",
+    "Rumunija",
+    "Rusija",
+    "Ruanda",
+    "Sveti Bartolomej",
+    "Sveta Jelena",
+    "Sent Kits i Nevis",
+    "Angvila",
+    "Sveta Lucija",
+    "Sveti Martin (Francuska)",
+    "Sen Pjer i Mikelon",
+    "Sent Vinsent i Grenadini",
+    "San Marino",
+    "Sao Tome i Principe",
+    "Saudijska Arabija",
+    "Senegal",
+    "Srbija",
+    "Sejšeli",
+    "Sijera Leone",
+    "Singapur",
+    "Slovačka",
+    "Vijetnam",
+    "Slovenija",
+    "Somalija",
+    "Južnoafrička Republika",
+    "Zimbabve",
+    "Španija",
+    "Južni Sudan",
+    "Sudan",
+    "Zapadna Sahara",
+    "Surinam",
+    "Svalbard i Jan Majen",
+    "Svazilend",
+    "Švedska",
+    "Švajcarska",
+    "Sirija",
+    "Tadžikistan",
+    "Tajland",
+    "Togo",
+    "Tokelau",
+    "Tonga",
+    "Trinidad i Tobago",
+    "Ujedinjeni Arapski Emirati",
+    "Tunis",
+    "Turska",
+    "Turkmenistan",
+    "Ostrva Turks i Kaikos",
+    "Tuvalu",
+    "Uganda",
+    "Ukrajina",
+    "Severna Makedonija",
+    "Egipat",
+    "Ujedinjeno Kraljevstvo",
+    "Gernzi",
+    "Džerzi",
+    "Ostrvo Man",
+    "Tanzanija",
+    "Sjedinjene Države",
+    "Američka Devičanska Ostrva",
+    "Burkina Faso",
+    "Urugvaj",
+    "Uzbekistan",
+    "Venecuela",
+    "Valis i Futuna",
+    "Samoa",
+    "Jemen",
+    "Zambija",
+];
+
+static SV: [&str; 249] = [
+    "Afghanistan",
+    "Albanien",
+    "Antarktis",
+    "Algeriet",
+    "Amerikanska Samoa",
+    "Andorra",
+    "Angola",
+    "Antigua och Barbuda",
+    "Azerbajdzjan",
+    "Argentina",
+    "Australien",
+    "Österrike",
+    "Bahamas",
+    "Bahrain",
+    "Bangladesh",
+    "Armenien",
+    "Barbados",
+    "Belgien",
+    "Bermuda",
+    "Bhutan",
+    "Bolivia",
+    "Bosnien och Hercegovina",
+    "Botswana",
+    "Bouvetön",
+    "Brasilien",
+    "Belize",
+    "Brittiska territoriet i Indiska oceanen",
+    "Salomonöarna",
+    "Brittiska Jungfruöarna",
+    "Brunei",
+    "Bulgarien",
+    "Myanmar (Burma)",
+    "Burundi",
+    "Belarus",
+    "Kambodja",
+    "Kamerun",
+    "Kanada",
+    "Kap Verde",
+    "Caymanöarna",
+    "Centralafrikanska republiken",
+    "Sri Lanka",
+    "Tchad",
+    "Chile",
+    "Kina",
+    "Taiwan",
+    "Julön",
+    "Kokosöarna",
+    "Colombia",
+    "Komorerna",
+    "Mayotte",
+    "Kongo-Brazzaville",
+    "Kongo-Kinshasa",
+    "Cooköarna",
+    "Costa Rica",
+    "Kroatien",
+    "Kuba",
+    "Cypern",
+    "Tjeckien",
+    "Benin",
+    "Danmark",
+    "Dominica",
+    "Dominikanska republiken",
+    "Ecuador",
+    "El Salvador",
+    "Ekvatorialguinea",
+    "Etiopien",
+    "Eritrea",
+    "Estland",
+    "Färöarna",
+    "Falklandsöarna",
+    "Sydgeorgien och Sydsandwichöarna",
+    "Fiji",
+    "Finland",
+    "Åland",
+    "Frankrike",
+    "Franska Guyana",
+    "Franska Polynesien",
+    "Franska sydterritorierna",
+    "Djibouti",
+    "Gabon",
+    "Georgien",
+    "Gambia",
+    "Palestinska territorierna",
+    "Tyskland",
+    "Ghana",
+    "Gibraltar",
+    "Kiribati",
+    "Grekland",
+    "Grönland",
+    "Grenada",
+    "Guadeloupe",
+    "Guam",
+    "Guatemala",
+    "Guinea",
+    "Guyana",
+    "Haiti",
+    "Heardön och McDonaldöarna",
+    "Vatikanstaten",
+    "Honduras",
+    "Hongkong SAR",
+    "Ungern",
+    "Island",
+    "Indien",
+    "Indonesien",
+    "Iran",
+    "Irak",
+    "Irland",
+    "Israel",
+    "Italien",
+    "Elfenbenskusten",
+    "Jamaica",
+    "Japan",
+    "Kazakstan",
+    "Jordanien",
+    "Kenya",
+    "Nordkorea",
+    "Sydkorea",
+    "Kuwait",
+    "Kirgizistan",
+    "Laos",
+    "Libanon",
+    "Lesotho",
+    "Lettland",
+    "Liberia",
+    "Libyen",
+    "Liechtenstein",
+    "Litauen",
+    "Luxemburg",
+    "Macao SAR",
+    "Madagaskar",
+    "Malawi",
+    "Malaysia",
+    "Maldiverna",
+    "Mali",
+    "Malta",
+    "Martinique",
+    "Mauretanien",
+    "Mauritius",
+    "Mexiko",
+    "Monaco",
+    "Mongoliet",
+    "Moldavien",
+    "Montenegro",
+    "Montserrat",
+    "Marocko",
+    "Moçambique",
+    "Oman",
+    "Namibia",
+    "Nauru",
+    "Nepal",
+    "Nederländerna",
+    "Curaçao",
+    "Aruba",
+    "Sint Maarten",
+    "Karibiska Nederländerna",
+    "Nya Kaledonien",
+    "Vanuatu",
+    "Nya Zeeland",
+    "Nicaragua",
+    "Niger",
+    "Nigeria",
+    "Niue",
+    "Norfolkön",
+    "Norge",
+    "Nordmarianerna",
+    "USA:s yttre öar",
+    "Mikronesien",
+    "Marshallöarna",
+    "Palau",
+    "Pakistan",
+    "Panama",
+    "Papua Nya Guinea",
+    "Paraguay",
+    "Peru",
+    "Filippinerna",
+    "Pitcairnöarna",
+    "Polen",
+    "Portugal",
+    "Guinea-Bissau",
+    "Östtimor",
+    "Puerto Rico",
+    "Qatar",
+    "Réunion",
+    "Rumänien",
+    "Ryssland",
+    "Rwanda",
+    "S:t Barthélemy",
+    "S:t Helena",
+    "S:t Kitts och Nevis",
+    "Anguilla",
+    "S:t Lucia",
+    "Saint-Martin",
+    "S:t Pierre och Miquelon",
+    "S:t Vincent och Grenadinerna",
+    "San Marino",
+    "São Tomé och Príncipe",
+    "Saudiarabien",
+    "Senegal",
+    "Serbien",
+    "Seychellerna",
+    "Sierra Leone",
+    "Singapore",
+    "Slovakien",
+    "Vietnam",
+    "Slovenien",
+    "Somalia",
+    "Sydafrika",
+    "Zimbabwe",
+    "Spanien",
+    "Sydsudan",
+    "Sudan",
+    "Västsahara",
+    "Surinam",
+    "Svalbard och Jan Mayen",
+    "Eswatini",
+    "Sverige",
+    "Schweiz",
+    "Syrien",
+    "Tadzjikistan",
+    "Thailand",
+    "Togo",
+    "Tokelauöarna",
+    "Tonga",
+    "Trinidad och Tobago",
+    "Förenade Arabemiraten",
+    "Tunisien",
+    "Turkiet",
+    "Turkmenistan",
+    "Turks- och Caicosöarna",
+    "Tuvalu",
+    "Uganda",
+    "Ukraina",
+    "Nordmakedonien",
+    "Egypten",
+    "Storbritannien",
+    "Guernsey",
+    "Jersey",
+    "Isle of Man",
+    "Tanzania",
+    "USA",
+    "Amerikanska Jungfruöarna",
+    "Burkina Faso",
+    "Uruguay",
+    "Uzbekistan",
+    "Venezuela",
+    "Wallis- och Futunaöarna",
+    "Samoa",
+    "Jemen",
+    "Zambia",
+];
+
+static SW: [&str; 249] = [
+    "Afghanistan",
+    "Albania",
+    "Antaktiki",
+    "Aljeria",
+    "Samoa ya Marekani",
+    "Andorra",
+    "Angola",
+    "Antigua na Barbuda",
+    "Azerbaijani",
+    "Ajentina",
+    "Australia",
+    "Austria",
+    "Bahama",
+    "Bahareni",
+    "Bangladeshi",
+    "Armenia",
+    "Babadosi",
+    "Ubelgiji",
+    "Bermuda",
+    "Bhutan",
+    "Bolivia",
+    "Bosnia na Hezegovina",
+    "Botswana",
+    "Kisiwa cha Bouvet",
+    "Brazil",
+    "Belize",
+    "Eneo la Uingereza katika Bahari Hindi",
+    "Visiwa vya Solomon",
+    "Visiwa vya Virgin, Uingereza",
+    "Brunei",
+    "Bulgaria",
+    "Myanmar (Burma)",
+    "Burundi",
+    "Belarus",
+    "Kambodia",
+    "Kameruni",
+    "Kanada",
+    "Cape Verde",
+    "Visiwa vya Cayman",
+    "Jamhuri ya Afrika ya Kati",
+    "Sri Lanka",
+    "Chad",
+    "Chile",
+    "Uchina",
+    "Taiwan",
+    "Kisiwa cha Krismasi",
+    "Visiwa vya Cocos (Keeling)",
+    "Kolombia",
+    "Komoro",
+    "Mayotte",
+    "Kongo - Brazzaville",
+    "Jamhuri ya Kidemokrasia ya Kongo",
+    "Visiwa vya Cook",
+    "Kostarika",
+    "Croatia",
+    "Kuba",
+    "Saiprasi",
+    "Chechia",
+    "Benin",
+    "Denmaki",
+    "Dominika",
+    "Jamhuri ya Dominika",
+    "Ecuador",
+    "El Salvador",
+    "Guinea ya Ikweta",
+    "Ethiopia",
+    "Eritrea",
+    "Estonia",
+    "Visiwa vya Faroe",
+    "Visiwa vya Falkland",
+    "Visiwa vya Georgia Kusini na Sandwich Kusini",
+    "Fiji",
+    "Ufini",
+    "Visiwa vya Aland",
+    "Ufaransa",
+    "Guiana ya Ufaransa",
+    "Polynesia ya Ufaransa",
+    "Himaya za Kusini za Kifaranza",
+    "Jibuti",
+    "Gabon",
+    "Jojia",
+    "Gambia",
+    "Maeneo ya Palestina",
+    "Ujerumani",
+    "Ghana",
+    "Gibraltar",
+    "Kiribati",
+    "Ugiriki",
+    "Greenland",
+    "Grenada",
+    "Guadeloupe",
+    "Guam",
+    "Guatemala",
+    "Gine",
+    "Guyana",
+    "Haiti",
+    "Kisiwa cha Heard na Visiwa vya McDonald",
+    "Mji wa Vatican",
+    "Honduras",
+    "Hong Kong SAR China",
+    "Hungaria",
+    "Aisilandi",
+    "India",
+    "Indonesia",
+    "Iran",
+    "Iraki",
+    "Ayalandi",
+    "Israeli",
+    "Italia",
+    "Cote d’Ivoire",
+    "Jamaika",
+    "Japani",
+    "Kazakistani",
+    "Jordan",
+    "Kenya",
+    "Korea Kaskazini",
+    "Korea Kusini",
+    "Kuwait",
+    "Kirigizistani",
+    "Laos",
+    "Lebanon",
+    "Lesoto",
+    "Latvia",
+    "Liberia",
+    "Libya",
+    "Liechtenstein",
+    "Lithuania",
+    "Luxembourg",
+    "Makau SAR China",
+    "Madagaska",
+    "Malawi",
+    "Malesia",
+    "Maldivi",
+    "Mali",
+    "Malta",
+    "Martinique",
+    "Moritania",
+    "Morisi",
+    "Meksiko",
+    "Monaco",
+    "Mongolia",
+    "Moldova",
+    "Montenegro",
+    "Montserrat",
+    "Morocco",
+    "Msumbiji",
+    "Oman",
+    "Namibia",
+    "Nauru",
+    "Nepal",
+    "Uholanzi",
+    "Curacao",
+    "Aruba",
+    "Sint Maarten",
+    "Uholanzi ya Karibiani",
+    "New Caledonia",
+    "Vanuatu",
+    "Nyuzilandi",
+    "Nikaragwa",
+    "Niger",
+    "Nigeria",
+    "Niue",
+    "Kisiwa cha Norfolk",
+    "Norway",
+    "Visiwa vya Mariana vya Kaskazini",
+    "Visiwa Vidogo vya Nje vya Marekani",
+    "Mikronesia",
+    "Visiwa vya Marshall",
+    "Palau",
+    "Pakistani",
+    "Panama",
+    "Papua New Guinea",
+    "Paraguay",
+    "Peru",
+    "Ufilipino",
+    "Visiwa vya Pitcairn",
+    "Poland",
+    "Ureno",
+    "Ginebisau",
+    "Timor-Leste",
+    "Puerto Rico",
+    "Qatar",
+    "Reunion",
+    "Romania",
+    "Urusi",
+    "Rwanda",
+    "St. Barthelemy",
+    "St. Helena",
+    "St. Kitts na Nevis",
+    "Anguilla",
+    "St. Lucia",
+    "St. Martin",
+    "Santapierre na Miquelon",
+    "St. Vincent na Grenadines",
+    "San Marino",
+    "Sao Tome na Principe",
+    "Saudia",
+    "Senegali",
+    "Serbia",
+    "Ushelisheli",
+    "Siera Leoni",
+    "Singapore",
+    "Slovakia",
+    "Vietnamu",
+    "Slovenia",
+    "Somalia",
+    "Afrika Kusini",
+    "Zimbabwe",
+    "Uhispania",
+    "Sudan Kusini",
+    "Sudan",
+    "Sahara Magharibi",
+    "Suriname",
+    "Svalbard na Jan Mayen",
+    "Eswatini",
+    "Uswidi",
+    "Uswisi",
+    "Syria",
+    "Tajikistani",
+    "Tailandi",
+    "Togo",
+    "Tokelau",
+    "Tonga",
+    "Trinidad na Tobago",
+    "Falme za Kiarabu",
+    "Tunisia",
+    "Uturuki",
+    "Turkmenistan",
+    "Visiwa vya Turks na Caicos",
+    "Tuvalu",
+    "Uganda",
+    "Ukraine",
+    "Masedonia ya Kaskazini",
+    "Misri",
+    "Ufalme wa Muungano",
+    "Guernsey",
+    "Jersey",
+    "Kisiwa cha Man",
+    "Tanzania",
+    "Marekani",
+    "Visiwa vya Virgin, Marekani",
+    "Bukinafaso",
+    "Uruguay",
+    "Uzibekistani",
+    "Venezuela",
+    "Wallis na Futuna",
+    "Samoa",
+    "Yemeni",
+    "Zambia",
+];
+
+static TH: [&str; 249] = [
+    "อัฟกานิสถาน",
+    "แอลเบเนีย",
+    "แอนตาร์กติกา",
+    "แอลจีเรีย",
+    "อเมริกันซามัว",
+    "อันดอร์รา",
+    "แองโกลา",
+    "แอนติกาและบาร์บูดา",
+    "อาเซอร์ไบจาน",
+    "อาร์เจนตินา",
+    "ออสเตรเลีย",
+    "ออสเตรีย",
+    "บาฮามาส",
+    "บาห์เรน",
+    "บังกลาเทศ",
+    "อาร์เมเนีย",
+    "บาร์เบโดส",
+    "เบลเยียม",
+    "เบอร์มิวดา",
+    "ภูฏาน",
+    "โบลิเวีย",
+    "บอสเนียและเฮอร์เซโกวีนา",
+    "บอตสวานา",
+    "เกาะบูเว",
+    "บราซิล",
+    "เบลีซ",
+    "บริติชอินเดียนโอเชียนเทร์ริทอรี",
+    "หมู่เกาะโซโลมอน",
+    "หมู่เกาะบริติชเวอร์จิน",
+    "บรูไน",
+    "บัลแกเรีย",
+    "เมียนมา (พม่า)",
+    "บุรุนดี",
+    "เบลารุส",
+    "กัมพูชา",
+    "แคเมอรูน",
+    "แคนาดา",
+    "เคปเวิร์ด",
+    "หมู่เกาะเคย์แมน",
+    "สาธารณรัฐแอฟริกากลาง",
+    "ศรีลังกา",
+    "ชาด",
+    "ชิลี",
+    "จีน",
+    "ไต้หวัน",
+    "เกาะคริสต์มาส",
+    "หมู่เกาะโคโคส (คีลิง)",
+    "โคลอมเบีย",
+    "คอโมโรส",
+    "มายอต",
+    "คองโก - บราซซาวิล",
+    "คองโก - กินชาซา",
+    "หมู่เกาะคุก",
+    "คอสตาริกา",
+    "โครเอเชีย",
+    "คิวบา",
+    "ไซปรัส",
+    "เช็ก",
+    "เบนิน",
+    "เดนมาร์ก",
+    "โดมินิกา",
+    "สาธารณรัฐโดมินิกัน",
+    "เอกวาดอร์",
+    "เอลซัลวาดอร์",
+    "อิเควทอเรียลกินี",
+    "เอธิโอเปีย",
+    "เอริเทรีย",
+    "เอสโตเนีย",
+    "หมู่เกาะแฟโร",
+    "หมู่เกาะฟอล์กแลนด์",
+    "เกาะเซาท์จอร์เจียและหมู่เกาะเซาท์แซนด์วิช",
+    "ฟิจิ",
+    "ฟินแลนด์",
+    "หมู่เกาะโอลันด์",
+    "ฝรั่งเศส",
+    "เฟรนช์เกียนา",
+    "เฟรนช์โปลินีเซีย",
+    "เฟรนช์เซาเทิร์นเทร์ริทอรีส์",
+    "จิบูตี",
+    "กาบอง",
+    "จอร์เจีย",
+    "แกมเบีย",
+    "ดินแดนปาเลสไตน์",
+    "เยอรมนี",
+    "กานา",
+    "ยิบรอลตาร์",
+    "คิริบาส",
+    "กรีซ",
+    "กรีนแลนด์",
+    "เกรเนดา",
+    "กวาเดอลูป",
+    "กวม",
+    "กัวเตมาลา",
+    "กินี",
+    "กายอานา",
+    "เฮติ",
+    "เกาะเฮิร์ดและหมู่เกาะแมกดอนัลด์",
+    "นครวาติกัน",
+    "ฮอนดูรัส",
+    "เขตปกครองพิเศษฮ่องกงแห่งสาธารณรัฐประชาชนจีน",
+    "ฮังการี",
+    "ไอซ์แลนด์",
+    "อินเดีย",
+    "อินโดนีเซีย",
+    "อิหร่าน",
+    "อิรัก",
+    "ไอร์แลนด์",
+    "อิสราเอล",
+    "อิตาลี",
+    "โกตดิวัวร์",
+    "จาเมกา",
+    "ญี่ปุ่น",
+    "คาซัคสถาน",
+    "จอร์แดน",
+    "เคนยา",
+    "เกาหลีเหนือ",
+    "เกาหลีใต้",
+    "คูเวต",
+    "คีร์กีซสถาน",
+    "ลาว",
+    "เลบานอน",
+    "เลโซโท",
+    "ลัตเวีย",
+    "ไลบีเรีย",
+    "ลิเบีย",
+    "ลิกเตนสไตน์",
+    "ลิทัวเนีย",
+    "ลักเซมเบิร์ก",
+    "เขตปกครองพิเศษมาเก๊าแห่งสาธารณรัฐประชาชนจีน",
+    "มาดากัสการ์",
+    "มาลาวี",
+    "มาเลเซีย",
+    "มัลดีฟส์",
+    "มาลี",
+    "มอลตา",
+    "มาร์ตินีก",
+    "มอริเตเนีย",
+    "มอริเชียส",
+    "เม็กซิโก",
+    "โมนาโก",
+    "มองโกเลีย",
+    "มอลโดวา",
+    "มอนเตเนโกร",
+    "มอนต์เซอร์รัต",
+    "โมร็อกโก",
+    "โมซัมบิก",
+    "โอมาน",
+    "นามิเบีย",
+    "นาอูรู",
+    "เนปาล",
+    "เนเธอร์แลนด์",
+    "คูราเซา",
+    "อารูบา",
+    "ซินต์มาร์เทน",
+    "เนเธอร์แลนด์แคริบเบียน",
+    "นิวแคลิโดเนีย",
+    "วานูอาตู",
+    "นิวซีแลนด์",
+    "นิการากัว",
+    "ไนเจอร์",
+    "ไนจีเรีย",
+    "นีอูเอ",
+    "เกาะนอร์ฟอล์ก",
+    "นอร์เวย์",
+    "หมู่เกาะนอร์เทิร์นมาเรียนา",
+    "หมู่เกาะรอบนอกของสหรัฐอเมริกา",
+    "ไมโครนีเซีย",
+    "หมู่เกาะมาร์แชลล์",
+    "ปาเลา",
+    "ปากีสถาน",
+    "ปานามา",
+    "ปาปัวนิวกินี",
+    "ปารากวัย",
+    "เปรู",
+    "ฟิลิปปินส์",
+    "หมู่เกาะพิตแคร์น",
+    "โปแลนด์",
+    "โปรตุเกส",
+    "กินี-บิสเซา",
+    "ติมอร์-เลสเต",
+    "เปอร์โตริโก",
+    "กาตาร์",
+    "เรอูนียง",
+    "โรมาเนีย",
+    "รัสเซีย",
+    "รวันดา",
+    "เซนต์บาร์เธเลมี",
+    "เซนต์เฮเลนา",
+    "เซนต์คิตส์และเนวิส",
+    "แองกวิลลา",
+    "เซนต์ลูเซีย",
+    "เซนต์มาร์ติน",
+    "แซงปีแยร์และมีเกอลง",
+    "เซนต์วินเซนต์และเกรนาดีนส์",
+    "ซานมาริโน",
+    "เซาตูเมและปรินซิปี",
+    "ซาอุดีอาระเบีย",
+    "เซเนกัล",
+    "เซอร์เบีย",
+    "เซเชลส์",
+    "เซียร์ราลีโอน",
+    "สิงคโปร์",
+    "สโลวะเกีย",
+    "เวียดนาม",
+    "สโลวีเนีย",
+    "โซมาเลีย",
+    "แอฟริกาใต้",
+    "ซิมบับเว",
+    "สเปน",
+    "ซูดานใต้",
+    "ซูดาน",
+    "ซาฮาราตะวันตก",
+    "ซูรินาเม",
+    "สฟาลบาร์และยานไมเอน",
+    "เอสวาตีนี",
+    "สวีเดน",
+    "สวิตเซอร์แลนด์",
+    "ซีเรีย",
+    "ทาจิกิสถาน",
+    "ไทย",
+    "โตโก",
+    "โตเกเลา",
+    "ตองกา",
+    "ตรินิแดดและโตเบโก",
+    "สหรัฐอาหรับเอมิเรตส์",
+    "ตูนิเซีย",
+    "ตุรกี",
+    "เติร์กเมนิสถาน",
+    "หมู่เกาะเติกส์และหมู่เกาะเคคอส",
+    "ตูวาลู",
+    "ยูกันดา",
+    "ยูเครน",
+    "มาซิโดเนียเหนือ",
+    "อียิปต์",
+    "สหราชอาณาจักร",
+    "เกิร์นซีย์",
+    "เจอร์ซีย์",
+    "เกาะแมน",
+    "แทนซาเนีย",
+    "สหรัฐอเมริกา",
+    "หมู่เกาะเวอร์จินของสหรัฐอเมริกา",
+    "บูร์กินาฟาโซ",
+    "อุรุกวัย",
+    "อุซเบกิสถาน",
+    "เวเนซุเอลา",
+    "วาลลิสและฟุตูนา",
+    "ซามัว",
+    "เยเมน",
+    "แซมเบีย",
+];
+
+static TR: [&str; 249] = [
+    "Afganistan",
+    "Arnavutluk",
+    "Antarktika",
+    "Cezayir",
+    "Amerikan Samoası",
+    "Andorra",
+    "Angola",
+    "Antigua ve Barbuda",
+    "Azerbaycan",
+    "Arjantin",
+    "Avustralya",
+    "Avusturya",
+    "Bahamalar",
+    "Bahreyn",
+    "Bangladeş",
+    "Ermenistan",
+    "Barbados",
+    "Belçika",
+    "Bermuda",
+    "Butan",
+    "Bolivya",
+    "Bosna-Hersek",
+    "Botsvana",
+    "Bouvet Adası",
+    "Brezilya",
+    "Belize",
+    "Britanya Hint Okyanusu Toprakları",
+    "Solomon Adaları",
+    "Britanya Virjin Adaları",
+    "Brunei",
+    "Bulgaristan",
+    "Myanmar (Burma)",
+    "Burundi",
+    "Belarus",
+    "Kamboçya",
+    "Kamerun",
+    "Kanada",
+    "Cabo Verde",
+    "Cayman Adaları",
+    "Orta Afrika Cumhuriyeti",
+    "Sri Lanka",
+    "Çad",
+    "Şili",
+    "Çin",
+    "Tayvan",
+    "Christmas Adası",
+    "Cocos (Keeling) Adaları",
+    "Kolombiya",
+    "Komorlar",
+    "Mayotte",
+    "Kongo - Brazavil",
+    "Kongo - Kinşasa",
+    "Cook Adaları",
+    "Kosta Rika",
+    "Hırvatistan",
+    "Küba",
+    "Kıbrıs",
+    "Çekya",
+    "Benin",
+    "Danimarka",
+    "Dominika",
+    "Dominik Cumhuriyeti",
+    "Ekvador",
+    "El Salvador",
+    "Ekvator Ginesi",
+    "Etiyopya",
+    "Eritre",
+    "Estonya",
+    "Faroe Adaları",
+    "Falkland Adaları",
+    "Güney Georgia ve Güney Sandwich Adaları",
+    "Fiji",
+    "Finlandiya",
+    "Åland Adaları",
+    "Fransa",
+    "Fransız Guyanası",
+    "Fransız Polinezyası",
+    "Fransız Güney Toprakları",
+    "Cibuti",
+    "Gabon",
+    "Gürcistan",
+    "Gambiya",
+    "Filistin Bölgeleri",
+    "Almanya",
+    "Gana",
+    "Cebelitarık",
+    "Kiribati",
+    "Yunanistan",
+    "Grönland",
+    "Grenada",
+    "Guadeloupe",
+    "Guam",
+    "Guatemala",
+    "Gine",
+    "Guyana",
+    "Haiti",
+    "Heard Adası ve McDonald Adaları",
+    "Vatikan",
+    "Honduras",
+    "Çin Hong Kong ÖİB",
+    "Macaristan",
+    "İzlanda",
+    "Hindistan",
+    "Endonezya",
+    "İran",
+    "Irak",
+    "İrlanda",
+    "İsrail",
+    "İtalya",
+    "Côte d’Ivoire",
+    "Jamaika",
+    "Japonya",
+    "Kazakistan",
+    "Ürdün",
+    "Kenya",
+    "Kuzey Kore",
+    "Güney Kore",
+    "Kuveyt",
+    "Kırgızistan",
+    "Laos",
+    "Lübnan",
+    "Lesotho",
+    "Letonya",
+    "Liberya",
+    "Libya",
+    "Liechtenstein",
+    "Litvanya",
+    "Lüksemburg",
+    "Çin Makao ÖİB",
+    "Madagaskar",
+    "Malavi",
+    "Malezya",
+    "Maldivler",
+    "Mali",
+    "Malta",
+    "Martinik",
+    "Moritanya",
+    "Mauritius",
+    "Meksika",
+    "Monako",
+    "Moğolistan",
+    "Moldova",
+    "Karadağ",
+    "Montserrat",
+    "Fas",
+    "Mozambik",
+    "Umman",
+    "Namibya",
+    "Nauru",
+    "Nepal",
+    "Hollanda",
+    "Curaçao",
+    "Aruba",
+    "Sint Maarten",
+    "Karayip Hollandası",
+    "Yeni Kaledonya",
+    "Vanuatu",
+    "Yeni Zelanda",
+    "Nikaragua",
+    "Nijer",
+    "Nijerya",
+    "Niue",
+    "Norfolk Adası",
+    "Norveç",
+    "Kuzey Mariana Adaları",
+    "ABD Küçük Harici Adaları",
+    "Mikronezya",
+    "Marshall Adaları",
+    "Palau",
+    "Pakistan",
+    "Panama",
+    "Papua Yeni Gine",
+    "Paraguay",
+    "Peru",
+    "Filipinler",
+    "Pitcairn Adaları",
+    "Polonya",
+    "Portekiz",
+    "Gine-Bissau",
+    "Timor-Leste",
+    "Porto Riko",
+    "Katar",
+    "Reunion",
+    "Romanya",
+    "Rusya",
+    "Ruanda",
+    "Saint Barthelemy",
+    "Saint Helena",
+    "Saint Kitts ve Nevis",
+    "Anguilla",
+    "Saint Lucia",
+    "Saint Martin",
+    "Saint Pierre ve Miquelon",
+    "Saint Vincent ve Grenadinler",
+    "San Marino",
+    "Sao Tome ve Principe",
+    "Suudi Arabistan",
+    "Senegal",
+    "Sırbistan",
+    "Seyşeller",
+    "Sierra Leone",
+    "Singapur",
+    "Slovakya",
+    "Vietnam",
+    "Slovenya",
+    "Somali",
+    "Güney Afrika",
+    "Zimbabve",
+    "İspanya",
+    "Güney Sudan",
+    "Sudan",
+    "Batı Sahra",
+    "Surinam",
+    "Svalbard ve Jan Mayen",
+    "Esvatini",
+    "İsveç",
+    "İsviçre",
+    "Suriye",
+    "Tacikistan",
+    "Tayland",
+    "Togo",
+    "Tokelau",
+    "Tonga",
+    "Trinidad ve Tobago",
+    "Birleşik Arap Emirlikleri",
+    "Tunus",
+    "Türkiye",
+    "Türkmenistan",
+    "Turks ve Caicos Adaları",
+    "Tuvalu",
+    "Uganda",
+    "Ukrayna",
+    "Kuzey Makedonya",
+    "Mısır",
+    "Birleşik Krallık",
+    "Guernsey",
+    "Jersey",
+    "Man Adası",
+    "Tanzanya",
+    "Amerika Birleşik Devletleri",
+    "ABD Virjin Adaları",
+    "Burkina Faso",
+    "Uruguay",
+    "Özbekistan",
+    "Venezuela",
+    "Wallis ve Futuna",
+    "Samoa",
+    "Yemen",
+    "Zambiya",
+];
+
+static UK: [&str; 249] = [
+    "Афганістан",
+    "Албанія",
+    "Антарктика",
+    "Алжир",
+    "Американське Самоа",
+    "Андорра",
+    "Ангола",
+    "Антигуа і Барбуда",
+    "Азербайджан",
+    "Аргентина",
+    "Австралія",
+    "Австрія",
+    "Багамські Острови",
+    "Бахрейн",
+    "Бангладеш",
+    "Вірменія",
+    "Барбадос",
+    "Бельгія",
+    "Бермудські Острови",
+    "Бутан",
+    "Болівія",
+    "Боснія і Герцеговина",
+    "Ботсвана",
+    "Острів Буве",
+    "Бразилія",
+    "Беліз",
+    "Британська територія в Індійському океані",
+    "Соломонові Острови",
+    "Британські Віргінські острови",
+    "Бруней",
+    "Болгарія",
+    "Мʼянма (Бірма)",
+    "Бурунді",
+    "Білорусь",
+    "Камбоджа",
+    "Камерун",
+    "Канада",
+    "Кабо-Верде",
+    "Кайманові Острови",
+    "Центральноафриканська Республіка",
+    "Шрі-Ланка",
+    "Чад",
+    "Чилі",
+    "Китай",
+    "Тайвань",
+    "Острів Різдва",
+    "Кокосові (Кілінг) Острови",
+    "Колумбія",
+    "Комори",
+    "Майотта",
+    "Конго – Браззавіль",
+    "Конго – Кіншаса",
+    "Острови Кука",
+    "Коста-Рика",
+    "Хорватія",
+    "Куба",
+    "Кіпр",
+    "Чехія",
+    "Бенін",
+    "Данія",
+    "Домініка",
+    "Домініканська Республіка",
+    "Еквадор",
+    "Сальвадор",
+    "Екваторіальна Гвінея",
+    "Ефіопія",
+    "Еритрея",
+    "Естонія",
+    "Фарерські Острови",
+    "Фолклендські Острови",
+    "Південна Джорджія та Південні Сандвічеві Острови",
+    "Фіджі",
+    "Фінляндія",
+    "Аландські Острови",
+    "Франція",
+    "Французька Гвіана",
+    "Французька Полінезія",
+    "Французькі Південні Території",
+    "Джибуті",
+    "Габон",
+    "Грузія",
+    "Гамбія",
+    "Палестинські території",
+    "Німеччина",
+    "Гана",
+    "Гібралтар",
+    "Кірибаті",
+    "Греція",
+    "Гренландія",
+    "Гренада",
+    "Гваделупа",
+    "Гуам",
+    "Гватемала",
+    "Гвінея",
+    "Гаяна",
+    "Гаїті",
+    "Острови Герд і Макдоналд",
+    "Ватикан",
+    "Гондурас",
+    "Гонконг, ОАР Китаю",
+    "Угорщина",
+    "Ісландія",
+    "Індія",
+    "Індонезія",
+    "Іран",
+    "Ірак",
+    "Ірландія",
+    "Ізраїль",
+    "Італія",
+    "Кот-дʼІвуар",
+    "Ямайка",
+    "Японія",
+    "Казахстан",
+    "Йорданія",
+    "Кенія",
+    "Північна Корея",
+    "Південна Корея",
+    "Кувейт",
+    "Киргизстан",
+    "Лаос",
+    "Ліван",
+    "Лесото",
+    "Латвія",
+    "Ліберія",
+    "Лівія",
+    "Ліхтенштейн",
+    "Литва",
+    "Люксембург",
+    "Макао, ОАР Китаю",
+    "Мадагаскар",
+    "Малаві",
+    "Малайзія",
+    "Мальдіви",
+    "Малі",
+    "Мальта",
+    "Мартиніка",
+    "Мавританія",
+    "Маврикій",
+    "Мексика",
+    "Монако",
+    "Монголія",
+    "Молдова",
+    "Чорногорія",
+    "Монтсеррат",
+    "Марокко",
+    "Мозамбік",
+    "Оман",
+    "Намібія",
+    "Науру",
+    "Непал",
+    "Нідерланди",
+    "Кюрасао",
+    "Аруба",
+    "Сінт-Мартен",
+    "Карибські Нідерланди",
+    "Нова Каледонія",
+    "Вануату",
+    "Нова Зеландія",
+    "Нікарагуа",
+    "Нігер",
+    "Нігерія",
+    "Ніуе",
+    "Острів Норфолк",
+    "Норвегія",
+    "Північні Маріанські Острови",
+    "Віддалені острови США",
+    "Мікронезія",
+    "Маршаллові Острови",
+    "Палау",
+    "Пакистан",
+    "Панама",
+    "Папуа-Нова Гвінея",
+    "Парагвай",
+    "Перу",
+    "Філіппіни",
+    "Острови Піткерн",
+    "Польща",
+    "Португалія",
+    "Гвінея-Бісау",
+    "Тимор-Лешті",
+    "Пуерто-Рико",
+    "Катар",
+    "Реюньйон",
+    "Румунія",
+    "Росія",
+    "Руанда",
+    "Сен-Бартелемі",
+    "Острів Святої Єлени",
+    "Сент-Кітс і Невіс",
+    "Ангілья",
+    "Сент-Люсія",
+    "Сен-Мартен",
+    "Сен-Пʼєр і Мікелон",
+    "Сент-Вінсент і Гренадіни",
+    "Сан-Марино",
+    "Сан-Томе і Принсіпі",
+    "Саудівська Аравія",
+    "Сенегал",
+    "Сербія",
+    "Сейшельські Острови",
+    "Сьєрра-Леоне",
+    "Сінгапур",
+    "Словаччина",
+    "Вʼєтнам",
+    "Словенія",
+    "Сомалі",
+    "Південно-Африканська Республіка",
+    "Зімбабве",
+    "Іспанія",
+    "Південний Судан",
+    "Судан",
+    "Західна Сахара",
+    "Суринам",
+    "Шпіцберген та Ян-Маєн",
+    "Есватіні",
+    "Швеція",
+    "Швейцарія",
+    "Сирія",
+    "Таджикистан",
+    "Таїланд",
+    "Того",
+    "Токелау",
+    "Тонга",
+    "Тринідад і Тобаго",
+    "Обʼєднані Арабські Емірати",
+    "Туніс",
+    "Туреччина",
+    "Туркменістан",
+    "Острови Теркс і Кайкос",
+    "Тувалу",
+    "Уганда",
+    "Україна",
+    "Північна Македонія",
+    "Єгипет",
+    "Велика Британія",
+    "Гернсі",
+    "Джерсі",
+    "Острів Мен",
+    "Танзанія",
+    "Сполучені Штати",
+    "Віргінські Острови (США)",
+    "Буркіна-Фасо",
+    "Уругвай",
+    "Узбекистан",
+    "Венесуела",
+    "Уолліс і Футуна",
+    "Самоа",
+    "Ємен",
+    "Замбія",
+];
+
+static UR: [&str; 249] = [
+    "افغانستان",
+    "البانیہ",
+    "انٹارکٹیکا",
+    "الجیریا",
+    "امریکی ساموآ",
+    "انڈورا",
+    "انگولا",
+    "انٹیگوا اور باربودا",
+    "آذربائیجان",
+    "ارجنٹینا",
+    "آسٹریلیا",
+    "آسٹریا",
+    "بہاماس",
+    "بحرین",
+    "بنگلہ دیش",
+    "آرمینیا",
+    "بارباڈوس",
+    "بیلجیم",
+    "برمودا",
+    "بھوٹان",
+    "بولیویا",
+    "بوسنیا اور ہرزیگووینا",
+    "بوتسوانا",
+    "بؤویٹ آئلینڈ",
+    "برازیل",
+    "بیلائز",
+    "برطانوی بحر ہند کا علاقہ",
+    "سولومن آئلینڈز",
+    "برٹش ورجن آئلینڈز",
+    "برونائی",
+    "بلغاریہ",
+    "میانمار (برما)",
+    "برونڈی",
+    "بیلاروس",
+    "کمبوڈیا",
+    "کیمرون",
+    "کینیڈا",
+    "کیپ ورڈی",
+    "کیمین آئلینڈز",
+    "وسط افریقی جمہوریہ",
+    "سری لنکا",
+    "چاڈ",
+    "چلی",
+    "چین",
+    "تائیوان",
+    "جزیرہ کرسمس",
+    "کوکوس (کیلنگ) جزائر",
+    "کولمبیا",
+    "کوموروس",
+    "مایوٹ",
+    "کانگو - برازاویلے",
+    "کانگو - کنشاسا",
+    "کک آئلینڈز",
+    "کوسٹا ریکا",
+    "کروشیا",
+    "کیوبا",
+    "قبرص",
+    "چیکیا",
+    "بینن",
+    "ڈنمارک",
+    "ڈومنیکا",
+    "جمہوریہ ڈومينيکن",
+    "ایکواڈور",
+    "ال سلواڈور",
+    "استوائی گیانا",
+    "ایتھوپیا",
+    "اریٹیریا",
+    "اسٹونیا",
+    "جزائر فارو",
+    "فاکلینڈ جزائر",
+    "جنوبی جارجیا اور جنوبی سینڈوچ جزائر",
+    "فجی",
+    "فن لینڈ",
+    "آلینڈ آئلینڈز",
+    "فرانس",
+    "فرینچ گیانا",
+    "فرانسیسی پولینیشیا",
+    "فرانسیسی جنوبی خطے",
+    "جبوتی",
+    "گیبون",
+    "جارجیا",
+    "گیمبیا",
+    "فلسطینی خطے",
+    "جرمنی",
+    "گھانا",
+    "جبل الطارق",
+    "کریباتی",
+    "یونان",
+    "گرین لینڈ",
+    "گریناڈا",
+    "گواڈیلوپ",
+    "گوام",
+    "گواٹے مالا",
+    "گنی",
+    "گیانا",
+    "ہیٹی",
+    "ہیرڈ جزیرہ و میکڈولینڈ جزائر",
+    "ویٹیکن سٹی",
+    "ہونڈاروس",
+    "ہانگ کانگ SAR چین",
+    "ہنگری",
+    "آئس لینڈ",
+    "بھارت",
+    "انڈونیشیا",
+    "ایران",
+    "عراق",
+    "آئرلینڈ",
+    "اسرائیل",
+    "اٹلی",
+    "کوٹ ڈی آئیوری",
+    "جمائیکا",
+    "جاپان",
+    "قزاخستان",
+    "اردن",
+    "کینیا",
+    "شمالی کوریا",
+    "جنوبی کوریا",
+    "کویت",
+    "کرغزستان",
+    "لاؤس",
+    "لبنان",
+    "لیسوتھو",
+    "لٹویا",
+    "لائبیریا",
+    "لیبیا",
+    "لیشٹنسٹائن",
+    "لیتھونیا",
+    "لکسمبرگ",
+    "مکاؤ SAR چین",
+    "مڈغاسکر",
+    "ملاوی",
+    "ملائشیا",
+    "مالدیپ",
+    "مالی",
+    "مالٹا",
+    "مارٹینک",
+    "موریطانیہ",
+    "ماریشس",
+    "میکسیکو",
+    "موناکو",
+    "منگولیا",
+    "مالدووا",
+    "مونٹے نیگرو",
+    "مونٹسیراٹ",
+    "مراکش",
+    "موزمبیق",
+    "عمان",
+    "نامیبیا",
+    "نؤرو",
+    "نیپال",
+    "نیدر لینڈز",
+    "کیوراکاؤ",
+    "اروبا",
+    "سنٹ مارٹن",
+    "کریبیائی نیدرلینڈز",
+    "نیو کلیڈونیا",
+    "وینوآٹو",
+    "نیوزی لینڈ",
+    "نکاراگووا",
+    "نائجر",
+    "نائجیریا",
+    "نیئو",
+    "نارفوک آئلینڈ",
+    "ناروے",
+    "شمالی ماریانا آئلینڈز",
+    "امریکہ سے باہر کے چھوٹے جزائز",
+    "مائکرونیشیا",
+    "مارشل آئلینڈز",
+    "پلاؤ",
+    "پاکستان",
+    "پانامہ",
+    "پاپوآ نیو گنی",
+    "پیراگوئے",
+    "پیرو",
+    "فلپائن",
+    "پٹکائرن جزائر",
+    "پولینڈ",
+    "پرتگال",
+    "گنی بساؤ",
+    "تیمور لیسٹ",
+    "پیورٹو ریکو",
+    "قطر",
+    "ری یونین",
+    "رومانیہ",
+    "روس",
+    "روانڈا",
+    "سینٹ برتھلیمی",
+    "سینٹ ہیلینا",
+    "سینٹ کٹس اور نیویس",
+    "انگوئیلا",
+    "سینٹ لوسیا",
+    "سینٹ مارٹن",
+    "سینٹ پیئر اور میکلیئون",
+    "سینٹ ونسنٹ اور گرینیڈائنز",
+    "سان مارینو",
+    "ساؤ ٹومے اور پرنسپے",
+    "سعودی عرب",
+    "سینیگل",
+    "سربیا",
+    "سشلیز",
+    "سیرالیون",
+    "سنگاپور",
+    "سلوواکیہ",
+    "ویتنام",
+    "سلووینیا",
+    "صومالیہ",
+    "جنوبی افریقہ",
+    "زمبابوے",
+    "ہسپانیہ",
+    "جنوبی سوڈان",
+    "سوڈان",
+    "مغربی صحارا",
+    "سورینام",
+    "سوالبرڈ اور جان ماین",
+    "سواتنی",
+    "سویڈن",
+    "سوئٹزر لینڈ",
+    "شام",
+    "تاجکستان",
+    "تھائی لینڈ",
+    "ٹوگو",
+    "ٹوکیلاؤ",
+    "ٹونگا",
+    "ترینیداد اور ٹوباگو",
+    "متحدہ عرب امارات",
+    "تونس",
+    "ترکیہ",
+    "ترکمانستان",
+    "ٹرکس اور کیکوس جزائر",
+    "ٹووالو",
+    "یوگنڈا",
+    "یوکرین",
+    "شمالی مقدونیہ",
+    "مصر",
+    "سلطنت متحدہ",
+    "گوئرنسی",
+    "جرسی",
+    "آئل آف مین",
+    "تنزانیہ",
+    "ریاست ہائے متحدہ امریکہ",
+    "امریکی ورجن آئلینڈز",
+    "برکینا فاسو",
+    "یوروگوئے",
+    "ازبکستان",
+    "وینزوئیلا",
+    "ویلیز اور فیوٹیونا",
+    "ساموآ",
+    "یمن",
+    "زامبیا",
+];
+
+static VI: [&str; 249] = [
+    "Afghanistan",
+    "Albania",
+    "Nam Cực",
+    "Algeria",
+    "Samoa thuộc Mỹ",
+    "Andorra",
+    "Angola",
+    "Antigua và Barbuda",
+    "Azerbaijan",
+    "Argentina",
+    "Australia",
+    "Áo",
+    "Bahamas",
+    "Bahrain",
+    "Bangladesh",
+    "Armenia",
+    "Barbados",
+    "Bỉ",
+    "Bermuda",
+    "Bhutan",
+    "Bolivia",
+    "Bosnia và Herzegovina",
+    "Botswana",
+    "Đảo Bouvet",
+    "Brazil",
+    "Belize",
+    "Lãnh thổ Ấn Độ Dương thuộc Anh",
+    "Quần đảo Solomon",
+    "Quần đảo Virgin thuộc Anh",
+    "Brunei",
+    "Bulgaria",
+    "Myanmar (Miến Điện)",
+    "Burundi",
+    "Belarus",
+    "Campuchia",
+    "Cameroon",
+    "Canada",
+    "Cape Verde",
+    "Quần đảo Cayman",
+    "Cộng hòa Trung Phi",
+    "Sri Lanka",
+    "Chad",
+    "Chile",
+    "Trung Quốc",
+    "Đài Loan",
+    "Đảo Giáng Sinh",
+    "Quần đảo Cocos (Keeling)",
+    "Colombia",
+    "Comoros",
+    "Mayotte",
+    "Congo - Brazzaville",
+    "Congo - Kinshasa",
+    "Quần đảo Cook",
+    "Costa Rica",
+    "Croatia",
+    "Cuba",
+    "Síp",
+    "Séc",
+    "Benin",
+    "Đan Mạch",
+    "Dominica",
+    "Cộng hòa Dominica",
+    "Ecuador",
+    "El Salvador",
+    "Guinea Xích Đạo",
+    "Ethiopia",
+    "Eritrea",
+    "Estonia",
+    "Quần đảo Faroe",
+    "Quần đảo Falkland",
+    "Nam Georgia & Quần đảo Nam Sandwich",
+    "Fiji",
+    "Phần Lan",
+    "Quần đảo Åland",
+    "Pháp",
+    "Guiana thuộc Pháp",
+    "Polynesia thuộc Pháp",
+    "Lãnh thổ phía Nam Thuộc Pháp",
+    "Djibouti",
+    "Gabon",
+    "Georgia",
+    "Gambia",
+    "Lãnh thổ Palestine",
+    "Đức",
+    "Ghana",
+    "Gibraltar",
+    "Kiribati",
+    "Hy Lạp",
+    "Greenland",
+    "Grenada",
+    "Guadeloupe",
+    "Guam",
+    "Guatemala",
+    "Guinea",
+    "Guyana",
+    "Haiti",
+    "Quần đảo Heard và McDonald",
+    "Thành Vatican",
+    "Honduras",
+    "Đặc khu Hành chính Hồng Kông, Trung Quốc",
+    "Hungary",
+    "Iceland",
+    "Ấn Độ",
+    "Indonesia",
+    "Iran",
+    "Iraq",
+    "Ireland",
+    "Israel",
+    "Italy",
+    "Côte d’Ivoire",
+    "Jamaica",
+    "Nhật Bản",
+    "Kazakhstan",
+    "Jordan",
+    "Kenya",
+    "Triều Tiên",
+    "Hàn Quốc",
+    "Kuwait",
+    "Kyrgyzstan",
+    "Lào",
+    "Li-băng",
+    "Lesotho",
+    "Latvia",
+    "Liberia",
+    "Libya",
+    "Liechtenstein",
+    "Litva",
+    "Luxembourg",
+    "Đặc khu Hành chính Macao, Trung Quốc",
+    "Madagascar",
+    "Malawi",
+    "Malaysia",
+    "Maldives",
+    "Mali",
+    "Malta",
+    "Martinique",
+    "Mauritania",
+    "Mauritius",
+    "Mexico",
+    "Monaco",
+    "Mông Cổ",
+    "Moldova",
+    "Montenegro",
+    "Montserrat",
+    "Ma-rốc",
+    "Mozambique",
+    "Oman",
+    "Namibia",
+    "Nauru",
+    "Nepal",
+    "Hà Lan",
+    "Curaçao",
+    "Aruba",
+    "Sint Maarten",
+    "Ca-ri-bê Hà Lan",
+    "New Caledonia",
+    "Vanuatu",
+    "New Zealand",
+    "Nicaragua",
+    "Niger",
+    "Nigeria",
+    "Niue",
+    "Đảo Norfolk",
+    "Na Uy",
+    "Quần đảo Bắc Mariana",
+    "Các tiểu đảo xa của Hoa Kỳ",
+    "Micronesia",
+    "Quần đảo Marshall",
+    "Palau",
+    "Pakistan",
+    "Panama",
+    "Papua New Guinea",
+    "Paraguay",
+    "Peru",
+    "Philippines",
+    "Quần đảo Pitcairn",
+    "Ba Lan",
+    "Bồ Đào Nha",
+    "Guinea-Bissau",
+    "Timor-Leste",
+    "Puerto Rico",
+    "Qatar",
+    "Réunion",
+    "Romania",
+    "Nga",
+    "Rwanda",
+    "St. Barthélemy",
+    "St. Helena",
+    "St. Kitts và Nevis",
+    "Anguilla",
+    "St. Lucia",
+    "St. Martin",
+    "Saint Pierre và Miquelon",
+    "St. Vincent và Grenadines",
+    "San Marino",
+    "São Tomé và Príncipe",
+    "Ả Rập Xê-út",
+    "Senegal",
+    "Serbia",
+    "Seychelles",
+    "Sierra Leone",
+    "Singapore",
+    "Slovakia",
+    "Việt Nam",
+    "Slovenia",
+    "Somalia",
+    "Nam Phi",
+    "Zimbabwe",
+    "Tây Ban Nha",
+    "Nam Sudan",
+    "Sudan",
+    "Tây Sahara",
+    "Suriname",
+    "Svalbard và Jan Mayen",
+    "Eswatini",
+    "Thụy Điển",
+    "Thụy Sĩ",
+    "Syria",
+    "Tajikistan",
+    "Thái Lan",
+    "Togo",
+    "Tokelau",
+    "Tonga",
+    "Trinidad và Tobago",
+    "Các Tiểu Vương quốc Ả Rập Thống nhất",
+    "Tunisia",
+    "Thổ Nhĩ Kỳ",
+    "Turkmenistan",
+    "Quần đảo Turks và Caicos",
+    "Tuvalu",
+    "Uganda",
+    "Ukraina",
+    "Bắc Macedonia",
+    "Ai Cập",
+    "Vương quốc Anh",
+    "Guernsey",
+    "Jersey",
+    "Đảo Man",
+    "Tanzania",
+    "Hoa Kỳ",
+    "Quần đảo Virgin thuộc Hoa Kỳ",
+    "Burkina Faso",
+    "Uruguay",
+    "Uzbekistan",
+    "Venezuela",
+    "Wallis và Futuna",
+    "Samoa",
+    "Yemen",
+    "Zambia",
+];
+
+static ZH: [&str; 249] = [
+    "阿富汗",
+    "阿尔巴尼亚",
+    "南极洲",
+    "阿尔及利亚",
+    "美属萨摩亚",
+    "安道尔",
+    "安哥拉",
+    "安提瓜和巴布达",
+    "阿塞拜疆",
+    "阿根廷",
+    "澳大利亚",
+    "奥地利",
+    "巴哈马",
+    "巴林",
+    "孟加拉国",
+    "亚美尼亚",
+    "巴巴多斯",
+    "比利时",
+    "百慕大",
+    "不丹",
+    "玻利维亚",
+    "波斯尼亚和黑塞哥维那",
+    "博茨瓦纳",
+    "布韦岛",
+    "巴西",
+    "伯利兹",
+    "英属印度洋领地",
+    "所罗门群岛",
+    "英属维尔京群岛",
+    "文莱",
+    "保加利亚",
+    "缅甸",
+    "布隆迪",
+    "白俄罗斯",
+    "柬埔寨",
+    "喀麦隆",
+    "加拿大",
+    "佛得角",
+    "开曼群岛",
+    "中非共和国",
+    "斯里兰卡",
+    "乍得",
+    "智利",
+    "中国",
+    "台湾",
+    "圣诞岛",
+    "科科斯（基林）群岛",
+    "哥伦比亚",
+    "科摩罗",
+    "马约特",
+    "刚果（布）",
+    "刚果（金）",
+    "库克群岛",
+    "哥斯达黎加",
+    "克罗地亚",
+    "古巴",
+    "塞浦路斯",
+    "捷克",
+    "贝宁",
+    "丹麦",
+    "多米尼克",
+    "多米尼加共和国",
+    "厄瓜多尔",
+    "萨尔瓦多",
+    "赤道几内亚",
+    "埃塞俄比亚",
+    "厄立特里亚",
+    "爱沙尼亚",
+    "法罗群岛",
+    "福克兰群岛",
+    "南乔治亚和南桑威奇群岛",
+    "斐济",
+    "芬兰",
+    "奥兰群岛",
+    "法国",
+    "法属圭亚那",
+    "法属波利尼西亚",
+    "法属南部领地",
+    "吉布提",
+    "加蓬",
+    "格鲁吉亚",
+    "冈比亚",
+    "巴勒斯坦领土",
+    "德国",
+    "加纳",
+    "直布罗陀",
+    "基里巴斯",
+    "希腊",
+    "格陵兰",
+    "格林纳达",
+    "瓜德罗普",
+    "关岛",
+    "危地马拉",
+    "几内亚",
+    "圭亚那",
+    "海地",
+    "赫德岛和麦克唐纳群岛",
+    "梵蒂冈",
+    "洪都拉斯",
+    "中国香港特别行政区",
+    "匈牙利",
+    "冰岛",
+    "印度",
+    "印度尼西亚",
+    "伊朗",
+    "伊拉克",
+    "爱尔兰",
+    "以色列",
+    "意大利",
+    "科特迪瓦",
+    "牙买加",
+    "日本",
+    "哈萨克斯坦",
+    "约旦",
+    "肯尼亚",
+    "朝鲜",
+    "韩国",
+    "科威特",
+    "吉尔吉斯斯坦",
+    "老挝",
+    "黎巴嫩",
+    "莱索托",
+    "拉脱维亚",
+    "利比里亚",
+    "利比亚",
+    "列支敦士登",
+    "立陶宛",
+    "卢森堡",
+    "中国澳门特别行政区",
+    "马达加斯加",
+    "马拉维",
+    "马来西亚",
+    "马尔代夫",
+    "马里",
+    "马耳他",
+    "马提尼克",
+    "毛里塔尼亚",
+    "毛里求斯",
+    "墨西哥",
+    "摩纳哥",
+    "蒙古",
+    "摩尔多瓦",
+    "黑山",
+    "蒙特塞拉特",
+    "摩洛哥",
+    "莫桑比克",
+    "阿曼",
+    "纳米比亚",
+    "瑙鲁",
+    "尼泊尔",
+    "荷兰",
+    "库拉索",
+    "阿鲁巴",
+    "荷属圣马丁",
+    "荷属加勒比区",
+    "新喀里多尼亚",
+    "瓦努阿图",
+    "新西兰",
+    "尼加拉瓜",
+    "尼日尔",
+    "尼日利亚",
+    "纽埃",
+    "诺福克岛",
+    "挪威",
+    "北马里亚纳群岛",
+    "美国本土外小岛屿",
+    "密克罗尼西亚",
+    "马绍尔群岛",
+    "帕劳",
+    "巴基斯坦",
+    "巴拿马",
+    "巴布亚新几内亚",
+    "巴拉圭",
+    "秘鲁",
+    "菲律宾",
+    "皮特凯恩群岛",
+    "波兰",
+    "葡萄牙",
+    "几内亚比绍",
+    "东帝汶",
+    "波多黎各",
+    "卡塔尔",
+    "留尼汪",
+    "罗马尼亚",
+    "俄罗斯",
+    "卢旺达",
+    "圣巴泰勒米",
+    "圣赫勒拿",
+    "圣基茨和尼维斯",
+    "安圭拉",
+    "圣卢西亚",
+    "法属圣马丁",
+    "圣皮埃尔和密克隆群岛",
+    "圣文森特和格林纳丁斯",
+    "圣马力诺",
+    "圣多美和普林西比",
+    "沙特阿拉伯",
+    "塞内加尔",
+    "塞尔维亚",
+    "塞舌尔",
+    "塞拉利昂",
+    "新加坡",
+    "斯洛伐克",
+    "越南",
+    "斯洛文尼亚",
+    "索马里",
+    "南非",
+    "津巴布韦",
+    "西班牙",
+    "南苏丹",
+    "苏丹",
+    "西撒哈拉",
+    "苏里南",
+    "斯瓦尔巴和扬马延",
+    "斯威士兰",
+    "瑞典",
+    "瑞士",
+    "叙利亚",
+    "塔吉克斯坦",
+    "泰国",
+    "多哥",
+    "托克劳",
+    "汤加",
+    "特立尼达和多巴哥",
+    "阿拉伯联合酋长国",
+    "突尼斯",
+    "土耳其",
+    "土库曼斯坦",
+    "特克斯和凯科斯群岛",
+    "图瓦卢",
+    "乌干达",
+    "乌克兰",
+    "北马其顿",
+    "埃及",
+    "英国",
+    "根西岛",
+    "泽西岛",
+    "马恩岛",
+    "坦桑尼亚",
+    "美国",
+    "美属维尔京群岛",
+    "布基纳法索",
+    "乌拉圭",
+    "乌兹别克斯坦",
+    "委内瑞拉",
+    "瓦利斯和富图纳",
+    "萨摩亚",
+    "也门",
+    "赞比亚",
+];
+
+static ZH_HK: &[(Country, &str)] = &[
+    (Country::AG, "安提瓜和巴布達"),
+    (Country::AZ, "亞塞拜疆"),
+    (Country::BB, "巴巴多斯"),
+    (Country::BA, "波斯尼亞和黑塞哥維那"),
+    (Country::BW, "博茨瓦納"),
+    (Country::BV, "鮑威特島"),
+    (Country::BZ, "伯利茲"),
+    (Country::SB, "所羅門群島"),
+    (Country::VG, "英屬維爾京群島"),
+    (Country::BI, "布隆迪"),
+    (Country::CV, "佛得角"),
+    (Country::TD, "乍得"),
+    (Country::CC, "科科斯 (基林) 群島"),
+    (Country::KM, "科摩羅"),
+    (Country::YT, "馬約特"),
+    (Country::CR, "哥斯達黎加"),
+    (Country::HR, "克羅地亞"),
+    (Country::CY, "塞浦路斯"),
+    (Country::BJ, "貝寧"),
+    (Country::DO, "多米尼加共和國"),
+    (Country::EC, "厄瓜多爾"),
+    (Country::ET, "埃塞俄比亞"),
+    (Country::ER, "厄立特里亞"),
+    (Country::GS, "南佐治亞島與南桑威奇群島"),
+    (Country::PF, "法屬波利尼西亞"),
+    (Country::TF, "法屬南部領地"),
+    (Country::DJ, "吉布提"),
+    (Country::GA, "加蓬"),
+    (Country::GE, "格魯吉亞"),
+    (Country::GM, "岡比亞"),
+    (Country::PS, "巴勒斯坦領土"),
+    (Country::GH, "加納"),
+    (Country::GD, "格林納達"),
+    (Country::GT, "危地馬拉"),
+    (Country::GY, "圭亞那"),
+    (Country::HN, "洪都拉斯"),
+    (Country::IT, "意大利"),
+    (Country::CI, "科特迪瓦"),
+    (Country::KE, "肯尼亞"),
+    (Country::LA, "老撾"),
+    (Country::LS, "萊索托"),
+    (Country::LR, "利比里亞"),
+    (Country::LI, "列支敦士登"),
+    (Country::MW, "馬拉維"),
+    (Country::MV, "馬爾代夫"),
+    (Country::ML, "馬里"),
+    (Country::MT, "馬耳他"),
+    (Country::MR, "毛里塔尼亞"),
+    (Country::MU, "毛里裘斯"),
+    (Country::ME, "黑山"),
+    (Country::MS, "蒙特塞拉特"),
+    (Country::MZ, "莫桑比克"),
+    (Country::NR, "瑙魯"),
+    (Country::AW, "阿魯巴"),
+    (Country::VU, "瓦努阿圖"),
+    (Country::NE, "尼日爾"),
+    (Country::NG, "尼日利亞"),
+    (Country::PG, "巴布亞新畿內亞"),
+    (Country::PN, "皮特凱恩島"),
+    (Country::GW, "幾內亞比紹"),
+    (Country::QA, "卡塔爾"),
+    (Country::RW, "盧旺達"),
+    (Country::BL, "聖巴泰勒米"),
+    (Country::KN, "聖基茨和尼維斯"),
+    (Country::LC, "聖盧西亞"),
+    (Country::VC, "聖文森特和格林納丁斯"),
+    (Country::SM, "聖馬力諾"),
+    (Country::ST, "聖多美和普林西比"),
+    (Country::SA, "沙地阿拉伯"),
+    (Country::SC, "塞舌爾"),
+    (Country::SL, "塞拉利昂"),
+    (Country::SI, "斯洛文尼亞"),
+    (Country::SO, "索馬里"),
+    (Country::ZW, "津巴布韋"),
+    (Country::SR, "蘇里南"),
+    (Country::SJ, "斯瓦爾巴特群島及揚馬延島"),
+    (Country::SZ, "斯威士蘭"),
+    (Country::TO, "湯加"),
+    (Country::TT, "千里達和多巴哥"),
+    (Country::AE, "阿拉伯聯合酋長國"),
+    (Country::TC, "特克斯和凱科斯群島"),
+    (Country::TV, "圖瓦盧"),
+    (Country::IM, "馬恩島"),
+    (Country::TZ, "坦桑尼亞"),
+    (Country::VI, "美屬維爾京群島"),
+    (Country::BF, "布基納法索"),
+    (Country::YE, "也門"),
+    (Country::ZM, "贊比亞"),
+];
+
+static ZH_HANT: [&str; 249] = [
+    "阿富汗",
+    "阿爾巴尼亞",
+    "南極洲",
+    "阿爾及利亞",
+    "美屬薩摩亞",
+    "安道爾",
+    "安哥拉",
+    "安地卡及巴布達",
+    "亞塞拜然",
+    "阿根廷",
+    "澳洲",
+    "奧地利",
+    "巴哈馬",
+    "巴林",
+    "孟加拉",
+    "亞美尼亞",
+    "巴貝多",
+    "比利時",
+    "百慕達",
+    "不丹",
+    "玻利維亞",
+    "波士尼亞與赫塞哥維納",
+    "波札那",
+    "布威島",
+    "巴西",
+    "貝里斯",
+    "英屬印度洋領地",
+    "索羅門群島",
+    "英屬維京群島",
+    "汶萊",
+    "保加利亞",
+    "緬甸",
+    "蒲隆地",
+    "白俄羅斯",
+    "柬埔寨",
+    "喀麥隆",
+    "加拿大",
+    "維德角",
+    "開曼群島",
+    "中非共和國",
+    "斯里蘭卡",
+    "查德",
+    "智利",
+    "中國",
+    "台灣",
+    "聖誕島",
+    "科克斯（基靈）群島",
+    "哥倫比亞",
+    "葛摩",
+    "馬約特島",
+    "剛果（布拉薩）",
+    "剛果（金夏沙）",
+    "庫克群島",
+    "哥斯大黎加",
+    "克羅埃西亞",
+    "古巴",
+    "賽普勒斯",
+    "捷克",
+    "貝南",
+    "丹麥",
+    "多米尼克",
+    "多明尼加共和國",
+    "厄瓜多",
+    "薩爾瓦多",
+    "赤道幾內亞",
+    "衣索比亞",
+    "厄利垂亞",
+    "愛沙尼亞",
+    "法羅群島",
+    "福克蘭群島",
+    "南喬治亞與南三明治群島",
+    "斐濟",
+    "芬蘭",
+    "奧蘭群島",
+    "法國",
+    "法屬圭亞那",
+    "法屬玻里尼西亞",
+    "法屬南部屬地",
+    "吉布地",
+    "加彭",
+    "喬治亞",
+    "甘比亞",
+    "巴勒斯坦自治區",
+    "德國",
+    "迦納",
+    "直布羅陀",
+    "吉里巴斯",
+    "希臘",
+    "格陵蘭",
+    "格瑞那達",
+    "瓜地洛普",
+    "關島",
+    "瓜地馬拉",
+    "幾內亞",
+    "蓋亞那",
+    "海地",
+    "赫德島及麥唐納群島",
+    "梵蒂岡",
+    "宏都拉斯",
+    "中國香港特別行政區",
+    "匈牙利",
+    "冰島",
+    "印度",
+    "印尼",
+    "伊朗",
+    "伊拉克",
+    "愛爾蘭",
+    "以色列",
+    "義大利",
+    "象牙海岸",
+    "牙買加",
+    "日本",
+    "哈薩克",
+    "約旦",
+    "肯亞",
+    "北韓",
+    "南韓",
+    "科威特",
+    "吉爾吉斯",
+    "寮國",
+    "黎巴嫩",
+    "賴索托",
+    "拉脫維亞",
+    "賴比瑞亞",
+    "利比亞",
+    "列支敦斯登",
+    "立陶宛",
+    "盧森堡",
+    "中國澳門特別行政區",
+    "馬達加斯加",
+    "馬拉威",
+    "馬來西亞",
+    "馬爾地夫",
+    "馬利",
+    "馬爾他",
+    "馬丁尼克",
+    "茅利塔尼亞",
+    "模里西斯",
+    "墨西哥",
+    "摩納哥",
+    "蒙古",
+    "摩爾多瓦",
+    "蒙特內哥羅",
+    "蒙哲臘",
+    "摩洛哥",
+    "莫三比克",
+    "阿曼",
+    "納米比亞",
+    "諾魯",
+    "尼泊爾",
+    "荷蘭",
+    "庫拉索",
+    "荷屬阿魯巴",
+    "荷屬聖馬丁",
+    "荷蘭加勒比區",
+    "新喀里多尼亞",
+    "萬那杜",
+    "紐西蘭",
+    "尼加拉瓜",
+    "尼日",
+    "奈及利亞",
+    "紐埃島",
+    "諾福克島",
+    "挪威",
+    "北馬利安納群島",
+    "美國本土外小島嶼",
+    "密克羅尼西亞",
+    "馬紹爾群島",
+    "帛琉",
+    "巴基斯坦",
+    "巴拿馬",
+    "巴布亞紐幾內亞",
+    "巴拉圭",
+    "秘魯",
+    "菲律賓",
+    "皮特肯群島",
+    "波蘭",
+    "葡萄牙",
+    "幾內亞比索",
+    "東帝汶",
+    "波多黎各",
+    "卡達",
+    "留尼旺",
+    "羅馬尼亞",
+    "俄羅斯",
+    "盧安達",
+    "聖巴瑟米",
+    "聖赫勒拿島",
+    "聖克里斯多福及尼維斯",
+    "安奎拉",
+    "聖露西亞",
+    "法屬聖馬丁",
+    "聖皮埃與密克隆群島",
+    "聖文森及格瑞那丁",
+    "聖馬利諾",
+    "聖多美普林西比",
+    "沙烏地阿拉伯",
+    "塞內加爾",
+    "塞爾維亞",
+    "塞席爾",
+    "獅子山",
+    "新加坡",
+    "斯洛伐克",
+    "越南",
+    "斯洛維尼亞",
+    "索馬利亞",
+    "南非",
+    "辛巴威",
+    "西班牙",
+    "南蘇丹",
+    "蘇丹",
+    "西撒哈拉",
+    "蘇利南",
+    "挪威屬斯瓦巴及尖棉",
+    "史瓦帝尼",
+    "瑞典",
+    "瑞士",
+    "敘利亞",
+    "塔吉克",
+    "泰國",
+    "多哥",
+    "托克勞群島",
+    "東加",
+    "千里達及托巴哥",
+    "阿拉伯聯合大公國",
+    "突尼西亞",
+    "土耳其",
+    "土庫曼",
+    "土克斯及開科斯群島",
+    "吐瓦魯",
+    "烏干達",
+    "烏克蘭",
+    "北馬其頓",
+    "埃及",
+    "英國",
+    "根息",
+    "澤西島",
+    "曼島",
+    "坦尚尼亞",
+    "美國",
+    "美屬維京群島",
+    "布吉納法索",
+    "烏拉圭",
+    "烏茲別克",
+    "委內瑞拉",
+    "瓦利斯群島和富圖那群島",
+    "薩摩亞",
+    "葉門",
+    "尚比亞",
+];
+// End locales
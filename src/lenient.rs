@@ -0,0 +1,107 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 3166-1?
+//
+// | ISO 3166-1 is part of the ISO 3166 standard published by the International
+// | Organization for Standardization (ISO), and defines codes for the names of
+// | countries, dependent territories, and special areas of geographical
+// | interest.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_3166-1)
+//
+// Originally by zeyla on GitHub.
+
+// Lookups that forgive the differences in how codes and names are commonly
+// written: surrounding whitespace, letter case, and missing zero padding.
+
+use codes::ENTRIES;
+use {index, Entry};
+
+/// Returns the entry with the given Alpha2 code in any case, ignoring
+/// surrounding whitespace.
+pub(crate) fn alpha2(alpha2: &str) -> Option<&'static Entry> {
+    let mut code = [0; 2];
+
+    if fold(alpha2, &mut code) {
+        index::alpha2(as_str(&code))
+    } else {
+        None
+    }
+}
+
+/// Returns the entry with the given Alpha3 code in any case, ignoring
+/// surrounding whitespace.
+pub(crate) fn alpha3(alpha3: &str) -> Option<&'static Entry> {
+    let mut code = [0; 3];
+
+    if fold(alpha3, &mut code) {
+        index::alpha3(as_str(&code))
+    } else {
+        None
+    }
+}
+
+/// Returns the entry with the given name in any ASCII case, ignoring
+/// surrounding whitespace.
+pub(crate) fn name(name: &str) -> Option<&'static Entry> {
+    let name = name.trim();
+
+    ENTRIES.iter().find(|e| e.name.eq_ignore_ascii_case(name))
+}
+
+/// Returns the entry with the given numeric code, with or without zero
+/// padding, ignoring surrounding whitespace.
+pub(crate) fn num(num: &str) -> Option<&'static Entry> {
+    let num = num.trim();
+
+    if num.is_empty() || !num.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    // Leading zeros may pad the code to any length.
+    let digits = num.trim_start_matches('0');
+
+    if digits.len() > 3 {
+        return None;
+    }
+
+    let code = digits
+        .bytes()
+        .fold(0, |acc, b| acc * 10 + u16::from(b - b'0'));
+
+    index::numeric(code)
+}
+
+// Writes the trimmed code, uppercased, into `out`. Returns whether the code
+// had exactly as many ASCII letters as `out` has room for.
+fn fold(code: &str, out: &mut [u8]) -> bool {
+    let code = code.trim().as_bytes();
+
+    if code.len() != out.len() || !code.iter().all(u8::is_ascii_alphabetic) {
+        return false;
+    }
+
+    for (to, from) in out.iter_mut().zip(code) {
+        *to = from.to_ascii_uppercase();
+    }
+
+    true
+}
+
+fn as_str(code: &[u8]) -> &str {
+    ::core::str::from_utf8(code).expect("folded codes are ASCII")
+}
@@ -25,18 +25,85 @@
 //
 // Originally by zeyla on GitHub.
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[macro_use]
+mod macros;
+
+mod aliases;
+mod as_of;
+#[cfg(feature = "std")]
+mod autocomplete;
+mod changes;
+mod code;
 mod codes;
+mod country;
+mod date;
+mod demonyms;
+mod entry;
+mod error;
+#[cfg(feature = "std")]
+mod extract;
+mod fold;
+mod index;
+#[cfg(feature = "std")]
+mod lenient;
+mod names;
+#[cfg(feature = "std")]
+mod parse;
+#[cfg(feature = "std")]
+mod registry;
+mod reserved;
+#[cfg(feature = "std")]
+mod search;
+mod user_assigned;
+
+pub mod iso3166_3;
+#[cfg(feature = "l10n")]
+pub mod l10n;
 
+pub use aliases::NameMatch;
+pub use as_of::{as_of, AsOf, HistoricalEntry, Validity};
+#[cfg(feature = "std")]
+pub use autocomplete::{autocomplete, Completion};
+pub use changes::{changes, changes_between, Change, ChangeKind, DATA_VERSION};
+pub use code::{Alpha2, Alpha3, CodeError, Numeric};
+#[cfg(feature = "std")]
 pub use codes::all;
+pub use codes::Country;
+pub use date::Date;
+pub use entry::Entry;
+pub use error::LookupError;
+#[cfg(feature = "std")]
+pub use extract::{extract, Extractor, Mention};
+#[cfg(feature = "std")]
+pub use parse::{parse, CodeKind, Parsed};
+#[cfg(feature = "std")]
+pub use registry::{InsertError, Registry};
+pub use reserved::{
+    reservation, reservation_status, reservations, Reservation, ReservationCategory,
+    ReservationStatus,
+};
+#[cfg(feature = "std")]
+pub use search::{search, search_with_threshold, Candidate, DEFAULT_THRESHOLD};
+pub use user_assigned::is_user_assigned;
+#[cfg(feature = "std")]
+pub use user_assigned::UserAssigned;
 
-use std::num::ParseIntError;
+#[cfg(feature = "std")]
+use std::ops::RangeBounds;
+
+#[cfg(feature = "std")]
+extern crate core;
 
 #[cfg(feature = "serde")]
 #[macro_use]
 extern crate serde;
 
+extern crate unicode_normalization;
 
 /// Container for the data of each Country Code defined by ISO 3166-1,
+#[cfg(feature = "std")]
 #[derive(Clone, Eq, PartialEq, Debug, Hash, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CountryCode {
@@ -50,30 +117,282 @@ pub struct CountryCode {
     pub num: String,
 }
 
+#[cfg(feature = "std")]
+impl CountryCode {
+    /// Returns the `alpha2` field as an `Alpha2`, if it is well-formed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let code = iso3166_1::alpha2("AF").unwrap();
+    ///
+    /// assert_eq!(code.alpha2_code().unwrap().as_str(), "AF");
+    /// ```
+    pub fn alpha2_code(&self) -> Result<Alpha2, CodeError> {
+        self.alpha2.parse()
+    }
+
+    /// Returns the `alpha3` field as an `Alpha3`, if it is well-formed.
+    pub fn alpha3_code(&self) -> Result<Alpha3, CodeError> {
+        self.alpha3.parse()
+    }
+
+    /// Returns the `num` field as a `Numeric`, if it is well-formed.
+    pub fn numeric_code(&self) -> Result<Numeric, CodeError> {
+        self.num.parse()
+    }
+
+    /// Returns the `num` field as an integer, if it is well-formed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let code = iso3166_1::alpha2("AF").unwrap();
+    ///
+    /// assert_eq!(code.numeric(), Ok(4));
+    /// ```
+    pub fn numeric(&self) -> Result<u16, CodeError> {
+        self.numeric_code().map(|code| code.value())
+    }
+}
+
 /// Returns the CountryCode with the given Alpha2 code if one exists.
+#[cfg(feature = "std")]
 pub fn alpha2(alpha2: &str) -> Option<CountryCode> {
-    all().into_iter().find(|c| c.alpha2 == alpha2)
+    Entry::by_alpha2(alpha2).map(CountryCode::from)
 }
 
 /// Returns the CountryCode with the given Alpha3 code if one exists.
+#[cfg(feature = "std")]
 pub fn alpha3(alpha3: &str) -> Option<CountryCode> {
-    all().into_iter().find(|c| c.alpha3 == alpha3)
+    Entry::by_alpha3(alpha3).map(CountryCode::from)
 }
 
 /// Returns the CountryCode with the given name if one exists.
+#[cfg(feature = "std")]
 pub fn name(name: &str) -> Option<CountryCode> {
-    all().into_iter().find(|c| c.name == name)
+    Entry::by_name(name).map(CountryCode::from)
+}
+
+/// Returns the CountryCode with the given name if one exists, ignoring
+/// accents, letter case, the kinds of apostrophes and hyphens used,
+/// punctuation and spacing. Aliases are matched the same way when no name is.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(iso3166_1::name_normalized("Aland Islands").unwrap().alpha2, "AX");
+/// assert_eq!(iso3166_1::name_normalized("guinea bissau").unwrap().alpha2, "GW");
+/// assert_eq!(iso3166_1::name_normalized("Korea, Republic of").unwrap().alpha2, "KR");
+/// ```
+#[cfg(feature = "std")]
+pub fn name_normalized(name: &str) -> Option<CountryCode> {
+    Entry::by_normalized_name(name).map(CountryCode::from)
+}
+
+/// Returns the CountryCode with the given name or alias, such as `"USA"`, if
+/// one exists, along with which of the two matched.
+///
+/// # Examples
+///
+/// ```rust
+/// use iso3166_1::NameMatch;
+///
+/// let (code, matched) = iso3166_1::name_or_alias("South Korea").unwrap();
+///
+/// assert_eq!(code.alpha2, "KR");
+/// assert_eq!(matched, NameMatch::Alias);
+/// ```
+#[cfg(feature = "std")]
+pub fn name_or_alias(name: &str) -> Option<(CountryCode, NameMatch)> {
+    Entry::by_name_or_alias(name).map(|(entry, matched)| (CountryCode::from(entry), matched))
+}
+
+/// Returns the CountryCode with the given demonym or adjective, such as
+/// `"French"`, if exactly one country has it.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(iso3166_1::demonym("Filipina").unwrap().alpha2, "PH");
+/// ```
+#[cfg(feature = "std")]
+pub fn demonym(demonym: &str) -> Option<CountryCode> {
+    Entry::by_demonym(demonym).map(CountryCode::from)
+}
+
+/// Returns the CountryCode with the given demonym or adjective, or why there
+/// is none.
+#[cfg(feature = "std")]
+pub fn try_demonym(demonym: &str) -> Result<CountryCode, LookupError> {
+    Entry::try_by_demonym(demonym).map(CountryCode::from)
+}
+
+/// Returns the CountryCode with the given French short name if one exists.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(iso3166_1::name_fr("Allemagne (l')").unwrap().alpha2, "DE");
+/// ```
+#[cfg(feature = "std")]
+pub fn name_fr(name: &str) -> Option<CountryCode> {
+    Entry::by_name_fr(name).map(CountryCode::from)
+}
+
+/// Returns the CountryCode with the given full name if one exists, with or
+/// without its leading "the".
+///
+/// # Examples
+///
+/// ```rust
+/// let code = iso3166_1::name_formal("the Federal Republic of Germany").unwrap();
+///
+/// assert_eq!(code.alpha2, "DE");
+/// ```
+#[cfg(feature = "std")]
+pub fn name_formal(name: &str) -> Option<CountryCode> {
+    Entry::by_formal_name(name).map(CountryCode::from)
+}
+
+/// Returns the CountryCode with the given ISO short name, such as
+/// `"Bahamas (the)"`, if one exists.
+#[cfg(feature = "std")]
+pub fn name_iso(name: &str) -> Option<CountryCode> {
+    Entry::by_iso_name(name).map(CountryCode::from)
+}
+
+/// Returns the CountryCode with the given upper-case ISO short name, such as
+/// `"BAHAMAS (THE)"`, if one exists.
+#[cfg(feature = "std")]
+pub fn name_upper(name: &str) -> Option<CountryCode> {
+    Entry::by_upper_name(name).map(CountryCode::from)
 }
 
 /// Returns the CountryCode with the given number of one exists.
+#[cfg(feature = "std")]
 pub fn num(num: &str) -> Option<CountryCode> {
-    all().into_iter().find(|c| c.num == num)
+    Entry::by_num(num).map(CountryCode::from)
+}
+
+/// Returns the CountryCode with the given numeric code if one exists.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(iso3166_1::num_u16(4).unwrap().alpha2, "AF");
+/// ```
+#[cfg(feature = "std")]
+pub fn num_u16(num: u16) -> Option<CountryCode> {
+    Entry::by_numeric(num).map(CountryCode::from)
+}
+
+/// Returns the CountryCode with the given Alpha2 code, or why there is none.
+///
+/// # Examples
+///
+/// ```rust
+/// use iso3166_1::LookupError;
+///
+/// assert!(iso3166_1::try_alpha2("AF").is_ok());
+/// assert_eq!(iso3166_1::try_alpha2("AB"), Err(LookupError::Unassigned));
+/// assert_eq!(iso3166_1::try_alpha2("af"), Err(LookupError::InvalidCharacter('a')));
+/// ```
+#[cfg(feature = "std")]
+pub fn try_alpha2(alpha2: &str) -> Result<CountryCode, LookupError> {
+    Entry::try_by_alpha2(alpha2).map(CountryCode::from)
+}
+
+/// Returns the CountryCode with the given Alpha3 code, or why there is none.
+#[cfg(feature = "std")]
+pub fn try_alpha3(alpha3: &str) -> Result<CountryCode, LookupError> {
+    Entry::try_by_alpha3(alpha3).map(CountryCode::from)
+}
+
+/// Returns the CountryCode with the given name, or why there is none.
+#[cfg(feature = "std")]
+pub fn try_name(name: &str) -> Result<CountryCode, LookupError> {
+    Entry::try_by_name(name).map(CountryCode::from)
+}
+
+/// Returns the CountryCode with the given number, or why there is none.
+#[cfg(feature = "std")]
+pub fn try_num(num: &str) -> Result<CountryCode, LookupError> {
+    Entry::try_by_num(num).map(CountryCode::from)
+}
+
+/// Returns the CountryCode with the given Alpha2 code if one exists, ignoring
+/// surrounding whitespace and letter case.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(iso3166_1::alpha2_lenient(" gb ").unwrap().alpha2, "GB");
+/// ```
+#[cfg(feature = "std")]
+pub fn alpha2_lenient(alpha2: &str) -> Option<CountryCode> {
+    lenient::alpha2(alpha2).map(CountryCode::from)
+}
+
+/// Returns the CountryCode with the given Alpha3 code if one exists, ignoring
+/// surrounding whitespace and letter case.
+#[cfg(feature = "std")]
+pub fn alpha3_lenient(alpha3: &str) -> Option<CountryCode> {
+    lenient::alpha3(alpha3).map(CountryCode::from)
+}
+
+/// Returns the CountryCode with the given name if one exists, ignoring
+/// surrounding whitespace and ASCII letter case.
+#[cfg(feature = "std")]
+pub fn name_lenient(name: &str) -> Option<CountryCode> {
+    lenient::name(name).map(CountryCode::from)
+}
+
+/// Returns the CountryCode with the given number if one exists, ignoring
+/// surrounding whitespace and zero padding.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(iso3166_1::num_lenient("4").unwrap().alpha2, "AF");
+/// assert_eq!(iso3166_1::num_lenient("004").unwrap().alpha2, "AF");
+/// ```
+#[cfg(feature = "std")]
+pub fn num_lenient(num: &str) -> Option<CountryCode> {
+    lenient::num(num).map(CountryCode::from)
+}
+
+/// Returns a `Vec` of `CountryCode`s that have a numeric code within the given
+/// range, sorted by their numeric code.
+///
+/// An unbounded range, `..`, returns all countries.
+///
+/// # Examples
+///
+/// ```rust
+/// // Getting all values between `100` and `300`:
+/// let countries = iso3166_1::numeric_range(100..=300);
+///
+/// // Getting all values up to, but not including, `500`:
+/// let countries = iso3166_1::numeric_range(..500);
+///
+/// // Getting all values:
+/// let countries = iso3166_1::numeric_range(..);
+/// assert_eq!(countries.len(), iso3166_1::all().len());
+/// ```
+#[cfg(feature = "std")]
+pub fn numeric_range<R: RangeBounds<u16>>(range: R) -> Vec<CountryCode> {
+    Entry::by_numeric_range(range).map(CountryCode::from).collect()
 }
 
 /// Returns a `Vec` of `CountryCode`s that have a numeric value within the range
 /// of the `from` and `to` given. The from and to are optional, and can either
 /// be `None` or `Some(&str)` for variations of the range wanted.
 ///
+/// See [`numeric_range`] for a version taking any range of integers.
+///
+/// [`numeric_range`]: fn.numeric_range.html
+///
 /// # Examples
 ///
 /// Getting all values between `100` and `300`:
@@ -99,16 +418,20 @@ pub fn num(num: &str) -> Option<CountryCode> {
 /// ```
 /// let countries = iso3166_1::num_range(None, None);
 /// ```
-pub fn num_range(from: Option<&str>, to: Option<&str>) -> Result<Vec<CountryCode>, ParseIntError> {
+///
+/// An error is returned if either bound is not made of digits, or is larger
+/// than 65535.
+#[cfg(feature = "std")]
+pub fn num_range(from: Option<&str>, to: Option<&str>) -> Result<Vec<CountryCode>, LookupError> {
     let from_do = from.is_some();
     let to_do = to.is_some();
-    let from_val = try!(from.unwrap_or("0").parse::<i16>());
-    let to_val = try!(to.unwrap_or("0").parse::<i16>());
+    let from_val = parse_bound(from.unwrap_or("0"))?;
+    let to_val = parse_bound(to.unwrap_or("0"))?;
 
-    Ok(all()
-        .into_iter()
-        .filter(|code| {
-            let num_as_int = code.num.parse::<i16>().unwrap();
+    Ok(Entry::all()
+        .iter()
+        .filter(|entry| {
+            let num_as_int = entry.numeric();
             let gte = num_as_int >= from_val;
             let lte = num_as_int <= to_val;
 
@@ -124,5 +447,24 @@ pub fn num_range(from: Option<&str>, to: Option<&str>) -> Result<Vec<CountryCode
                 }
             }
         })
+        .map(CountryCode::from)
         .collect())
 }
+
+// Parses a bound of `num_range()`. Bounds may have any number of digits, such
+// as "4" or "1000", as long as they fit a `u16`.
+#[cfg(feature = "std")]
+fn parse_bound(bound: &str) -> Result<u16, LookupError> {
+    if let Some(c) = bound.chars().find(|c| !c.is_ascii_digit()) {
+        return Err(LookupError::InvalidCharacter(c));
+    }
+
+    if bound.is_empty() {
+        return Err(LookupError::InvalidLength {
+            expected: 3,
+            found: 0,
+        });
+    }
+
+    bound.parse().map_err(|_| LookupError::OutOfRange)
+}
@@ -0,0 +1,110 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 3166-1?
+//
+// | ISO 3166-1 is part of the ISO 3166 standard published by the International
+// | Organization for Standardization (ISO), and defines codes for the names of
+// | countries, dependent territories, and special areas of geographical
+// | interest.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_3166-1)
+//
+// Originally by zeyla on GitHub.
+
+/// Returns the `Country` with the given Alpha2, Alpha3 or zero-padded numeric
+/// code, failing to compile if the code is not assigned.
+///
+/// The code must be a string literal. The lookup is done in a constant, so
+/// the macro can also be used to define constants.
+///
+/// # Examples
+///
+/// ```rust
+/// #[macro_use]
+/// extern crate iso3166_1;
+///
+/// use iso3166_1::Country;
+///
+/// const DEFAULT: Country = country!("DE");
+///
+/// fn main() {
+///     assert_eq!(DEFAULT, Country::DE);
+///     assert_eq!(country!("FRA"), Country::FR);
+///     assert_eq!(country!("004"), Country::AF);
+/// }
+/// ```
+///
+/// An unassigned code is a compile error:
+///
+/// ```rust,compile_fail
+/// #[macro_use]
+/// extern crate iso3166_1;
+///
+/// fn main() {
+///     let country = country!("UK");
+/// }
+/// ```
+#[macro_export]
+macro_rules! country {
+    ($code:literal) => {{
+        const COUNTRY: $crate::Country = match $crate::Country::from_code($code) {
+            Some(country) => country,
+            None => panic!(concat!("not an assigned ISO 3166-1 code: ", $code)),
+        };
+
+        COUNTRY
+    }};
+}
+
+/// Returns an array of the `Country`s with the given codes, failing to compile
+/// if any code is not assigned.
+///
+/// Each code is checked as with [`country!`].
+///
+/// [`country!`]: macro.country.html
+///
+/// # Examples
+///
+/// ```rust
+/// #[macro_use]
+/// extern crate iso3166_1;
+///
+/// use iso3166_1::Country;
+///
+/// const EUROZONE_FOUNDERS: [Country; 3] = countries!["DE", "FR", "ITA"];
+///
+/// fn main() {
+///     assert_eq!(EUROZONE_FOUNDERS, [Country::DE, Country::FR, Country::IT]);
+/// }
+/// ```
+///
+/// A single trailing comma is allowed, but not more:
+///
+/// ```rust,compile_fail
+/// #[macro_use]
+/// extern crate iso3166_1;
+///
+/// fn main() {
+///     let countries = countries!["DE",,];
+/// }
+/// ```
+#[macro_export]
+macro_rules! countries {
+    ($($code:literal),* $(,)?) => {
+        [$($crate::country!($code)),*]
+    };
+}
@@ -0,0 +1,506 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 3166-1?
+//
+// | ISO 3166-1 is part of the ISO 3166 standard published by the International
+// | Organization for Standardization (ISO), and defines codes for the names of
+// | countries, dependent territories, and special areas of geographical
+// | interest.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_3166-1)
+//
+// Originally by zeyla on GitHub.
+
+// Other forms of the names of countries, and their French short names, as
+// published on the ISO Online Browsing Platform.
+//
+// Source:
+// https://www.iso.org/obp/ui/#search/code/
+
+use Country;
+use Country::*;
+
+// Returns the full name of a country, such as "the Federal Republic of
+// Germany", if it has one other than its short name.
+pub(crate) const fn formal(country: Country) -> Option<&'static str> {
+    match country {
+        AD => Some("the Principality of Andorra"),
+        AF => Some("the Islamic Republic of Afghanistan"),
+        AL => Some("the Republic of Albania"),
+        AM => Some("the Republic of Armenia"),
+        AO => Some("the Republic of Angola"),
+        AR => Some("the Argentine Republic"),
+        AT => Some("the Republic of Austria"),
+        AZ => Some("the Republic of Azerbaijan"),
+        BD => Some("the People's Republic of Bangladesh"),
+        BE => Some("the Kingdom of Belgium"),
+        BG => Some("the Republic of Bulgaria"),
+        BH => Some("the Kingdom of Bahrain"),
+        BI => Some("the Republic of Burundi"),
+        BJ => Some("the Republic of Benin"),
+        BO => Some("the Plurinational State of Bolivia"),
+        BR => Some("the Federative Republic of Brazil"),
+        BS => Some("the Commonwealth of the Bahamas"),
+        BT => Some("the Kingdom of Bhutan"),
+        BW => Some("the Republic of Botswana"),
+        BY => Some("the Republic of Belarus"),
+        CD => Some("the Democratic Republic of the Congo"),
+        CG => Some("the Republic of the Congo"),
+        CH => Some("the Swiss Confederation"),
+        CI => Some("the Republic of Côte d'Ivoire"),
+        CL => Some("the Republic of Chile"),
+        CM => Some("the Republic of Cameroon"),
+        CN => Some("the People's Republic of China"),
+        CO => Some("the Republic of Colombia"),
+        CR => Some("the Republic of Costa Rica"),
+        CU => Some("the Republic of Cuba"),
+        CV => Some("the Republic of Cabo Verde"),
+        CY => Some("the Republic of Cyprus"),
+        CZ => Some("the Czech Republic"),
+        DE => Some("the Federal Republic of Germany"),
+        DJ => Some("the Republic of Djibouti"),
+        DK => Some("the Kingdom of Denmark"),
+        DM => Some("the Commonwealth of Dominica"),
+        DZ => Some("the People's Democratic Republic of Algeria"),
+        EC => Some("the Republic of Ecuador"),
+        EE => Some("the Republic of Estonia"),
+        EG => Some("the Arab Republic of Egypt"),
+        ER => Some("the State of Eritrea"),
+        ES => Some("the Kingdom of Spain"),
+        ET => Some("the Federal Democratic Republic of Ethiopia"),
+        FI => Some("the Republic of Finland"),
+        FJ => Some("the Republic of Fiji"),
+        FM => Some("the Federated States of Micronesia"),
+        FR => Some("the French Republic"),
+        GA => Some("the Gabonese Republic"),
+        GB => Some("the United Kingdom of Great Britain and Northern Ireland"),
+        GH => Some("the Republic of Ghana"),
+        GM => Some("the Republic of the Gambia"),
+        GN => Some("the Republic of Guinea"),
+        GQ => Some("the Republic of Equatorial Guinea"),
+        GR => Some("the Hellenic Republic"),
+        GT => Some("the Republic of Guatemala"),
+        GW => Some("the Republic of Guinea-Bissau"),
+        GY => Some("the Co-operative Republic of Guyana"),
+        HK => Some("the Hong Kong Special Administrative Region of China"),
+        HN => Some("the Republic of Honduras"),
+        HR => Some("the Republic of Croatia"),
+        HT => Some("the Republic of Haiti"),
+        ID => Some("the Republic of Indonesia"),
+        IL => Some("the State of Israel"),
+        IN => Some("the Republic of India"),
+        IQ => Some("the Republic of Iraq"),
+        IR => Some("the Islamic Republic of Iran"),
+        IT => Some("the Italian Republic"),
+        JO => Some("the Hashemite Kingdom of Jordan"),
+        KE => Some("the Republic of Kenya"),
+        KG => Some("the Kyrgyz Republic"),
+        KH => Some("the Kingdom of Cambodia"),
+        KI => Some("the Republic of Kiribati"),
+        KM => Some("the Union of the Comoros"),
+        KP => Some("the Democratic People's Republic of Korea"),
+        KR => Some("the Republic of Korea"),
+        KW => Some("the State of Kuwait"),
+        KZ => Some("the Republic of Kazakhstan"),
+        LA => Some("the Lao People's Democratic Republic"),
+        LB => Some("the Lebanese Republic"),
+        LI => Some("the Principality of Liechtenstein"),
+        LK => Some("the Democratic Socialist Republic of Sri Lanka"),
+        LR => Some("the Republic of Liberia"),
+        LS => Some("the Kingdom of Lesotho"),
+        LT => Some("the Republic of Lithuania"),
+        LU => Some("the Grand Duchy of Luxembourg"),
+        LV => Some("the Republic of Latvia"),
+        LY => Some("the State of Libya"),
+        MA => Some("the Kingdom of Morocco"),
+        MC => Some("the Principality of Monaco"),
+        MD => Some("the Republic of Moldova"),
+        MG => Some("the Republic of Madagascar"),
+        MH => Some("the Republic of the Marshall Islands"),
+        MK => Some("the former Yugoslav Republic of Macedonia"),
+        ML => Some("the Republic of Mali"),
+        MM => Some("the Republic of the Union of Myanmar"),
+        MO => Some("the Macao Special Administrative Region of China"),
+        MR => Some("the Islamic Republic of Mauritania"),
+        MT => Some("the Republic of Malta"),
+        MU => Some("the Republic of Mauritius"),
+        MV => Some("the Republic of Maldives"),
+        MW => Some("the Republic of Malawi"),
+        MX => Some("the United Mexican States"),
+        MZ => Some("the Republic of Mozambique"),
+        NA => Some("the Republic of Namibia"),
+        NE => Some("the Republic of the Niger"),
+        NG => Some("the Federal Republic of Nigeria"),
+        NI => Some("the Republic of Nicaragua"),
+        NL => Some("the Kingdom of the Netherlands"),
+        NO => Some("the Kingdom of Norway"),
+        NP => Some("the Federal Democratic Republic of Nepal"),
+        NR => Some("the Republic of Nauru"),
+        OM => Some("the Sultanate of Oman"),
+        PA => Some("the Republic of Panama"),
+        PE => Some("the Republic of Peru"),
+        PG => Some("the Independent State of Papua New Guinea"),
+        PH => Some("the Republic of the Philippines"),
+        PK => Some("the Islamic Republic of Pakistan"),
+        PL => Some("the Republic of Poland"),
+        PS => Some("the State of Palestine"),
+        PT => Some("the Portuguese Republic"),
+        PW => Some("the Republic of Palau"),
+        PY => Some("the Republic of Paraguay"),
+        QA => Some("the State of Qatar"),
+        RS => Some("the Republic of Serbia"),
+        RW => Some("the Republic of Rwanda"),
+        SA => Some("the Kingdom of Saudi Arabia"),
+        SC => Some("the Republic of Seychelles"),
+        SD => Some("the Republic of the Sudan"),
+        SE => Some("the Kingdom of Sweden"),
+        SG => Some("the Republic of Singapore"),
+        SI => Some("the Republic of Slovenia"),
+        SK => Some("the Slovak Republic"),
+        SL => Some("the Republic of Sierra Leone"),
+        SM => Some("the Republic of San Marino"),
+        SN => Some("the Republic of Senegal"),
+        SO => Some("the Federal Republic of Somalia"),
+        SR => Some("the Republic of Suriname"),
+        SS => Some("the Republic of South Sudan"),
+        ST => Some("the Democratic Republic of Sao Tome and Principe"),
+        SV => Some("the Republic of El Salvador"),
+        SY => Some("the Syrian Arab Republic"),
+        SZ => Some("the Kingdom of Swaziland"),
+        TD => Some("the Republic of Chad"),
+        TG => Some("the Togolese Republic"),
+        TH => Some("the Kingdom of Thailand"),
+        TJ => Some("the Republic of Tajikistan"),
+        TL => Some("the Democratic Republic of Timor-Leste"),
+        TN => Some("the Republic of Tunisia"),
+        TO => Some("the Kingdom of Tonga"),
+        TR => Some("the Republic of Turkey"),
+        TT => Some("the Republic of Trinidad and Tobago"),
+        TZ => Some("the United Republic of Tanzania"),
+        UG => Some("the Republic of Uganda"),
+        US => Some("the United States of America"),
+        UY => Some("the Eastern Republic of Uruguay"),
+        UZ => Some("the Republic of Uzbekistan"),
+        VE => Some("the Bolivarian Republic of Venezuela"),
+        VN => Some("the Socialist Republic of Viet Nam"),
+        VU => Some("the Republic of Vanuatu"),
+        WS => Some("the Independent State of Samoa"),
+        YE => Some("the Republic of Yemen"),
+        ZA => Some("the Republic of South Africa"),
+        ZM => Some("the Republic of Zambia"),
+        ZW => Some("the Republic of Zimbabwe"),
+        _ => None,
+    }
+}
+
+// Returns the short name of a country as ISO publishes it, if it differs from
+// the name in the table, such as "Bahamas (the)".
+pub(crate) const fn iso(country: Country) -> Option<&'static str> {
+    match country {
+        AE => Some("United Arab Emirates (the)"),
+        BS => Some("Bahamas (the)"),
+        CC => Some("Cocos (Keeling) Islands (the)"),
+        CD => Some("Congo (the Democratic Republic of the)"),
+        CF => Some("Central African Republic (the)"),
+        CG => Some("Congo (the)"),
+        CK => Some("Cook Islands (the)"),
+        DO => Some("Dominican Republic (the)"),
+        FK => Some("Falkland Islands (the) [Malvinas]"),
+        FO => Some("Faroe Islands (the)"),
+        GB => Some("United Kingdom of Great Britain and Northern Ireland (the)"),
+        GM => Some("Gambia (the)"),
+        IO => Some("British Indian Ocean Territory (the)"),
+        KM => Some("Comoros (the)"),
+        KP => Some("Korea (the Democratic People's Republic of)"),
+        KR => Some("Korea (the Republic of)"),
+        KY => Some("Cayman Islands (the)"),
+        LA => Some("Lao People's Democratic Republic (the)"),
+        MD => Some("Moldova (the Republic of)"),
+        MH => Some("Marshall Islands (the)"),
+        MP => Some("Northern Mariana Islands (the)"),
+        NE => Some("Niger (the)"),
+        NL => Some("Netherlands (the)"),
+        PH => Some("Philippines (the)"),
+        RU => Some("Russian Federation (the)"),
+        SD => Some("Sudan (the)"),
+        SY => Some("Syrian Arab Republic (the)"),
+        TC => Some("Turks and Caicos Islands (the)"),
+        TF => Some("French Southern Territories (the)"),
+        TW => Some("Taiwan (Province of China)"),
+        TZ => Some("Tanzania, the United Republic of"),
+        UM => Some("United States Minor Outlying Islands (the)"),
+        US => Some("United States of America (the)"),
+        VA => Some("Holy See (the)"),
+        _ => None,
+    }
+}
+
+// Returns the French short name of a country, with articles in parentheses,
+// such as "Allemagne (l')".
+pub(crate) const fn fr(country: Country) -> &'static str {
+    match country {
+        AD => "Andorre (l')",
+        AE => "Émirats arabes unis (les)",
+        AF => "Afghanistan (l')",
+        AG => "Antigua-et-Barbuda",
+        AI => "Anguilla",
+        AL => "Albanie (l')",
+        AM => "Arménie (l')",
+        AO => "Angola (l')",
+        AQ => "Antarctique (l')",
+        AR => "Argentine (l')",
+        AS => "Samoa américaines (les)",
+        AT => "Autriche (l')",
+        AU => "Australie (l')",
+        AW => "Aruba",
+        AX => "Åland (les Îles)",
+        AZ => "Azerbaïdjan (l')",
+        BA => "Bosnie-Herzégovine (la)",
+        BB => "Barbade (la)",
+        BD => "Bangladesh (le)",
+        BE => "Belgique (la)",
+        BF => "Burkina Faso (le)",
+        BG => "Bulgarie (la)",
+        BH => "Bahreïn",
+        BI => "Burundi (le)",
+        BJ => "Bénin (le)",
+        BL => "Saint-Barthélemy",
+        BM => "Bermudes (les)",
+        BN => "Brunéi Darussalam (le)",
+        BO => "Bolivie (État plurinational de)",
+        BQ => "Bonaire, Saint-Eustache et Saba",
+        BR => "Brésil (le)",
+        BS => "Bahamas (les)",
+        BT => "Bhoutan (le)",
+        BV => "Bouvet (l'Île)",
+        BW => "Botswana (le)",
+        BY => "Bélarus (le)",
+        BZ => "Belize (le)",
+        CA => "Canada (le)",
+        CC => "Cocos (les Îles)/Keeling (les Îles)",
+        CD => "Congo (la République démocratique du)",
+        CF => "République centrafricaine (la)",
+        CG => "Congo (le)",
+        CH => "Suisse (la)",
+        CI => "Côte d'Ivoire (la)",
+        CK => "Cook (les Îles)",
+        CL => "Chili (le)",
+        CM => "Cameroun (le)",
+        CN => "Chine (la)",
+        CO => "Colombie (la)",
+        CR => "Costa Rica (le)",
+        CU => "Cuba",
+        CV => "Cabo Verde",
+        CW => "Curaçao",
+        CX => "Christmas (l'Île)",
+        CY => "Chypre",
+        CZ => "République tchèque (la)",
+        DE => "Allemagne (l')",
+        DJ => "Djibouti",
+        DK => "Danemark (le)",
+        DM => "Dominique (la)",
+        DO => "dominicaine (la République)",
+        DZ => "Algérie (l')",
+        EC => "Équateur (l')",
+        EE => "Estonie (l')",
+        EG => "Égypte (l')",
+        EH => "Sahara occidental (le)",
+        ER => "Érythrée (l')",
+        ES => "Espagne (l')",
+        ET => "Éthiopie (l')",
+        FI => "Finlande (la)",
+        FJ => "Fidji (les)",
+        FK => "Falkland (les Îles)/Malouines (les Îles)",
+        FM => "Micronésie (États fédérés de)",
+        FO => "Féroé (les Îles)",
+        FR => "France (la)",
+        GA => "Gabon (le)",
+        GB => "Royaume-Uni de Grande-Bretagne et d'Irlande du Nord (le)",
+        GD => "Grenade (la)",
+        GE => "Géorgie (la)",
+        GF => "Guyane française (la)",
+        GG => "Guernesey",
+        GH => "Ghana (le)",
+        GI => "Gibraltar",
+        GL => "Groenland (le)",
+        GM => "Gambie (la)",
+        GN => "Guinée (la)",
+        GP => "Guadeloupe (la)",
+        GQ => "Guinée équatoriale (la)",
+        GR => "Grèce (la)",
+        GS => "Géorgie du Sud-et-les Îles Sandwich du Sud (la)",
+        GT => "Guatemala (le)",
+        GU => "Guam",
+        GW => "Guinée-Bissau (la)",
+        GY => "Guyana (le)",
+        HK => "Hong Kong",
+        HM => "Heard-et-Îles MacDonald (l'Île)",
+        HN => "Honduras (le)",
+        HR => "Croatie (la)",
+        HT => "Haïti",
+        HU => "Hongrie (la)",
+        ID => "Indonésie (l')",
+        IE => "Irlande (l')",
+        IL => "Israël",
+        IM => "Île de Man",
+        IN => "Inde (l')",
+        IO => "Indien (le Territoire britannique de l'océan)",
+        IQ => "Iraq (l')",
+        IR => "Iran (République Islamique d')",
+        IS => "Islande (l')",
+        IT => "Italie (l')",
+        JE => "Jersey",
+        JM => "Jamaïque (la)",
+        JO => "Jordanie (la)",
+        JP => "Japon (le)",
+        KE => "Kenya (le)",
+        KG => "Kirghizistan (le)",
+        KH => "Cambodge (le)",
+        KI => "Kiribati",
+        KM => "Comores (les)",
+        KN => "Saint-Kitts-et-Nevis",
+        KP => "Corée (la République populaire démocratique de)",
+        KR => "Corée (la République de)",
+        KW => "Koweït (le)",
+        KY => "Caïmans (les Îles)",
+        KZ => "Kazakhstan (le)",
+        LA => "Lao (la République démocratique populaire)",
+        LB => "Liban (le)",
+        LC => "Sainte-Lucie",
+        LI => "Liechtenstein (le)",
+        LK => "Sri Lanka",
+        LR => "Libéria (le)",
+        LS => "Lesotho (le)",
+        LT => "Lituanie (la)",
+        LU => "Luxembourg (le)",
+        LV => "Lettonie (la)",
+        LY => "Libye (la)",
+        MA => "Maroc (le)",
+        MC => "Monaco",
+        MD => "Moldavie (la République de)",
+        ME => "Monténégro (le)",
+        MF => "Saint-Martin (partie française)",
+        MG => "Madagascar",
+        MH => "Marshall (les Îles)",
+        MK => "Macédoine (l'ex‑République yougoslave de)",
+        ML => "Mali (le)",
+        MM => "Myanmar (le)",
+        MN => "Mongolie (la)",
+        MO => "Macao",
+        MP => "Mariannes du Nord (les Îles)",
+        MQ => "Martinique (la)",
+        MR => "Mauritanie (la)",
+        MS => "Montserrat",
+        MT => "Malte",
+        MU => "Maurice",
+        MV => "Maldives (les)",
+        MW => "Malawi (le)",
+        MX => "Mexique (le)",
+        MY => "Malaisie (la)",
+        MZ => "Mozambique (le)",
+        NA => "Namibie (la)",
+        NC => "Nouvelle-Calédonie (la)",
+        NE => "Niger (le)",
+        NF => "Norfolk (l'Île)",
+        NG => "Nigéria (le)",
+        NI => "Nicaragua (le)",
+        NL => "Pays-Bas (les)",
+        NO => "Norvège (la)",
+        NP => "Népal (le)",
+        NR => "Nauru",
+        NU => "Niue",
+        NZ => "Nouvelle-Zélande (la)",
+        OM => "Oman",
+        PA => "Panama (le)",
+        PE => "Pérou (le)",
+        PF => "Polynésie française (la)",
+        PG => "Papouasie-Nouvelle-Guinée (la)",
+        PH => "Philippines (les)",
+        PK => "Pakistan (le)",
+        PL => "Pologne (la)",
+        PM => "Saint-Pierre-et-Miquelon",
+        PN => "Pitcairn",
+        PR => "Porto Rico",
+        PS => "Palestine, État de",
+        PT => "Portugal (le)",
+        PW => "Palaos (les)",
+        PY => "Paraguay (le)",
+        QA => "Qatar (le)",
+        RE => "Réunion (La)",
+        RO => "Roumanie (la)",
+        RS => "Serbie (la)",
+        RU => "Russie (la Fédération de)",
+        RW => "Rwanda (le)",
+        SA => "Arabie saoudite (l')",
+        SB => "Salomon (les Îles)",
+        SC => "Seychelles (les)",
+        SD => "Soudan (le)",
+        SE => "Suède (la)",
+        SG => "Singapour",
+        SH => "Sainte-Hélène, Ascension et Tristan da Cunha",
+        SI => "Slovénie (la)",
+        SJ => "Svalbard et l'Île Jan Mayen (le)",
+        SK => "Slovaquie (la)",
+        SL => "Sierra Leone (la)",
+        SM => "Saint-Marin",
+        SN => "Sénégal (le)",
+        SO => "Somalie (la)",
+        SR => "Suriname (le)",
+        SS => "Soudan du Sud (le)",
+        ST => "Sao Tomé-et-Principe",
+        SV => "El Salvador",
+        SX => "Saint-Martin (partie néerlandaise)",
+        SY => "République arabe syrienne (la)",
+        SZ => "Swaziland (le)",
+        TC => "Turks-et-Caïcos (les Îles)",
+        TD => "Tchad (le)",
+        TF => "Terres australes françaises (les)",
+        TG => "Togo (le)",
+        TH => "Thaïlande (la)",
+        TJ => "Tadjikistan (le)",
+        TK => "Tokelau (les)",
+        TL => "Timor-Leste (le)",
+        TM => "Turkménistan (le)",
+        TN => "Tunisie (la)",
+        TO => "Tonga (les)",
+        TR => "Turquie (la)",
+        TT => "Trinité-et-Tobago (la)",
+        TV => "Tuvalu (les)",
+        TW => "Taïwan (Province de Chine)",
+        TZ => "Tanzanie (la République-Unie de)",
+        UA => "Ukraine (l')",
+        UG => "Ouganda (l')",
+        UM => "Îles mineures éloignées des États-Unis (les)",
+        US => "États-Unis d'Amérique (les)",
+        UY => "Uruguay (l')",
+        UZ => "Ouzbékistan (l')",
+        VA => "Saint-Siège (le)",
+        VC => "Saint-Vincent-et-les Grenadines",
+        VE => "Venezuela (République bolivarienne du)",
+        VG => "Vierges britanniques (les Îles)",
+        VI => "Vierges des États-Unis (les Îles)",
+        VN => "Viet Nam (le)",
+        VU => "Vanuatu (le)",
+        WF => "Wallis-et-Futuna",
+        WS => "Samoa (le)",
+        YE => "Yémen (le)",
+        YT => "Mayotte",
+        ZA => "Afrique du Sud (l')",
+        ZM => "Zambie (la)",
+        ZW => "Zimbabwe (le)",
+    }
+}
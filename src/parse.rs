@@ -0,0 +1,140 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 3166-1?
+//
+// | ISO 3166-1 is part of the ISO 3166 standard published by the International
+// | Organization for Standardization (ISO), and defines codes for the names of
+// | countries, dependent territories, and special areas of geographical
+// | interest.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_3166-1)
+//
+// Originally by zeyla on GitHub.
+
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use {lenient, CountryCode, Entry, LookupError};
+
+/// The kind of identifier that an input to [`parse`] matched.
+///
+/// [`parse`]: fn.parse.html
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum CodeKind {
+    /// A two-character Alpha2 code, such as `"DE"`
+    Alpha2,
+    /// A three-character Alpha3 code, such as `"DEU"`
+    Alpha3,
+    /// A numeric code, such as `"276"`
+    Numeric,
+    /// An English short name, such as `"Germany"`
+    Name,
+}
+
+impl Display for CodeKind {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.write_str(match *self {
+            CodeKind::Alpha2 => "alpha2",
+            CodeKind::Alpha3 => "alpha3",
+            CodeKind::Numeric => "numeric",
+            CodeKind::Name => "name",
+        })
+    }
+}
+
+/// The result of [`parse`]: the country found, and the kind of identifier it
+/// was found by.
+///
+/// [`parse`]: fn.parse.html
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Parsed {
+    /// The country found
+    pub code: CountryCode,
+    /// The kind of identifier the input matched
+    pub kind: CodeKind,
+}
+
+/// Returns the CountryCode identified by any kind of identifier, along with
+/// the kind that matched.
+///
+/// The input is matched leniently, as with [`alpha2_lenient`] and friends:
+/// surrounding whitespace, letter case and zero padding are ignored. Digits
+/// are looked up as a numeric code, and two or three letters as an Alpha2 or
+/// Alpha3 code. Everything else is looked up as a name.
+///
+/// If the input matches different countries as different kinds of
+/// identifier, [`LookupError::Ambiguous`] is returned rather than guessing.
+///
+/// [`alpha2_lenient`]: fn.alpha2_lenient.html
+/// [`LookupError::Ambiguous`]: enum.LookupError.html#variant.Ambiguous
+///
+/// # Examples
+///
+/// ```rust
+/// use iso3166_1::CodeKind;
+///
+/// let parsed = iso3166_1::parse("deu").unwrap();
+///
+/// assert_eq!(parsed.code.alpha2, "DE");
+/// assert_eq!(parsed.kind, CodeKind::Alpha3);
+///
+/// assert_eq!(iso3166_1::parse("276").unwrap().kind, CodeKind::Numeric);
+/// assert_eq!(iso3166_1::parse("Germany").unwrap().kind, CodeKind::Name);
+/// ```
+pub fn parse(input: &str) -> Result<Parsed, LookupError> {
+    let input = input.trim();
+
+    if !input.is_empty() && input.bytes().all(|b| b.is_ascii_digit()) {
+        // Leading zeros may pad the code to any length.
+        let digits = input.trim_start_matches('0');
+
+        if digits.len() > 3 {
+            return Err(LookupError::InvalidLength {
+                expected: 3,
+                found: digits.len(),
+            });
+        }
+
+        return match lenient::num(input) {
+            Some(entry) => Ok(found(entry, CodeKind::Numeric)),
+            None => Err(LookupError::num(&format!("{:0>3}", digits))),
+        };
+    }
+
+    let is_code = input.bytes().all(|b| b.is_ascii_alphabetic());
+    let code = match input.len() {
+        2 if is_code => lenient::alpha2(input).map(|entry| (entry, CodeKind::Alpha2)),
+        3 if is_code => lenient::alpha3(input).map(|entry| (entry, CodeKind::Alpha3)),
+        _ => None,
+    };
+    let name = lenient::name(input).map(|entry| (entry, CodeKind::Name));
+
+    match (code, name) {
+        (Some(code), Some(name)) if code.0 != name.0 => Err(LookupError::Ambiguous),
+        (Some((entry, kind)), _) | (None, Some((entry, kind))) => Ok(found(entry, kind)),
+        (None, None) => Err(match input.len() {
+            2 if is_code => LookupError::alpha2(&input.to_ascii_uppercase()),
+            3 if is_code => LookupError::alpha3(&input.to_ascii_uppercase()),
+            _ => LookupError::UnknownName,
+        }),
+    }
+}
+
+fn found(entry: &'static Entry, kind: CodeKind) -> Parsed {
+    Parsed {
+        code: CountryCode::from(entry),
+        kind,
+    }
+}
@@ -0,0 +1,231 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 3166-1?
+//
+// | ISO 3166-1 is part of the ISO 3166 standard published by the International
+// | Organization for Standardization (ISO), and defines codes for the names of
+// | countries, dependent territories, and special areas of geographical
+// | interest.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_3166-1)
+//
+// Originally by zeyla on GitHub.
+
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::ops::{Bound, RangeBounds};
+use {parse_bound, CodeError, CountryCode, LookupError};
+
+/// An error returned when an entry cannot be added to a [`Registry`].
+///
+/// [`Registry`]: struct.Registry.html
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum InsertError {
+    /// One of the codes of the entry is not well-formed.
+    Malformed(CodeError),
+    /// The entry has the Alpha2, Alpha3 or numeric code, or the name, of
+    /// another entry of the registry, which is given.
+    Conflict(CountryCode),
+}
+
+/// A set of countries to look codes up in, starting from the countries of
+/// ISO 3166-1 and changed at runtime.
+///
+/// Entries can be added, overridden, such as to change a display name, or
+/// hidden. The free functions, such as [`alpha2`], always look up the
+/// unchanged dataset, as `Registry::default()` would.
+///
+/// [`alpha2`]: fn.alpha2.html
+///
+/// # Examples
+///
+/// ```rust
+/// use iso3166_1::{CountryCode, Registry};
+///
+/// let mut registry = Registry::default();
+///
+/// let mut gb = registry.alpha2("GB").unwrap();
+/// gb.name = "United Kingdom".to_owned();
+/// registry.insert(gb).unwrap();
+///
+/// registry.hide("AQ");
+///
+/// assert_eq!(registry.alpha3("GBR").unwrap().name, "United Kingdom");
+/// assert!(registry.alpha2("AQ").is_none());
+/// assert!(iso3166_1::alpha2("AQ").is_some());
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Registry {
+    // Sorted by numeric code.
+    entries: Vec<CountryCode>,
+}
+
+impl Registry {
+    /// Creates a registry with no entries.
+    pub fn empty() -> Registry {
+        Registry { entries: Vec::new() }
+    }
+
+    /// Adds an entry, replacing the entry with the same Alpha2 code if there
+    /// is one.
+    ///
+    /// The replaced entry is returned. An error is returned, and nothing
+    /// changed, if any of the entry's codes are not well-formed, or if
+    /// another entry already has its Alpha3 code, numeric code or name.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iso3166_1::{CountryCode, Registry};
+    ///
+    /// let mut registry = Registry::default();
+    ///
+    /// let replaced = registry.insert(CountryCode {
+    ///     alpha2: "XK".to_owned(),
+    ///     alpha3: "XKX".to_owned(),
+    ///     name: "Kosovo".to_owned(),
+    ///     num: "983".to_owned(),
+    /// });
+    ///
+    /// assert_eq!(replaced, Ok(None));
+    /// assert_eq!(registry.num("983").unwrap().alpha2, "XK");
+    /// ```
+    pub fn insert(&mut self, code: CountryCode) -> Result<Option<CountryCode>, InsertError> {
+        code.alpha2_code()?;
+        code.alpha3_code()?;
+        code.numeric_code()?;
+
+        let conflict = self.find(|entry| {
+            entry.alpha2 != code.alpha2
+                && (entry.alpha3 == code.alpha3 || entry.num == code.num || entry.name == code.name)
+        });
+
+        if let Some(conflict) = conflict {
+            return Err(InsertError::Conflict(conflict));
+        }
+
+        let replaced = self.hide(&code.alpha2);
+        let position = self
+            .entries
+            .iter()
+            .position(|entry| entry.num > code.num)
+            .unwrap_or(self.entries.len());
+
+        self.entries.insert(position, code);
+
+        Ok(replaced)
+    }
+
+    /// Removes the entry with the given Alpha2 code from the registry,
+    /// returning it if there was one.
+    pub fn hide(&mut self, alpha2: &str) -> Option<CountryCode> {
+        self.entries
+            .iter()
+            .position(|entry| entry.alpha2 == alpha2)
+            .map(|position| self.entries.remove(position))
+    }
+
+    /// Returns all entries of the registry, sorted by their numeric code.
+    pub fn all(&self) -> &[CountryCode] {
+        &self.entries
+    }
+
+    /// Returns the entry with the given Alpha2 code if one exists.
+    pub fn alpha2(&self, alpha2: &str) -> Option<CountryCode> {
+        self.find(|entry| entry.alpha2 == alpha2)
+    }
+
+    /// Returns the entry with the given Alpha3 code if one exists.
+    pub fn alpha3(&self, alpha3: &str) -> Option<CountryCode> {
+        self.find(|entry| entry.alpha3 == alpha3)
+    }
+
+    /// Returns the entry with the given name if one exists.
+    pub fn name(&self, name: &str) -> Option<CountryCode> {
+        self.find(|entry| entry.name == name)
+    }
+
+    /// Returns the entry with the given number if one exists.
+    pub fn num(&self, num: &str) -> Option<CountryCode> {
+        self.find(|entry| entry.num == num)
+    }
+
+    /// Returns the entries with a numeric code within the given range, sorted
+    /// by their numeric code.
+    pub fn numeric_range<R: RangeBounds<u16>>(&self, range: R) -> Vec<CountryCode> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.numeric().is_ok_and(|num| range.contains(&num)))
+            .cloned()
+            .collect()
+    }
+
+    /// Returns the entries with a numeric code between `from` and `to`, as
+    /// with the free function [`num_range`].
+    ///
+    /// [`num_range`]: fn.num_range.html
+    pub fn num_range(
+        &self,
+        from: Option<&str>,
+        to: Option<&str>,
+    ) -> Result<Vec<CountryCode>, LookupError> {
+        let from = match from {
+            Some(from) => Bound::Included(parse_bound(from)?),
+            None => Bound::Unbounded,
+        };
+        let to = match to {
+            Some(to) => Bound::Included(parse_bound(to)?),
+            None => Bound::Unbounded,
+        };
+
+        if from == Bound::Unbounded && to == Bound::Unbounded {
+            return Ok(Vec::new());
+        }
+
+        Ok(self.numeric_range((from, to)))
+    }
+
+    fn find<F: Fn(&CountryCode) -> bool>(&self, predicate: F) -> Option<CountryCode> {
+        self.entries.iter().find(|entry| predicate(entry)).cloned()
+    }
+}
+
+impl Default for Registry {
+    /// Creates a registry of the countries of ISO 3166-1.
+    fn default() -> Registry {
+        Registry { entries: ::all() }
+    }
+}
+
+impl Display for InsertError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match *self {
+            InsertError::Malformed(ref error) => Display::fmt(error, f),
+            InsertError::Conflict(ref entry) => {
+                write!(f, "codes or name already used by {}", entry.alpha2)
+            }
+        }
+    }
+}
+
+impl Error for InsertError {}
+
+impl From<CodeError> for InsertError {
+    fn from(error: CodeError) -> InsertError {
+        InsertError::Malformed(error)
+    }
+}
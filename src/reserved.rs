@@ -0,0 +1,544 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 3166-1?
+//
+// | ISO 3166-1 is part of the ISO 3166 standard published by the International
+// | Organization for Standardization (ISO), and defines codes for the names of
+// | countries, dependent territories, and special areas of geographical
+// | interest.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_3166-1)
+//
+// Originally by zeyla on GitHub.
+
+// Codes that ISO 3166/MA has reserved rather than assigned, such as "UK" and
+// "EU". They are not countries, but they are not free to be assigned either.
+//
+// Source:
+// https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2#Reserved_code_elements
+
+use core::fmt::{Display, Formatter, Result as FmtResult};
+use Country::*;
+use {index, is_user_assigned, iso3166_3, Country};
+
+/// Why a code is reserved.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ReservationCategory {
+    /// Reserved at the request of an organization that uses the code, such as
+    /// `"UK"` or `"EU"`.
+    Exceptional,
+    /// Reserved for a period after being withdrawn, such as `"YU"`, so that it
+    /// is not reassigned while still in use.
+    Transitional,
+    /// Reserved because the code is used outside of ISO 3166-1, such as
+    /// `"RA"` on vehicle registration plates, and may conflict with it.
+    Indeterminate,
+    /// Used by intellectual property organizations, such as `"EP"`, and agreed
+    /// not to be assigned.
+    NotUsed,
+}
+
+impl Display for ReservationCategory {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.write_str(match *self {
+            ReservationCategory::Exceptional => "exceptionally reserved",
+            ReservationCategory::Transitional => "transitionally reserved",
+            ReservationCategory::Indeterminate => "indeterminately reserved",
+            ReservationCategory::NotUsed => "not used",
+        })
+    }
+}
+
+/// An Alpha2 or Alpha3 code that is reserved rather than assigned.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Reservation {
+    /// The reserved Alpha2 or Alpha3 code
+    pub code: &'static str,
+    /// Why the code is reserved
+    pub category: ReservationCategory,
+    /// What the code refers to
+    pub refers_to: &'static str,
+    /// The countries whose assigned codes should be used instead, if any
+    pub replacements: &'static [Country],
+}
+
+/// The status of an Alpha2, Alpha3 or numeric code.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ReservationStatus {
+    /// The code is assigned to a country.
+    Assigned(Country),
+    /// The code is reserved.
+    Reserved(&'static Reservation),
+    /// The code was withdrawn. It may still be transitionally reserved, as
+    /// [`reservation`] tells.
+    ///
+    /// [`reservation`]: fn.reservation.html
+    Withdrawn(&'static iso3166_3::FormerCountry),
+    /// The code is in a range left for users to assign, such as `"XK"`.
+    UserAssigned,
+    /// The code is neither assigned nor reserved, or is not well-formed.
+    Unassigned,
+}
+
+static RESERVED: &[Reservation] = &[
+    // Exceptionally reserved at the request of an organization.
+    Reservation {
+        code: "AC",
+        category: ReservationCategory::Exceptional,
+        refers_to: "Ascension Island",
+        replacements: &[SH],
+    },
+    Reservation {
+        code: "CP",
+        category: ReservationCategory::Exceptional,
+        refers_to: "Clipperton Island",
+        replacements: &[FR],
+    },
+    Reservation {
+        code: "CQ",
+        category: ReservationCategory::Exceptional,
+        refers_to: "Island of Sark",
+        replacements: &[GG],
+    },
+    Reservation {
+        code: "DG",
+        category: ReservationCategory::Exceptional,
+        refers_to: "Diego Garcia",
+        replacements: &[IO],
+    },
+    Reservation {
+        code: "EA",
+        category: ReservationCategory::Exceptional,
+        refers_to: "Ceuta and Melilla",
+        replacements: &[ES],
+    },
+    Reservation {
+        code: "EU",
+        category: ReservationCategory::Exceptional,
+        refers_to: "European Union",
+        replacements: &[],
+    },
+    Reservation {
+        code: "EZ",
+        category: ReservationCategory::Exceptional,
+        refers_to: "Eurozone",
+        replacements: &[],
+    },
+    Reservation {
+        code: "FX",
+        category: ReservationCategory::Exceptional,
+        refers_to: "France, Metropolitan",
+        replacements: &[FR],
+    },
+    Reservation {
+        code: "IC",
+        category: ReservationCategory::Exceptional,
+        refers_to: "Canary Islands",
+        replacements: &[ES],
+    },
+    Reservation {
+        code: "SU",
+        category: ReservationCategory::Exceptional,
+        refers_to: "USSR",
+        replacements: &[],
+    },
+    Reservation {
+        code: "TA",
+        category: ReservationCategory::Exceptional,
+        refers_to: "Tristan da Cunha",
+        replacements: &[SH],
+    },
+    Reservation {
+        code: "UK",
+        category: ReservationCategory::Exceptional,
+        refers_to: "United Kingdom",
+        replacements: &[GB],
+    },
+    Reservation {
+        code: "UN",
+        category: ReservationCategory::Exceptional,
+        refers_to: "United Nations",
+        replacements: &[],
+    },
+    Reservation {
+        code: "FXX",
+        category: ReservationCategory::Exceptional,
+        refers_to: "France, Metropolitan",
+        replacements: &[FR],
+    },
+    Reservation {
+        code: "SUN",
+        category: ReservationCategory::Exceptional,
+        refers_to: "USSR",
+        replacements: &[],
+    },
+
+    // Transitionally reserved while a withdrawn code is phased out.
+    Reservation {
+        code: "AN",
+        category: ReservationCategory::Transitional,
+        refers_to: "Netherlands Antilles",
+        replacements: &[BQ, CW, SX],
+    },
+    Reservation {
+        code: "BU",
+        category: ReservationCategory::Transitional,
+        refers_to: "Burma",
+        replacements: &[MM],
+    },
+    Reservation {
+        code: "CS",
+        category: ReservationCategory::Transitional,
+        refers_to: "Serbia and Montenegro",
+        replacements: &[ME, RS],
+    },
+    Reservation {
+        code: "NT",
+        category: ReservationCategory::Transitional,
+        refers_to: "Neutral Zone",
+        replacements: &[IQ, SA],
+    },
+    Reservation {
+        code: "SF",
+        category: ReservationCategory::Transitional,
+        refers_to: "Finland",
+        replacements: &[FI],
+    },
+    Reservation {
+        code: "TP",
+        category: ReservationCategory::Transitional,
+        refers_to: "East Timor",
+        replacements: &[TL],
+    },
+    Reservation {
+        code: "YU",
+        category: ReservationCategory::Transitional,
+        refers_to: "Yugoslavia",
+        replacements: &[ME, RS],
+    },
+    Reservation {
+        code: "ZR",
+        category: ReservationCategory::Transitional,
+        refers_to: "Zaire",
+        replacements: &[CD],
+    },
+    Reservation {
+        code: "ANT",
+        category: ReservationCategory::Transitional,
+        refers_to: "Netherlands Antilles",
+        replacements: &[BQ, CW, SX],
+    },
+    Reservation {
+        code: "BUR",
+        category: ReservationCategory::Transitional,
+        refers_to: "Burma",
+        replacements: &[MM],
+    },
+    Reservation {
+        code: "BYS",
+        category: ReservationCategory::Transitional,
+        refers_to: "Byelorussian SSR",
+        replacements: &[BY],
+    },
+    Reservation {
+        code: "CSK",
+        category: ReservationCategory::Transitional,
+        refers_to: "Czechoslovakia",
+        replacements: &[CZ, SK],
+    },
+    Reservation {
+        code: "NTZ",
+        category: ReservationCategory::Transitional,
+        refers_to: "Neutral Zone",
+        replacements: &[IQ, SA],
+    },
+    Reservation {
+        code: "ROM",
+        category: ReservationCategory::Transitional,
+        refers_to: "Romania",
+        replacements: &[RO],
+    },
+    Reservation {
+        code: "SCG",
+        category: ReservationCategory::Transitional,
+        refers_to: "Serbia and Montenegro",
+        replacements: &[ME, RS],
+    },
+    Reservation {
+        code: "TMP",
+        category: ReservationCategory::Transitional,
+        refers_to: "East Timor",
+        replacements: &[TL],
+    },
+    Reservation {
+        code: "YUG",
+        category: ReservationCategory::Transitional,
+        refers_to: "Yugoslavia",
+        replacements: &[ME, RS],
+    },
+    Reservation {
+        code: "ZAR",
+        category: ReservationCategory::Transitional,
+        refers_to: "Zaire",
+        replacements: &[CD],
+    },
+
+    // Indeterminately reserved, as used on vehicle registration plates.
+    Reservation {
+        code: "DY",
+        category: ReservationCategory::Indeterminate,
+        refers_to: "Benin",
+        replacements: &[BJ],
+    },
+    Reservation {
+        code: "EW",
+        category: ReservationCategory::Indeterminate,
+        refers_to: "Estonia",
+        replacements: &[EE],
+    },
+    Reservation {
+        code: "FL",
+        category: ReservationCategory::Indeterminate,
+        refers_to: "Liechtenstein",
+        replacements: &[LI],
+    },
+    Reservation {
+        code: "JA",
+        category: ReservationCategory::Indeterminate,
+        refers_to: "Jamaica",
+        replacements: &[JM],
+    },
+    Reservation {
+        code: "LF",
+        category: ReservationCategory::Indeterminate,
+        refers_to: "Libya Fezzan",
+        replacements: &[LY],
+    },
+    Reservation {
+        code: "PI",
+        category: ReservationCategory::Indeterminate,
+        refers_to: "Philippines",
+        replacements: &[PH],
+    },
+    Reservation {
+        code: "RA",
+        category: ReservationCategory::Indeterminate,
+        refers_to: "Argentina",
+        replacements: &[AR],
+    },
+    Reservation {
+        code: "RB",
+        category: ReservationCategory::Indeterminate,
+        refers_to: "Bolivia or Botswana",
+        replacements: &[BO, BW],
+    },
+    Reservation {
+        code: "RC",
+        category: ReservationCategory::Indeterminate,
+        refers_to: "China",
+        replacements: &[CN],
+    },
+    Reservation {
+        code: "RH",
+        category: ReservationCategory::Indeterminate,
+        refers_to: "Haiti",
+        replacements: &[HT],
+    },
+    Reservation {
+        code: "RI",
+        category: ReservationCategory::Indeterminate,
+        refers_to: "Indonesia",
+        replacements: &[ID],
+    },
+    Reservation {
+        code: "RL",
+        category: ReservationCategory::Indeterminate,
+        refers_to: "Lebanon",
+        replacements: &[LB],
+    },
+    Reservation {
+        code: "RM",
+        category: ReservationCategory::Indeterminate,
+        refers_to: "Madagascar",
+        replacements: &[MG],
+    },
+    Reservation {
+        code: "RN",
+        category: ReservationCategory::Indeterminate,
+        refers_to: "Niger",
+        replacements: &[NE],
+    },
+    Reservation {
+        code: "RP",
+        category: ReservationCategory::Indeterminate,
+        refers_to: "Philippines",
+        replacements: &[PH],
+    },
+    Reservation {
+        code: "WG",
+        category: ReservationCategory::Indeterminate,
+        refers_to: "Grenada",
+        replacements: &[GD],
+    },
+    Reservation {
+        code: "WL",
+        category: ReservationCategory::Indeterminate,
+        refers_to: "Saint Lucia",
+        replacements: &[LC],
+    },
+    Reservation {
+        code: "WV",
+        category: ReservationCategory::Indeterminate,
+        refers_to: "Saint Vincent",
+        replacements: &[VC],
+    },
+    Reservation {
+        code: "YV",
+        category: ReservationCategory::Indeterminate,
+        refers_to: "Venezuela",
+        replacements: &[VE],
+    },
+
+    // Not to be used, as used by intellectual property organizations.
+    Reservation {
+        code: "AP",
+        category: ReservationCategory::NotUsed,
+        refers_to: "African Regional Industrial Property Organization",
+        replacements: &[],
+    },
+    Reservation {
+        code: "BX",
+        category: ReservationCategory::NotUsed,
+        refers_to: "Benelux Trademarks and Designs Office",
+        replacements: &[],
+    },
+    Reservation {
+        code: "EF",
+        category: ReservationCategory::NotUsed,
+        refers_to: "Union of Countries under the European Community Patent Convention",
+        replacements: &[],
+    },
+    Reservation {
+        code: "EM",
+        category: ReservationCategory::NotUsed,
+        refers_to: "European Trademark Office",
+        replacements: &[],
+    },
+    Reservation {
+        code: "EP",
+        category: ReservationCategory::NotUsed,
+        refers_to: "European Patent Organization",
+        replacements: &[],
+    },
+    Reservation {
+        code: "EV",
+        category: ReservationCategory::NotUsed,
+        refers_to: "Eurasian Patent Organization",
+        replacements: &[],
+    },
+    Reservation {
+        code: "GC",
+        category: ReservationCategory::NotUsed,
+        refers_to: "Patent Office of the Cooperation Council for the Arab States of the Gulf",
+        replacements: &[],
+    },
+    Reservation {
+        code: "IB",
+        category: ReservationCategory::NotUsed,
+        refers_to: "International Bureau of WIPO",
+        replacements: &[],
+    },
+    Reservation {
+        code: "OA",
+        category: ReservationCategory::NotUsed,
+        refers_to: "African Intellectual Property Organization",
+        replacements: &[],
+    },
+    Reservation {
+        code: "WO",
+        category: ReservationCategory::NotUsed,
+        refers_to: "World Intellectual Property Organization",
+        replacements: &[],
+    },
+];
+
+/// Returns all reserved codes.
+pub fn reservations() -> &'static [Reservation] {
+    RESERVED
+}
+
+/// Returns the reservation of the given Alpha2 or Alpha3 code, if it is
+/// reserved.
+pub fn reservation(code: &str) -> Option<&'static Reservation> {
+    RESERVED.iter().find(|r| r.code == code)
+}
+
+/// Returns whether the given Alpha2, Alpha3 or numeric code is assigned,
+/// withdrawn, reserved, user-assigned, or none of those.
+///
+/// A code that was withdrawn and is still reserved, such as `"YU"`, is
+/// `Withdrawn`, as it is a `LookupError::Withdrawn` for lookups. Numeric codes
+/// are never reserved, so a three-digit code is `Assigned`, `Withdrawn`,
+/// `UserAssigned` from `"900"` to `"999"`, or `Unassigned`.
+///
+/// # Examples
+///
+/// ```rust
+/// use iso3166_1::{reservation_status, Country, ReservationCategory, ReservationStatus};
+///
+/// assert_eq!(reservation_status("GB"), ReservationStatus::Assigned(Country::GB));
+/// assert_eq!(reservation_status("826"), ReservationStatus::Assigned(Country::GB));
+///
+/// match reservation_status("UK") {
+///     ReservationStatus::Reserved(reservation) => {
+///         assert_eq!(reservation.category, ReservationCategory::Exceptional);
+///         assert_eq!(reservation.replacements, &[Country::GB]);
+///     },
+///     _ => unreachable!(),
+/// }
+/// ```
+pub fn reservation_status(code: &str) -> ReservationStatus {
+    let numeric = code.len() == 3 && code.bytes().all(|byte| byte.is_ascii_digit());
+    let assigned = match code.len() {
+        2 => index::alpha2(code),
+        3 if numeric => index::num(code),
+        3 => index::alpha3(code),
+        _ => return ReservationStatus::Unassigned,
+    };
+
+    if let Some(entry) = assigned {
+        return ReservationStatus::Assigned(entry.country);
+    }
+
+    // Withdrawn codes come before reserved ones, as in `LookupError`, since
+    // most of them are also transitionally reserved.
+    let withdrawn = match code.len() {
+        2 => iso3166_3::alpha2(code),
+        _ if numeric => iso3166_3::num(code),
+        _ => iso3166_3::alpha3(code),
+    };
+
+    if let Some(former) = withdrawn {
+        return ReservationStatus::Withdrawn(former);
+    }
+
+    match reservation(code) {
+        Some(reservation) => ReservationStatus::Reserved(reservation),
+        None if is_user_assigned(code) => ReservationStatus::UserAssigned,
+        None => ReservationStatus::Unassigned,
+    }
+}
@@ -0,0 +1,195 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 3166-1?
+//
+// | ISO 3166-1 is part of the ISO 3166 standard published by the International
+// | Organization for Standardization (ISO), and defines codes for the names of
+// | countries, dependent territories, and special areas of geographical
+// | interest.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_3166-1)
+//
+// Originally by zeyla on GitHub.
+
+// Fuzzy search over the names, aliases and codes of countries, ranked by the
+// Jaro-Winkler similarity of their folded forms.
+
+use codes::ENTRIES;
+use {fold, CountryCode, Entry};
+
+/// The score a candidate of [`search`] needs at least to be returned.
+///
+/// [`search`]: fn.search.html
+pub const DEFAULT_THRESHOLD: f64 = 0.85;
+
+/// A country found by [`search`], with how similar it is to the query.
+///
+/// [`search`]: fn.search.html
+#[derive(Clone, Debug, PartialEq)]
+pub struct Candidate {
+    /// The country found
+    pub code: CountryCode,
+    /// The name, alias or code of the country closest to the query
+    pub matched: &'static str,
+    /// The similarity of `matched` to the query, from 0 to 1
+    pub score: f64,
+}
+
+/// Returns up to `limit` countries whose name, alias or code is similar to the
+/// query, best first.
+///
+/// Names and aliases are compared by their Jaro-Winkler similarity, ignoring
+/// accents, letter case and punctuation as [`name_normalized`] does. Codes
+/// only match exactly, in any case, with a score of 1. Candidates scoring
+/// below [`DEFAULT_THRESHOLD`] are left out.
+///
+/// [`name_normalized`]: fn.name_normalized.html
+/// [`DEFAULT_THRESHOLD`]: constant.DEFAULT_THRESHOLD.html
+///
+/// # Examples
+///
+/// ```rust
+/// let candidates = iso3166_1::search("Phillipines", 3);
+///
+/// assert_eq!(candidates[0].code.alpha2, "PH");
+/// assert!(candidates[0].score > 0.9);
+/// ```
+pub fn search(query: &str, limit: usize) -> Vec<Candidate> {
+    search_with_threshold(query, limit, DEFAULT_THRESHOLD)
+}
+
+/// Returns up to `limit` countries similar to the query, as [`search`] does,
+/// leaving out those scoring below `threshold`.
+///
+/// [`search`]: fn.search.html
+///
+/// # Examples
+///
+/// ```rust
+/// let candidates = iso3166_1::search_with_threshold("Kazakstan", 10, 0.95);
+///
+/// assert_eq!(candidates.len(), 1);
+/// assert_eq!(candidates[0].code.name, "Kazakhstan");
+/// ```
+pub fn search_with_threshold(query: &str, limit: usize, threshold: f64) -> Vec<Candidate> {
+    let query: Vec<char> = fold::fold(query).collect();
+
+    if query.is_empty() {
+        return Vec::new();
+    }
+
+    let mut found: Vec<(&'static Entry, &'static str, f64)> = ENTRIES
+        .iter()
+        .filter_map(|entry| best(entry, &query))
+        .filter(|&(_, _, score)| score >= threshold)
+        .collect();
+
+    found.sort_by(|a, b| b.2.total_cmp(&a.2).then_with(|| a.0.name.cmp(b.0.name)));
+    found.truncate(limit);
+
+    found
+        .into_iter()
+        .map(|(entry, matched, score)| Candidate {
+            code: CountryCode::from(entry),
+            matched,
+            score,
+        })
+        .collect()
+}
+
+// Returns the name, alias or code of the entry closest to the folded query.
+fn best(entry: &'static Entry, query: &[char]) -> Option<(&'static Entry, &'static str, f64)> {
+    let mut best: Option<(&'static str, f64)> = None;
+
+    for &code in &[entry.alpha2, entry.alpha3] {
+        if code.len() == query.len()
+            && code
+                .chars()
+                .zip(query)
+                .all(|(a, &b)| a.eq_ignore_ascii_case(&b))
+        {
+            return Some((entry, code, 1.0));
+        }
+    }
+
+    for &name in Some(&entry.name).into_iter().chain(entry.aliases()) {
+        let folded: Vec<char> = fold::fold(name).collect();
+        let score = jaro_winkler(query, &folded);
+
+        if best.is_none_or(|(_, best)| score > best) {
+            best = Some((name, score));
+        }
+    }
+
+    best.map(|(name, score)| (entry, name, score))
+}
+
+// The Jaro similarity of two strings, boosted by the length of the prefix they
+// share, up to four characters.
+fn jaro_winkler(a: &[char], b: &[char]) -> f64 {
+    let jaro = jaro(a, b);
+    let prefix = a.iter().zip(b).take(4).take_while(|(a, b)| a == b).count();
+
+    jaro + prefix as f64 * 0.1 * (1.0 - jaro)
+}
+
+fn jaro(a: &[char], b: &[char]) -> f64 {
+    if a.is_empty() || b.is_empty() {
+        return if a.len() == b.len() { 1.0 } else { 0.0 };
+    }
+
+    let window = (a.len().max(b.len()) / 2).saturating_sub(1);
+    let mut a_matched = vec![false; a.len()];
+    let mut b_matched = vec![false; b.len()];
+    let mut matches = 0;
+
+    for (i, &c) in a.iter().enumerate() {
+        let start = i.saturating_sub(window);
+        let end = (i + window + 1).min(b.len());
+
+        for j in start..end {
+            if !b_matched[j] && b[j] == c {
+                a_matched[i] = true;
+                b_matched[j] = true;
+                matches += 1;
+                break;
+            }
+        }
+    }
+
+    if matches == 0 {
+        return 0.0;
+    }
+
+    let a_chars = a
+        .iter()
+        .zip(&a_matched)
+        .filter(|&(_, &m)| m)
+        .map(|(c, _)| c);
+    let b_chars = b
+        .iter()
+        .zip(&b_matched)
+        .filter(|&(_, &m)| m)
+        .map(|(c, _)| c);
+    let transpositions = a_chars.zip(b_chars).filter(|&(a, b)| a != b).count() / 2;
+    let matches = matches as f64;
+
+    (matches / a.len() as f64
+        + matches / b.len() as f64
+        + (matches - transpositions as f64) / matches)
+        / 3.0
+}
@@ -0,0 +1,196 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 3166-1?
+//
+// | ISO 3166-1 is part of the ISO 3166 standard published by the International
+// | Organization for Standardization (ISO), and defines codes for the names of
+// | countries, dependent territories, and special areas of geographical
+// | interest.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_3166-1)
+//
+// Originally by zeyla on GitHub.
+
+// Codes that ISO 3166-1 leaves to its users, which it will never assign.
+//
+// Source:
+// https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2#User-assigned_code_elements
+
+#[cfg(feature = "std")]
+use {CountryCode, Entry};
+
+/// Returns whether the given Alpha2, Alpha3 or numeric code is in one of the
+/// ranges left for users to assign.
+///
+/// These are `"AA"`, `"QM"` to `"QZ"`, `"XA"` to `"XZ"` and `"ZZ"`, the Alpha3
+/// codes starting with those letters, and the numeric codes `"900"` to
+/// `"999"`.
+///
+/// # Examples
+///
+/// ```rust
+/// use iso3166_1::is_user_assigned;
+///
+/// assert!(is_user_assigned("XK"));
+/// assert!(is_user_assigned("QMA"));
+/// assert!(is_user_assigned("999"));
+/// assert!(!is_user_assigned("QL"));
+/// assert!(!is_user_assigned("xk"));
+/// ```
+pub const fn is_user_assigned(code: &str) -> bool {
+    let bytes = code.as_bytes();
+
+    if bytes.len() != 2 && bytes.len() != 3 {
+        return false;
+    }
+
+    if bytes[0].is_ascii_digit() {
+        return bytes.len() == 3
+            && bytes[0] == b'9'
+            && bytes[1].is_ascii_digit()
+            && bytes[2].is_ascii_digit();
+    }
+
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_uppercase() {
+            return false;
+        }
+
+        i += 1;
+    }
+
+    match (bytes[0], bytes[1]) {
+        (b'A', b'A') | (b'Z', b'Z') | (b'X', _) => true,
+        (b'Q', second) => second >= b'M',
+        _ => false,
+    }
+}
+
+/// A set of entries using user-assigned codes, such as `"XK"` for Kosovo,
+/// looked up alongside the countries of ISO 3166-1.
+///
+/// # Examples
+///
+/// ```rust
+/// use iso3166_1::{CountryCode, UserAssigned};
+///
+/// let mut codes = UserAssigned::with_kosovo();
+///
+/// codes.insert(CountryCode {
+///     alpha2: "QS".to_owned(),
+///     alpha3: "QSA".to_owned(),
+///     name: "Somewhere".to_owned(),
+///     num: "901".to_owned(),
+/// }).unwrap();
+///
+/// assert_eq!(codes.alpha2("XK").unwrap().name, "Kosovo");
+/// assert_eq!(codes.alpha3("QSA").unwrap().name, "Somewhere");
+/// assert_eq!(codes.alpha2("DE").unwrap().name, "Germany");
+/// ```
+#[cfg(feature = "std")]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct UserAssigned {
+    entries: Vec<CountryCode>,
+}
+
+#[cfg(feature = "std")]
+impl UserAssigned {
+    /// Creates an empty set.
+    pub fn new() -> UserAssigned {
+        UserAssigned::default()
+    }
+
+    /// Creates a set holding Kosovo, as `"XK"` and `"XKX"`.
+    ///
+    /// Kosovo has no agreed numeric code, so `"983"` from the user-assigned
+    /// range is used.
+    pub fn with_kosovo() -> UserAssigned {
+        UserAssigned {
+            entries: vec![CountryCode {
+                alpha2: "XK".to_owned(),
+                alpha3: "XKX".to_owned(),
+                name: "Kosovo".to_owned(),
+                num: "983".to_owned(),
+            }],
+        }
+    }
+
+    /// Adds an entry to the set.
+    ///
+    /// The entry is given back if any of its codes are not user-assigned, if
+    /// its codes or name are already in the set, or if its name is the name
+    /// or an alias of an assigned country, such as "Germany" or "UK".
+    pub fn insert(&mut self, code: CountryCode) -> Result<(), CountryCode> {
+        let valid = code.alpha2.len() == 2
+            && code.alpha3.len() == 3
+            && is_user_assigned(&code.alpha2)
+            && is_user_assigned(&code.alpha3)
+            && is_user_assigned(&code.num);
+        let taken = self.entries.iter().any(|entry| {
+            entry.alpha2 == code.alpha2
+                || entry.alpha3 == code.alpha3
+                || entry.name == code.name
+                || entry.num == code.num
+        }) || Entry::by_name_or_alias(&code.name).is_some();
+
+        if !valid || taken {
+            return Err(code);
+        }
+
+        self.entries.push(code);
+
+        Ok(())
+    }
+
+    /// Returns the user-assigned entries in the set, in the order they were
+    /// added.
+    pub fn entries(&self) -> &[CountryCode] {
+        &self.entries
+    }
+
+    /// Returns the country or user-assigned entry with the given Alpha2 code,
+    /// if one exists.
+    pub fn alpha2(&self, code: &str) -> Option<CountryCode> {
+        self.find(|entry| entry.alpha2 == code)
+            .or_else(|| ::alpha2(code))
+    }
+
+    /// Returns the country or user-assigned entry with the given Alpha3 code,
+    /// if one exists.
+    pub fn alpha3(&self, code: &str) -> Option<CountryCode> {
+        self.find(|entry| entry.alpha3 == code)
+            .or_else(|| ::alpha3(code))
+    }
+
+    /// Returns the country or user-assigned entry with the given name, if one
+    /// exists.
+    pub fn name(&self, name: &str) -> Option<CountryCode> {
+        self.find(|entry| entry.name == name).or_else(|| ::name(name))
+    }
+
+    /// Returns the country or user-assigned entry with the given number, if
+    /// one exists.
+    pub fn num(&self, code: &str) -> Option<CountryCode> {
+        self.find(|entry| entry.num == code).or_else(|| ::num(code))
+    }
+
+    fn find<F: Fn(&CountryCode) -> bool>(&self, predicate: F) -> Option<CountryCode> {
+        self.entries.iter().find(|entry| predicate(entry)).cloned()
+    }
+}
@@ -25,11 +25,19 @@
 //
 // Originally by zeyla on GitHub.
 //
+#![cfg(feature = "std")]
+
+#[macro_use]
 extern crate iso3166_1;
 
-use iso3166_1::{all, alpha2, alpha3, name, num, num_range};
+use iso3166_1::{
+    all, alpha2, alpha3, name, num, num_range, Alpha2, Alpha3, CodeError, Country, CountryCode,
+    Entry, LookupError, Numeric,
+};
+use std::convert::TryFrom;
 
 #[test]
+#[allow(clippy::len_zero)]
 fn get_all() {
     assert!(all().len() > 0);
 }
@@ -84,6 +92,7 @@ fn get_num() {
 }
 
 #[test]
+#[allow(clippy::len_zero)]
 fn get_num_range() {
     // Test a Some-Some range with countries with nums within it.
     assert!(num_range(Some("001"), Some("005")).unwrap().len() > 0);
@@ -104,8 +113,930 @@ fn get_num_range() {
     assert!(num_range(None, None).unwrap().len() == 0);
 }
 
+#[test]
+fn country_accessors() {
+    assert_eq!(Country::DE.alpha2(), "DE");
+    assert_eq!(Country::DE.alpha3(), "DEU");
+    assert_eq!(Country::DE.name(), "Germany");
+    assert_eq!(Country::DE.numeric(), 276);
+}
+
+// Ensure every `CountryCode` converts to a `Country` and back without loss.
+#[test]
+fn country_conversions() {
+    for code in all() {
+        let country = Country::try_from(code.clone()).unwrap();
+
+        assert_eq!(CountryCode::from(country), code);
+    }
+
+    let mut code = CountryCode::from(Country::AF);
+    code.name = String::from("Not Afghanistan");
+    assert!(Country::try_from(code).is_err());
+}
+
+// Ensure the static lookups agree with the owned ones.
+#[test]
+fn entry_lookups() {
+    for entry in Entry::all() {
+        let code = CountryCode::from(entry);
+
+        assert_eq!(Entry::by_alpha2(entry.alpha2), Some(entry));
+        assert_eq!(Entry::by_alpha3(entry.alpha3), Some(entry));
+        assert_eq!(Entry::by_name(entry.name), Some(entry));
+        assert_eq!(Entry::by_num(entry.num), Some(entry));
+        assert_eq!(alpha2(entry.alpha2), Some(code));
+        assert_eq!(entry.country.entry(), entry);
+    }
+
+    assert!(Entry::by_alpha2("AA").is_none());
+}
+
+#[test]
+fn typed_codes() {
+    let alpha2: Alpha2 = "DE".parse().unwrap();
+    let alpha3 = Alpha3::try_from("DEU").unwrap();
+    let numeric = Numeric::try_from(276).unwrap();
+
+    assert_eq!(alpha2.to_alpha3(), Some(alpha3));
+    assert_eq!(alpha3.to_numeric(), Some(numeric));
+    assert_eq!(numeric.to_alpha2(), Some(alpha2));
+    assert_eq!(numeric.to_string(), "276");
+    assert_eq!(Numeric::try_from("004").unwrap().value(), 4);
+
+    // Well-formed, but not assigned.
+    assert_eq!(Alpha2::try_from("AA").unwrap().country(), None);
+
+    assert_eq!(
+        "DEU".parse::<Alpha2>(),
+        Err(CodeError::Length {
+            expected: 2,
+            found: 3,
+        })
+    );
+    assert_eq!("De".parse::<Alpha2>(), Err(CodeError::Character('e')));
+    assert_eq!("27a".parse::<Numeric>(), Err(CodeError::Character('a')));
+    assert!(Numeric::try_from(1000).is_err());
+
+    let code = iso3166_1::alpha2("DE").unwrap();
+    assert_eq!(code.alpha2_code(), Ok(alpha2));
+    assert_eq!(code.alpha3_code(), Ok(alpha3));
+    assert_eq!(code.numeric_code(), Ok(numeric));
+}
+
+#[test]
+fn lookup_errors() {
+    use iso3166_1::{try_alpha2, try_alpha3, try_name, try_num};
+
+    assert_eq!(try_alpha2("DE").unwrap().alpha3, "DEU");
+    assert_eq!(
+        try_alpha2("DEU"),
+        Err(LookupError::InvalidLength {
+            expected: 2,
+            found: 3,
+        })
+    );
+    assert_eq!(try_alpha2("D3"), Err(LookupError::InvalidCharacter('3')));
+    assert_eq!(try_alpha2("AB"), Err(LookupError::Unassigned));
+    assert_eq!(
+        try_alpha2("EU"),
+        Err(LookupError::Reserved(iso3166_1::reservation("EU").unwrap()))
+    );
+    assert_eq!(
+        try_alpha2("YU"),
+        Err(LookupError::Withdrawn {
+            successors: &[Country::ME, Country::RS],
+        })
+    );
+    assert_eq!(
+        try_alpha3("ZAR"),
+        Err(LookupError::Withdrawn {
+            successors: &[Country::CD],
+        })
+    );
+    assert_eq!(try_name("Zaire"), Err(LookupError::UnknownName));
+    assert_eq!(try_num("000"), Err(LookupError::Unassigned));
+    assert!(num_range(Some("1o0"), None).is_err());
+    assert_eq!(num_range(Some("70000"), None), Err(LookupError::OutOfRange));
+    assert_eq!(num_range(None, Some("65535")).unwrap().len(), all().len());
+}
+
+#[test]
+fn lenient_lookups() {
+    use iso3166_1::{alpha2_lenient, alpha3_lenient, name_lenient, num_lenient};
+
+    assert_eq!(alpha2_lenient("gb").unwrap().alpha2, "GB");
+    assert_eq!(alpha3_lenient(" DeU ").unwrap().alpha2, "DE");
+    assert_eq!(name_lenient("  germany").unwrap().alpha2, "DE");
+    assert_eq!(num_lenient("4").unwrap().alpha2, "AF");
+    assert_eq!(num_lenient("04").unwrap().alpha2, "AF");
+    assert_eq!(num_lenient(" 004\n").unwrap().alpha2, "AF");
+    assert_eq!(num_lenient("0004").unwrap().alpha2, "AF");
+
+    assert!(alpha2_lenient("g b").is_none());
+    assert!(alpha3_lenient("DE").is_none());
+    assert!(num_lenient("-4").is_none());
+    assert!(num_lenient("").is_none());
+    assert!(num_lenient("1004").is_none());
+
+    // The strict lookups are unaffected.
+    assert!(alpha2("gb").is_none());
+    assert!(num("4").is_none());
+}
+
+#[test]
+fn parse_any_kind() {
+    use iso3166_1::{parse, CodeKind};
+
+    assert_eq!(parse("GB").unwrap().kind, CodeKind::Alpha2);
+    assert_eq!(parse(" deu ").unwrap().kind, CodeKind::Alpha3);
+    assert_eq!(parse("4").unwrap().kind, CodeKind::Numeric);
+    assert_eq!(parse("004").unwrap().code.alpha2, "AF");
+    assert_eq!(parse("bosnia and herzegovina").unwrap().kind, CodeKind::Name);
+
+    assert_eq!(
+        parse("UK"),
+        Err(LookupError::Reserved(iso3166_1::reservation("UK").unwrap()))
+    );
+    assert_eq!(parse("899"), Err(LookupError::Unassigned));
+    assert_eq!(parse("0899"), Err(LookupError::Unassigned));
+
+    let too_long = LookupError::InvalidLength {
+        expected: 3,
+        found: 4,
+    };
+
+    assert_eq!(parse("1234"), Err(too_long));
+    assert_eq!(parse("01234"), Err(too_long));
+    assert_eq!(iso3166_1::try_num("1234"), Err(too_long));
+    assert_eq!(parse("D3U"), Err(LookupError::UnknownName));
+    assert_eq!(parse(""), Err(LookupError::UnknownName));
+}
+
+#[test]
+fn get_numeric_range() {
+    use iso3166_1::{num_u16, numeric_range};
+
+    assert_eq!(num_u16(4).unwrap().alpha2, "AF");
+    assert!(num_u16(0).is_none());
+    assert!(num_u16(1000).is_none());
+    assert_eq!(alpha2("AF").unwrap().numeric(), Ok(4));
+
+    assert_eq!(numeric_range(1..=5).len(), 1);
+    assert!(numeric_range(1..4).is_empty());
+    assert!(numeric_range(1000..).is_empty());
+    assert_eq!(numeric_range(..), {
+        let mut all = all();
+        all.sort_by_key(|code| code.numeric().unwrap());
+        all
+    });
+
+    let countries = numeric_range(100..=300);
+    assert!(countries.iter().all(|c| (100..=300).contains(&c.numeric().unwrap())));
+    assert!(countries.windows(2).all(|w| w[0].num < w[1].num));
+}
+
+#[test]
+fn const_lookups() {
+    const DE: Option<Country> = Country::from_alpha2("DE");
+    const FR: Country = country!("FRA");
+    const NAME: &str = Country::AF.name();
+    const LIST: [Country; 3] = countries!["DE", "FRA", "004"];
+
+    assert_eq!(DE, Some(Country::DE));
+    assert_eq!(FR, Country::FR);
+    assert_eq!(NAME, "Afghanistan");
+    assert_eq!(LIST, [Country::DE, Country::FR, Country::AF]);
+    assert_eq!(countries!["DE", "FR",], [Country::DE, Country::FR]);
+    assert_eq!(Country::from_alpha3("AFG"), Some(Country::AF));
+    assert_eq!(Country::from_numeric(4), Some(Country::AF));
+    assert_eq!(Country::from_code("UK"), None);
+    assert_eq!(Country::from_code("0004"), None);
+}
+
+#[test]
+fn former_countries() {
+    use iso3166_1::iso3166_3;
+
+    for former in iso3166_3::all() {
+        assert_eq!(former.code.len(), 4);
+        assert!(former.code.starts_with(former.alpha2));
+        assert!(former.validity.until.is_some());
+        assert!(former.validity.from < former.validity.until);
+        assert!(!former.successors.is_empty());
+    }
+
+    // "CS" was withdrawn twice: the most recent is Serbia and Montenegro.
+    assert_eq!(iso3166_3::alpha2("CS").unwrap().code, "CSXX");
+    assert_eq!(
+        iso3166_3::successors("CSK"),
+        Some(&[Country::CZ, Country::SK][..])
+    );
+    assert_eq!(
+        iso3166_3::successors("ANHH"),
+        Some(&[Country::BQ, Country::CW, Country::SX][..])
+    );
+    assert_eq!(iso3166_3::code("DDDE").unwrap().name, "German Democratic Republic");
+    assert_eq!(iso3166_3::successors("DE"), None);
+}
+
+#[test]
+fn reserved_codes() {
+    use iso3166_1::{reservation_status, reservations, ReservationCategory, ReservationStatus};
+
+    for reservation in reservations() {
+        // No reserved code is assigned.
+        assert!(alpha2(reservation.code).is_none());
+        assert!(alpha3(reservation.code).is_none());
+    }
+
+    assert_eq!(reservation_status("DE"), ReservationStatus::Assigned(Country::DE));
+    assert_eq!(reservation_status("DEU"), ReservationStatus::Assigned(Country::DE));
+    assert_eq!(reservation_status("AB"), ReservationStatus::Unassigned);
+    assert_eq!(reservation_status("Germany"), ReservationStatus::Unassigned);
+    assert_eq!(reservation_status("004"), ReservationStatus::Assigned(Country::AF));
+    assert_eq!(reservation_status("900"), ReservationStatus::UserAssigned);
+    assert_eq!(reservation_status("001"), ReservationStatus::Unassigned);
+    assert_eq!(reservation_status("0004"), ReservationStatus::Unassigned);
+
+    match reservation_status("278") {
+        ReservationStatus::Withdrawn(former) => assert_eq!(former.code, "DDDE"),
+        status => panic!("unexpected status {:?}", status),
+    }
+
+    match reservation_status("ROM") {
+        ReservationStatus::Reserved(reservation) => {
+            assert_eq!(reservation.category, ReservationCategory::Transitional);
+            assert_eq!(reservation.replacements, &[Country::RO]);
+        }
+        status => panic!("unexpected status {:?}", status),
+    }
+
+    match reservation_status("DD") {
+        ReservationStatus::Withdrawn(former) => assert_eq!(former.code, "DDDE"),
+        status => panic!("unexpected status {:?}", status),
+    }
+
+    match reservation_status("EP") {
+        ReservationStatus::Reserved(reservation) => {
+            assert_eq!(reservation.category, ReservationCategory::NotUsed);
+            assert!(reservation.replacements.is_empty());
+        }
+        status => panic!("unexpected status {:?}", status),
+    }
+
+    // Withdrawn codes that are still reserved are reported as withdrawn, both
+    // by the status and by lookups.
+    let lookup = |code: &str| match code.len() {
+        2 => iso3166_1::try_alpha2(code),
+        _ => iso3166_1::try_alpha3(code),
+    };
+
+    for &code in &["YU", "AN", "CS", "TP", "ZR", "FX", "SU", "ZAR"] {
+        assert!(iso3166_1::reservation(code).is_some(), "{}", code);
+
+        match (reservation_status(code), lookup(code)) {
+            (ReservationStatus::Withdrawn(_), Err(LookupError::Withdrawn { .. })) => {}
+            status => panic!("unexpected status {:?} of {}", status, code),
+        }
+    }
+
+    for reservation in reservations() {
+        match (reservation_status(reservation.code), lookup(reservation.code)) {
+            (ReservationStatus::Withdrawn(_), Err(LookupError::Withdrawn { .. })) => {}
+            (ReservationStatus::Reserved(_), Err(LookupError::Reserved(_))) => {}
+            status => panic!("unexpected status {:?} of {}", status, reservation.code),
+        }
+    }
+}
+
+#[test]
+fn user_assigned_codes() {
+    use iso3166_1::{is_user_assigned, reservation_status, ReservationStatus, UserAssigned};
+
+    for code in &["AA", "QM", "QZ", "XA", "XK", "ZZ", "AAA", "QMX", "XKX", "ZZZ", "900"] {
+        assert!(is_user_assigned(code), "{} is user-assigned", code);
+    }
+
+    for code in &["AB", "QL", "ZY", "QLA", "899", "xk", "X", "XKXX", "9A9"] {
+        assert!(!is_user_assigned(code), "{} is not user-assigned", code);
+    }
+
+    // No country is assigned a user-assigned code.
+    for entry in Entry::all() {
+        assert!(!is_user_assigned(entry.alpha2));
+        assert!(!is_user_assigned(entry.alpha3));
+        assert!(!is_user_assigned(entry.num));
+    }
+
+    assert!(Alpha2::try_from("XK").unwrap().is_user_assigned());
+    assert!(Numeric::try_from(999).unwrap().is_user_assigned());
+    assert_eq!(iso3166_1::try_alpha2("XK"), Err(LookupError::UserAssigned));
+    assert_eq!(iso3166_1::try_num("999"), Err(LookupError::UserAssigned));
+    assert_eq!(reservation_status("ZZ"), ReservationStatus::UserAssigned);
+    assert!(alpha2("XK").is_none());
+
+    let mut codes = UserAssigned::with_kosovo();
+    let private = CountryCode {
+        alpha2: "QS".to_owned(),
+        alpha3: "QSA".to_owned(),
+        name: "Somewhere".to_owned(),
+        num: "901".to_owned(),
+    };
+
+    assert_eq!(codes.alpha2("XK").unwrap().alpha3, "XKX");
+    assert_eq!(codes.name("Kosovo").unwrap().alpha2, "XK");
+    assert!(codes.insert(private.clone()).is_ok());
+    assert_eq!(codes.insert(private.clone()), Err(private));
+    assert_eq!(codes.num("901").unwrap().alpha2, "QS");
+    assert_eq!(codes.alpha3("DEU").unwrap().alpha2, "DE");
+    assert_eq!(codes.entries().len(), 2);
+
+    let assigned = alpha2("DE").unwrap();
+    assert_eq!(codes.insert(assigned.clone()), Err(assigned));
+
+    // Names of assigned countries, and their aliases, are not taken over.
+    for name in &["Germany", "UK"] {
+        let impostor = CountryCode {
+            alpha2: "QT".to_owned(),
+            alpha3: "QTA".to_owned(),
+            name: name.to_string(),
+            num: "902".to_owned(),
+        };
+
+        assert_eq!(codes.insert(impostor.clone()), Err(impostor));
+    }
+
+    assert_eq!(codes.name("Germany").unwrap().alpha2, "DE");
+}
+
+#[test]
+fn registry_lookups() {
+    use iso3166_1::{InsertError, Registry};
+
+    let mut registry = Registry::default();
+
+    assert_eq!(registry.all(), &all()[..]);
+    assert_eq!(registry.alpha2("DE"), alpha2("DE"));
+    assert_eq!(registry.num_range(None, None), Ok(Vec::new()));
+    assert_eq!(
+        registry.num_range(Some("1"), Some("10")),
+        num_range(Some("1"), Some("10"))
+    );
+
+    let mut germany = alpha2("DE").unwrap();
+    germany.name = "Deutschland".to_owned();
+
+    assert_eq!(registry.insert(germany), Ok(alpha2("DE")));
+    assert_eq!(registry.name("Deutschland").unwrap().alpha3, "DEU");
+    assert!(registry.name("Germany").is_none());
+    assert_eq!(registry.all().len(), all().len());
+
+    let kosovo = CountryCode {
+        alpha2: "XK".to_owned(),
+        alpha3: "XKX".to_owned(),
+        name: "Kosovo".to_owned(),
+        num: "983".to_owned(),
+    };
+
+    assert_eq!(registry.insert(kosovo.clone()), Ok(None));
+    assert_eq!(registry.all().last(), Some(&kosovo));
+    assert_eq!(registry.numeric_range(900..), vec![kosovo.clone()]);
+    assert_eq!(registry.hide("XK"), Some(kosovo));
+    assert_eq!(registry.hide("XK"), None);
+
+    let mut malformed = alpha2("FR").unwrap();
+    malformed.num = "4".to_owned();
+
+    assert_eq!(
+        registry.insert(malformed),
+        Err(InsertError::Malformed(CodeError::Length {
+            expected: 3,
+            found: 1
+        }))
+    );
+
+    // An entry may not take the codes or name of another entry.
+    let deutschland = registry.alpha2("DE").unwrap();
+
+    for &(alpha3, name, num) in &[
+        ("DEU", "Kosovo", "983"),
+        ("XKX", "Deutschland", "983"),
+        ("XKX", "Kosovo", "276"),
+    ] {
+        let taken = CountryCode {
+            alpha2: "XK".to_owned(),
+            alpha3: alpha3.to_owned(),
+            name: name.to_owned(),
+            num: num.to_owned(),
+        };
+
+        assert_eq!(registry.insert(taken), Err(InsertError::Conflict(deutschland.clone())));
+    }
+
+    assert!(registry.alpha2("XK").is_none());
+    assert_eq!(registry.num("276"), Some(deutschland));
+    assert!(Registry::empty().alpha2("DE").is_none());
+}
+
+#[test]
+fn point_in_time() {
+    use iso3166_1::{as_of, changes, Date};
+
+    let view = as_of(Date::new(2000, 6, 1));
+
+    assert_eq!(view.alpha2("TR").unwrap().name, "Turkey");
+    assert_eq!(view.name("Czech Republic").unwrap().country, Some(Country::CZ));
+    assert_eq!(view.alpha2("YU").unwrap().name, "Yugoslavia");
+    assert_eq!(view.alpha2("AN").unwrap().country, None);
+    assert_eq!(view.alpha3("ROM").unwrap().country, Some(Country::RO));
+    assert!(view.alpha2("RS").is_none());
+    assert!(view.alpha3("ROU").is_none());
+
+    let view = as_of(Date::new(2004, 1, 1));
+
+    assert_eq!(view.alpha2("CS").unwrap().name, "Serbia and Montenegro");
+    assert_eq!(view.alpha2("MK").unwrap().name, "Macedonia (the former Yugoslav Republic of)");
+    assert_eq!(view.alpha2("TL").unwrap().alpha3, "TLS");
+    assert!(view.alpha2("TP").is_none());
+
+    // Names are those in use on the day, not those adopted since.
+    let view = as_of(Date::new(2005, 1, 1));
+
+    for &(alpha2, name) in &[
+        ("BO", "Bolivia"),
+        ("CV", "Cape Verde"),
+        ("PS", "Palestinian Territory, Occupied"),
+        ("VE", "Venezuela"),
+    ] {
+        assert_eq!(view.alpha2(alpha2).unwrap().name, name);
+    }
+
+    assert_eq!(as_of(Date::new(1980, 1, 1)).alpha2("KH").unwrap().name, "Kampuchea, Democratic");
+    assert_eq!(Entry::by_alpha2("CV").unwrap().name_validity().from, Some(Date::new(2013, 10, 24)));
+
+    // Withdrawals known to the day take effect on that day.
+    let view = as_of(Date::new(1990, 6, 1));
+
+    assert_eq!(view.alpha2("DD").unwrap().name, "German Democratic Republic");
+    assert_eq!(view.alpha2("DE").unwrap().num, Some("280"));
+    assert!(view.alpha2("YD").is_none());
+    assert_eq!(view.alpha2("YE").unwrap().num, Some("887"));
+    assert!(as_of(Date::new(1990, 5, 21)).alpha2("YD").is_some());
+    assert!(as_of(Date::new(1991, 12, 25)).alpha2("SU").is_some());
+    assert!(as_of(Date::new(1991, 12, 26)).alpha2("RU").is_some());
+    assert!(as_of(Date::new(1997, 5, 16)).alpha2("ZR").is_some());
+    assert!(as_of(Date::new(1997, 5, 17)).alpha2("CD").is_some());
+    assert!(as_of(Date::new(1989, 6, 17)).alpha2("BU").is_some());
+
+    // No two countries shared a code at any point.
+    let dates = (1974..2030)
+        .map(|year| Date::new(year, 6, 1))
+        .chain(changes().iter().map(|change| change.date));
+
+    for date in dates {
+        let mut all = as_of(date).all();
+        let count = all.len();
+
+        all.dedup_by_key(|entry| entry.alpha2);
+        assert_eq!(all.len(), count, "an Alpha2 code is shared on {}", date);
+        all.sort_by_key(|entry| entry.alpha3);
+        all.dedup_by_key(|entry| entry.alpha3);
+        assert_eq!(all.len(), count, "an Alpha3 code is shared on {}", date);
+        all.sort_by_key(|entry| entry.num);
+        all.dedup_by(|a, b| a.num.is_some() && a.num == b.num);
+        assert_eq!(all.len(), count, "a numeric code is shared on {}", date);
+    }
+
+    let today = as_of(Date::new(2026, 1, 1)).all();
+
+    assert_eq!(today.len(), all().len());
+    assert!(today.iter().all(|entry| entry.country.is_some()));
+    assert_eq!(alpha2("MK").unwrap().name, "Macedonia (the former Yugoslav Republic of)");
+    assert_eq!(Country::TR.name(), "Turkey");
+    assert_eq!(name("Turkey").unwrap().alpha2, "TR");
+
+    // Names adopted since the table was last updated are only in the view.
+    let today = as_of(Date::new(2026, 1, 1));
+
+    assert_eq!(today.alpha2("TR").unwrap().name, "Türkiye");
+    assert_eq!(today.alpha2("MK").unwrap().name, "North Macedonia");
+    assert!(today.name("Czech Republic").is_none());
+    assert_eq!(as_of(Date::new(2016, 7, 4)).alpha2("CZ").unwrap().name, "Czech Republic");
+    assert_eq!(as_of(Date::new(2016, 7, 5)).alpha2("CZ").unwrap().name, "Czechia");
+}
+
+#[test]
+fn dates() {
+    use iso3166_1::Date;
+
+    assert_eq!(Date::new(2000, 2, 29).to_string(), "2000-02-29");
+    assert_eq!(Date::new(2004, 2, 29).day(), 29);
+    assert_eq!(Date::new(2010, 12, 31).day(), 31);
+}
+
+#[test]
+#[should_panic(expected = "day out of range")]
+fn date_past_end_of_month() {
+    iso3166_1::Date::new(2010, 4, 31);
+}
+
+#[test]
+#[should_panic(expected = "day out of range")]
+fn date_leap_day_of_common_year() {
+    iso3166_1::Date::new(1900, 2, 29);
+}
+
+#[test]
+fn change_history() {
+    use iso3166_1::{as_of, changes, changes_between, iso3166_3, ChangeKind, Date, DATA_VERSION};
+
+    for change in changes() {
+        let after = as_of(change.date).alpha2(change.alpha2);
+
+        match change.kind {
+            ChangeKind::Added { name } => assert_eq!(after.unwrap().name, name),
+            ChangeKind::Removed { name } => {
+                assert!(after.is_none_or(|entry| entry.name != name))
+            }
+            ChangeKind::Alpha3Changed { to, .. } => assert_eq!(after.unwrap().alpha3, to),
+            ChangeKind::NumericChanged { to, .. } => assert_eq!(after.unwrap().num, Some(to)),
+            ChangeKind::NameChanged { to, .. } => assert_eq!(after.unwrap().name, to),
+        }
+    }
+
+    assert!(changes().windows(2).all(|pair| pair[0].date <= pair[1].date));
+    assert!(changes().iter().any(|change| change.date == DATA_VERSION));
+    assert!(changes_between(Date::new(2020, 1, 1), Date::new(2010, 1, 1)).is_empty());
+    assert_eq!(changes_between(Date::new(1900, 1, 1), Date::new(2100, 1, 1)), changes());
+
+    // The changes are built from the history, so they agree with it.
+    for former in iso3166_3::all() {
+        let removed = ChangeKind::Removed { name: former.name };
+
+        assert!(changes().iter().any(|change| {
+            change.kind == removed && Some(change.date) == former.validity.until
+        }));
+    }
+
+    for entry in Entry::all() {
+        if let Some(from) = entry.name_validity().from {
+            assert!(changes().iter().any(|change| {
+                change.alpha2 == entry.alpha2 && change.date == from
+            }));
+        }
+    }
+
+    // The changes after `DATA_VERSION` are renames the table does not carry.
+    let later = changes_between(DATA_VERSION, Date::new(2100, 1, 1));
+
+    assert_eq!(later.len(), 4);
+
+    for change in later {
+        match change.kind {
+            ChangeKind::NameChanged { from, .. } => {
+                assert_eq!(alpha2(change.alpha2).unwrap().name, from)
+            }
+            kind => panic!("unexpected change {:?}", kind),
+        }
+    }
+
+    let antilles = changes_between(Date::new(2010, 1, 1), Date::new(2010, 12, 31));
+    let codes: Vec<&str> = antilles.iter().map(|change| change.alpha2).collect();
+
+    assert_eq!(codes, ["AN", "BQ", "CW", "SX"]);
+    assert_eq!(antilles[0].reference, "ISO 3166-3 ANHH");
+}
+
+#[test]
+fn name_variants() {
+    use iso3166_1::{name_formal, name_iso, name_upper};
+
+    for entry in Entry::all() {
+        let formal = entry.formal_name();
+
+        assert!(formal.is_none_or(|name| name.starts_with("the ")));
+        assert_eq!(Entry::by_iso_name(entry.iso_name()), Some(entry));
+        assert_eq!(Entry::by_upper_name(&entry.upper_name()), Some(entry));
+
+        if let Some(name) = formal {
+            assert_eq!(Entry::by_formal_name(name), Some(entry));
+        }
+    }
+
+    assert_eq!(name_formal("The United States of America").unwrap().alpha2, "US");
+    assert_eq!(name_formal("Kingdom of Spain").unwrap().alpha2, "ES");
+    assert_eq!(name_iso("Congo (the Democratic Republic of the)").unwrap().alpha2, "CD");
+    assert_eq!(name_iso("Germany").unwrap().alpha2, "DE");
+    assert_eq!(name_upper("ÅLAND ISLANDS").unwrap().alpha2, "AX");
+    assert_eq!(name_upper("UNITED STATES OF AMERICA (THE)").unwrap().alpha2, "US");
+    assert_eq!(
+        Country::GB.upper_name(),
+        "UNITED KINGDOM OF GREAT BRITAIN AND NORTHERN IRELAND (THE)"
+    );
+    assert!(name_upper("Germany").is_none());
+    assert!(name_iso("Bahamas").is_none());
+}
+
+#[test]
+fn french_names() {
+    use iso3166_1::name_fr;
+
+    for entry in Entry::all() {
+        assert!(!entry.name_fr().is_empty());
+        assert_eq!(Entry::by_name_fr(entry.name_fr()), Some(entry));
+    }
+
+    assert_eq!(name_fr("Côte d'Ivoire (la)").unwrap().alpha2, "CI");
+    assert_eq!(name_fr("États-Unis d'Amérique (les)").unwrap().alpha2, "US");
+    assert_eq!(Country::CH.name_fr(), "Suisse (la)");
+    assert!(name_fr("Allemagne").is_none());
+    assert!(name_fr("Germany").is_none());
+}
+
+#[test]
+fn normalized_names() {
+    let expected = [
+        ("Cote d'Ivoire", "CI"),
+        ("COTE D\u{2019}IVOIRE", "CI"),
+        ("Co\u{302}te d'Ivoire", "CI"),
+        ("Curacao", "CW"),
+        ("Aland Islands", "AX"),
+        ("Reunion", "RE"),
+        ("Sao Tome and Principe", "ST"),
+        ("São Tomé and Príncipe", "ST"),
+        ("  saint   barthelemy ", "BL"),
+        ("Guinea\u{2013}Bissau", "GW"),
+        ("SAINT-MARTIN (FRENCH PART)", "MF"),
+        ("Tǘrkiye", "TR"),
+        ("Tu\u{308}\u{301}rkiye", "TR"),
+        ("Korea, Republic of", "KR"),
+        ("Korea (Democratic People\u{2019}s Republic of)", "KP"),
+        ("St. Lucia", "LC"),
+    ];
+
+    for &(name, alpha2) in &expected {
+        let code = iso3166_1::name_normalized(name).unwrap();
+
+        assert_eq!(code.alpha2, alpha2, "{}", name);
+    }
+
+    assert_eq!(iso3166_1::name_normalized("Cote dIvoire"), None);
+    assert_eq!(iso3166_1::name_normalized(""), None);
+
+    for entry in Entry::all() {
+        assert_eq!(Entry::by_normalized_name(entry.name), Some(entry));
+    }
+}
+
+#[test]
+fn fuzzy_search() {
+    use iso3166_1::{search, search_with_threshold};
+
+    for &(query, alpha2) in &[
+        ("Phillipines", "PH"),
+        ("Argentia", "AR"),
+        ("Kazakstan", "KZ"),
+        ("Swizerland", "CH"),
+        ("deu", "DE"),
+        ("Burma", "MM"),
+    ] {
+        let candidates = search(query, 5);
+
+        assert_eq!(candidates[0].code.alpha2, alpha2, "{}", query);
+    }
+
+    let candidates = search("Argentia", 10);
+
+    assert!(candidates.len() > 1);
+    assert!(candidates.windows(2).all(|pair| pair[0].score >= pair[1].score));
+    assert_eq!(candidates[0].matched, "Argentina");
+    assert_eq!(search("Niger", 1).len(), 1);
+    assert_eq!(search("Niger", 1)[0].score, 1.0);
+    assert_eq!(search("Niger", 0), vec![]);
+    assert_eq!(search("xyz", 10), vec![]);
+    assert_eq!(search("", 10), vec![]);
+
+    let all = search_with_threshold("Argentia", 300, 0.0);
+
+    assert!(all.iter().all(|c| c.score >= 0.0 && c.score <= 1.0));
+    assert!(search_with_threshold("Argentia", 10, 0.95)
+        .iter()
+        .all(|c| c.score >= 0.95));
+}
+
+#[test]
+fn autocompletion() {
+    use iso3166_1::autocomplete;
+
+    let alpha2 = |prefix: &str, limit: usize| -> Vec<String> {
+        autocomplete(prefix, limit)
+            .into_iter()
+            .map(|completion| completion.code.alpha2)
+            .collect()
+    };
+
+    assert_eq!(alpha2("Korea", 5), ["KR", "KP"]);
+    assert_eq!(alpha2("kor", 2), ["KR", "KP"]);
+    assert_eq!(alpha2("Bissau", 5), ["GW"]);
+    assert_eq!(alpha2("guinea", 5), ["GN", "GW", "GQ", "PG"]);
+    assert_eq!(alpha2("cote", 5), ["CI"]);
+    assert_eq!(alpha2("Holl", 5), ["NL"]);
+    assert_eq!(alpha2("deu", 5), ["DE"]);
+    assert_eq!(alpha2("de", 1), ["DE"]);
+    assert_eq!(alpha2("Saint", 3).len(), 3);
+    assert_eq!(alpha2("", 5), Vec::<String>::new());
+    assert_eq!(alpha2("Atl", 5), Vec::<String>::new());
+
+    // Common words only match at the start of a name.
+    assert!(alpha2("Rep", 10).len() < 5);
+    assert!(!alpha2("Rep", 10).contains(&"DO".to_owned()));
+
+    let completions = autocomplete("a", 300);
+
+    assert!(completions.iter().all(|c| {
+        completions.iter().filter(|other| other.code == c.code).count() == 1
+    }));
+}
+
+#[test]
+fn mentions() {
+    use iso3166_1::{extract, Extractor};
+
+    let text = "From Guinea-Bissau via Papua New Guinea to Guinea, \
+                then the USA, the UK and Cote d\u{2019}Ivoire. Chad, not chad.";
+    let mentions = extract(text);
+    let found: Vec<(&str, &str)> = mentions
+        .iter()
+        .map(|mention| (mention.text, mention.code.alpha2.as_str()))
+        .collect();
+
+    assert_eq!(
+        found,
+        [
+            ("Guinea-Bissau", "GW"),
+            ("Papua New Guinea", "PG"),
+            ("Guinea", "GN"),
+            ("Cote d\u{2019}Ivoire", "CI"),
+            ("Chad", "TD"),
+        ]
+    );
+
+    for mention in &mentions {
+        assert_eq!(&text[mention.span.clone()], mention.text);
+    }
+
+    // Mentions end at word boundaries.
+    assert_eq!(extract("Nigerian Nigeria")[0].text, "Nigeria");
+    assert_eq!(extract("Guineas"), vec![]);
+
+    let codes = "IT moved DE data to FR and the UAE.";
+    let including = Extractor::new().ignore_two_letter_codes(false);
+    let abbreviations = Extractor::new().ignore_abbreviations(false);
+
+    assert_eq!(extract(codes), vec![]);
+    assert_eq!(including.extract(codes).len(), 3);
+    assert_eq!(including.extract(text)[3].text, "UK");
+    assert_eq!(abbreviations.extract(codes).len(), 1);
+    assert_eq!(abbreviations.extract(codes)[0].code.alpha2, "AE");
+    assert_eq!(abbreviations.extract(text)[3].text, "USA");
+
+    // Words in upper case are not taken for abbreviations by default.
+    assert_eq!(extract("THE CAR IS RED"), vec![]);
+    assert_eq!(extract("THE DRC AND UAE"), vec![]);
+    assert_eq!(abbreviations.extract("THE CAR IS RED")[0].code.alpha2, "CF");
+
+    let all = Extractor::new()
+        .ignore_two_letter_codes(false)
+        .ignore_abbreviations(false);
+
+    assert_eq!(all.extract(codes).len(), 4);
+}
+
+#[test]
+fn demonyms() {
+    use iso3166_1::{demonym, try_demonym, LookupError};
+
+    for &(name, alpha2) in &[
+        ("German", "DE"),
+        ("French", "FR"),
+        ("Filipino", "PH"),
+        ("Filipina", "PH"),
+        ("Philippine", "PH"),
+        ("Dutch", "NL"),
+        ("DUTCH", "NL"),
+        ("Ivorian", "CI"),
+        ("Sao Tomean", "ST"),
+        ("Bissau-Guinean", "GW"),
+        ("Guinean", "GN"),
+    ] {
+        assert_eq!(demonym(name).unwrap().alpha2, alpha2, "{}", name);
+    }
+
+    assert_eq!(Country::DE.demonym(), Some("German"));
+    assert_eq!(Country::PH.demonyms(), ["Filipino", "Filipina"]);
+    assert_eq!(Country::PH.adjective(), Some("Philippine"));
+    assert_eq!(Country::NL.adjective(), Some("Dutch"));
+    assert_eq!(Country::BV.adjective(), None);
+
+    assert_eq!(try_demonym("Dominican"), Err(LookupError::Ambiguous));
+    assert_eq!(try_demonym("Congolese"), Err(LookupError::Ambiguous));
+    assert_eq!(try_demonym("Atlantean"), Err(LookupError::UnknownName));
+
+    // A nationality rendered from a country leads back to it, unless shared.
+    for entry in Entry::all() {
+        if let Some(name) = entry.demonym() {
+            match Entry::try_by_demonym(name) {
+                Ok(found) => assert_eq!(found, entry),
+                Err(error) => assert_eq!(error, LookupError::Ambiguous, "{}", name),
+            }
+        }
+    }
+}
+
+#[test]
+fn aliases() {
+    use iso3166_1::NameMatch;
+
+    let expected = [
+        ("USA", "US"),
+        ("United States", "US"),
+        ("UK", "GB"),
+        ("Great Britain", "GB"),
+        ("Holland", "NL"),
+        ("Burma", "MM"),
+        ("Ivory Coast", "CI"),
+        ("Czechia", "CZ"),
+        ("South Korea", "KR"),
+        ("Russia", "RU"),
+    ];
+
+    for &(alias, alpha2) in &expected {
+        let (code, matched) = iso3166_1::name_or_alias(alias).unwrap();
+
+        assert_eq!(code.alpha2, alpha2);
+        assert_eq!(matched, NameMatch::Alias);
+        assert_eq!(iso3166_1::name(alias), None);
+    }
+
+    let (code, matched) = iso3166_1::name_or_alias("Germany").unwrap();
+
+    assert_eq!(code.alpha2, "DE");
+    assert_eq!(matched, NameMatch::Canonical);
+    assert_eq!(iso3166_1::name_or_alias("Atlantis"), None);
+
+    // An alias belongs to one country only, and never shadows a short name.
+    let mut seen = Vec::new();
+
+    for entry in Entry::all() {
+        for alias in entry.aliases() {
+            assert_eq!(Entry::by_name(alias), None, "{}", alias);
+            assert!(!seen.contains(alias), "{}", alias);
+
+            seen.push(*alias);
+        }
+    }
+}
+
+#[cfg(feature = "l10n")]
+#[test]
+fn localized_names() {
+    use iso3166_1::l10n;
+
+    let locales: Vec<&str> = l10n::locales().collect();
+
+    assert!(locales.len() > 40);
+    assert!(locales.windows(2).all(|pair| pair[0] < pair[1]));
+
+    for locale in &locales {
+        for entry in Entry::all() {
+            assert!(!entry.localized_name(locale).unwrap().is_empty());
+        }
+    }
+
+    assert_eq!(Country::DE.localized_name("de"), Some("Deutschland"));
+    assert_eq!(Country::SJ.localized_name("de-AT"), Some("Svalbard und Jan Mayen"));
+    assert_eq!(
+        Country::SJ.localized_name("de-DE"),
+        Country::SJ.localized_name("de")
+    );
+    assert_eq!(Country::DE.localized_name("DE_at"), Some("Deutschland"));
+    assert_eq!(Country::DE.localized_name("de-AT-u-co-phonebk"), Some("Deutschland"));
+    assert_eq!(Country::DE.localized_name("zh-TW"), Some("德國"));
+    assert_eq!(Country::DE.localized_name("zh-Hans-CN"), Some("德国"));
+    assert_eq!(Country::DE.localized_name("nb-NO"), Some("Tyskland"));
+    assert_eq!(
+        Country::US.localized_name("es-AR"),
+        Country::US.localized_name("es-419")
+    );
+    assert_eq!(Country::DE.localized_name("x-private"), None);
+    assert_eq!(Country::DE.localized_name(""), None);
+    assert_eq!(Country::DE.localized_name("tlh"), None);
+}
+
 // Backwards compatibility tests.
 #[test]
+#[allow(clippy::len_zero)]
 fn backwards_compat() {
     assert!(all().len() != 0);
 }
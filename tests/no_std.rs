@@ -0,0 +1,88 @@
+// ISC License (ISC)
+//
+// Copyright (c) 2016, Zeyla Hellyer <zeylahellyer@gmail.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER
+// RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF
+// CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+// What is ISO 3166-1?
+//
+// | ISO 3166-1 is part of the ISO 3166 standard published by the International
+// | Organization for Standardization (ISO), and defines codes for the names of
+// | countries, dependent territories, and special areas of geographical
+// | interest.
+// |
+// | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_3166-1)
+//
+// Originally by zeyla on GitHub.
+//
+// Lookups that are available without the `std` feature, and so without an
+// allocator.
+
+#[macro_use]
+extern crate iso3166_1;
+
+use iso3166_1::{Alpha2, Country, Entry, LookupError, Numeric};
+use std::convert::TryFrom;
+
+#[test]
+fn entry_lookups() {
+    let entry = Entry::by_alpha2("DE").unwrap();
+
+    assert_eq!(entry.country, Country::DE);
+    assert_eq!(entry.alpha3, "DEU");
+    assert_eq!(entry.name, "Germany");
+    assert_eq!(entry.numeric(), 276);
+    assert_eq!(Entry::by_alpha3("DEU"), Some(entry));
+    assert_eq!(Entry::by_num("276"), Some(entry));
+    assert_eq!(Entry::by_numeric(276), Some(entry));
+    assert_eq!(Entry::by_name("Germany"), Some(entry));
+    assert_eq!(Entry::by_alpha2("de"), None);
+    assert_eq!(Entry::by_numeric_range(1..4).count(), 0);
+    assert_eq!(Entry::by_numeric_range(..).count(), Entry::all().len());
+
+    assert_eq!(Entry::try_by_alpha2("DE"), Ok(entry));
+    assert_eq!(Entry::try_by_num("000"), Err(LookupError::Unassigned));
+    assert_eq!(
+        Entry::try_by_alpha2("YU"),
+        Err(LookupError::Withdrawn {
+            successors: &[Country::ME, Country::RS],
+        })
+    );
+}
+
+#[test]
+fn country_lookups() {
+    const DEFAULT: Country = country!("DE");
+
+    assert_eq!(Country::from_alpha2("DE"), Some(DEFAULT));
+    assert_eq!(Country::from_alpha3("DEU"), Some(DEFAULT));
+    assert_eq!(Country::from_numeric(276), Some(DEFAULT));
+    assert_eq!(Country::from_alpha2("XX"), None);
+    assert_eq!(DEFAULT.alpha2(), "DE");
+    assert_eq!(DEFAULT.numeric(), 276);
+    assert_eq!(countries!["DE", "FR"], [Country::DE, Country::FR]);
+
+    for entry in Entry::all() {
+        assert_eq!(Country::from_alpha2(entry.alpha2), Some(entry.country));
+    }
+}
+
+#[test]
+fn typed_codes() {
+    let alpha2: Alpha2 = "FR".parse().unwrap();
+    let numeric = Numeric::try_from(250).unwrap();
+
+    assert_eq!(alpha2.country(), Some(Country::FR));
+    assert_eq!(numeric.country(), Some(Country::FR));
+    assert_eq!(alpha2.to_alpha3().unwrap().as_str(), "FRA");
+}